@@ -16,7 +16,8 @@
 #![deny(unsafe_code)]
 
 // exports
-pub use rust_toolchain::channel::{Beta, Nightly, Stable};
+pub use rust_toolchain::channel::{Alpha, Beta, Nightly, Stable};
+use std::borrow::Borrow;
 use std::cmp;
 use std::fmt::Debug;
 
@@ -24,16 +25,21 @@ use std::fmt::Debug;
 ///
 /// Do not use as your date type!
 pub mod date {
-    pub use rust_toolchain::Date;
+    pub use rust_toolchain::{Date, DateError};
 }
 /// Describes toolchains in so far they're relevant to a release
 pub mod toolchain {
-    pub use rust_toolchain::{Channel, Component, RustVersion, Target, Toolchain};
+    pub use rust_toolchain::{
+        Channel, ChannelSpec, Component, KnownComponent, PartialRustVersion, PlatformSupport,
+        Profile, RustVersion, RustVersionReq, Target, Tier, Toolchain, ToolchainSpec,
+    };
 }
 
 /// Describes the version of a release
 pub mod version;
 
+pub use version::{CustomChannelError, ReleaseChannel, ReleaseVersion, UnknownChannelError};
+
 /// Type to model a Rust release.
 ///
 /// # PartialEq, Eq, Ord, PartialOrd
@@ -48,9 +54,9 @@ pub struct RustRelease<V: Debug, C = ()> {
     ///
     /// The versioning scheme depends on the channel, which is why the version
     /// type is a generic. In this library, the `V` is always substituted by one
-    /// of the following types: [`Stable`], [`Beta`] or [`Nightly`].
+    /// of the following types: [`Stable`], [`Beta`], [`Nightly`] or [`Alpha`].
     ///
-    /// [`Stable`] and [`Beta`] carry a semver version number, while [`Nightly`]
+    /// [`Stable`], [`Beta`] and [`Alpha`] carry a semver version number, while [`Nightly`]
     /// is versioned by a date.
     pub version: V,
     /// The release date of the release.
@@ -85,6 +91,13 @@ impl<V: Ord + Debug, C> Ord for RustRelease<V, C> {
     }
 }
 
+// A release is identified by its version, which allows sets of releases to be queried by version
+impl<V: Debug, C> Borrow<V> for RustRelease<V, C> {
+    fn borrow(&self) -> &V {
+        &self.version
+    }
+}
+
 impl<V: Debug> RustRelease<V, ()> {
     /// Create a new RustRelease instance using a version, optionally
     /// a release date, and an iterator of toolchains.
@@ -174,6 +187,32 @@ impl<V: Debug, C> RustRelease<V, C> {
         self.toolchains.iter()
     }
 
+    /// Convert the version of the release, retaining the release date, toolchains and context.
+    ///
+    /// For example, a `RustRelease<Stable>` can be converted into a channel erased
+    /// `RustRelease<ReleaseVersion>` with `release.map_version(ReleaseVersion::Stable)`.
+    pub fn map_version<W: Debug>(self, f: impl FnOnce(V) -> W) -> RustRelease<W, C> {
+        RustRelease {
+            version: f(self.version),
+            release_date: self.release_date,
+            toolchains: self.toolchains,
+            context: self.context,
+        }
+    }
+
+    /// Convert the context of the release, retaining the version, release date and toolchains.
+    ///
+    /// For example, to give the releases of several sources a common context type before
+    /// merging them.
+    pub fn map_context<D>(self, f: impl FnOnce(C) -> D) -> RustRelease<V, D> {
+        RustRelease {
+            version: self.version,
+            release_date: self.release_date,
+            toolchains: self.toolchains,
+            context: f(self.context),
+        }
+    }
+
     /// A shared reference to the (added) context of this release.
     pub fn context(&self) -> &C {
         &self.context
@@ -185,20 +224,6 @@ impl<V: Debug, C> RustRelease<V, C> {
     }
 }
 
-/// A combination of a channel and the version number.
-///
-/// For stable and beta releases, we have a three component MAJOR.MINOR.PATCH
-/// version number. For nightly releases, we have a release date.
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub enum ReleaseVersion {
-    /// A stable channel release version
-    Stable(Stable),
-    /// A beta channel release version
-    Beta(Beta),
-    /// A nightly channel release version
-    Nightly(Nightly),
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -381,6 +406,29 @@ mod tests {
         assert_eq!(release.context.checksum, Checksum::Crc32(0xFFFFFFFF));
     }
 
+    #[test]
+    fn map_version() {
+        let stable = Stable::new(1, 82, 0);
+        let date = rust_toolchain::Date::new(2024, 10, 17);
+        let release = RustRelease::new(stable.clone(), Some(date.clone()), []);
+
+        let release = release.map_version(ReleaseVersion::Stable);
+
+        assert_eq!(release.version(), &ReleaseVersion::Stable(stable));
+        assert_eq!(release.release_date(), Some(&date));
+    }
+
+    #[test]
+    fn map_context() {
+        let stable = Stable::new(1, 82, 0);
+        let release = RustRelease::new(stable.clone(), None, []);
+
+        let release = release.map_context(|()| "changelog");
+
+        assert_eq!(release.version(), &stable);
+        assert_eq!(release.context(), &"changelog");
+    }
+
     #[test]
     fn pattern_match() {
         let stable = Stable::new(1, 82, 0);
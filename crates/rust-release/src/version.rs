@@ -1,10 +1,34 @@
 //! Version information for a Rust release.
 
+use rust_toolchain::channel::{Alpha, Beta, Custom, Nightly, Stable};
+use rust_toolchain::{Channel, Date, ParseToolchainError, RustVersion};
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
 /// A combination of a channel and the version number.
 ///
-/// For stable and beta releases, we have a three component MAJOR.MINOR.PATCH
+/// For stable, beta and alpha releases, we have a three component MAJOR.MINOR.PATCH
 /// version number. For nightly releases, we have a release date.
-#[derive(Clone, Debug, Eq, PartialEq)]
+///
+/// # Ord
+///
+/// Release versions are ordered by their version number. An alpha release precedes a beta
+/// release of the same version, which in turn precedes the stable release. Since a nightly
+/// release does not have a version number, nightly releases are ordered by their date, after
+/// all versioned releases.
+///
+/// The release train a nightly release was building toward can not be derived from the nightly
+/// alone, since it depends on the release dates of the other releases. To order nightly
+/// releases relative to their release train instead, use `RustReleases::all` of the
+/// `rust-releases-core` crate.
+///
+/// # FromStr and Display
+///
+/// A release version is written like the channel of a toolchain, e.g. `1.70.0`, `1.71.0-beta.2`,
+/// `nightly-2024-02-01`, `0.12.0` or `1.0.0-alpha.2`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum ReleaseVersion {
     /// A stable channel release version
     Stable(rust_toolchain::channel::Stable),
@@ -12,4 +36,305 @@ pub enum ReleaseVersion {
     Beta(rust_toolchain::channel::Beta),
     /// A nightly channel release version
     Nightly(rust_toolchain::channel::Nightly),
+    /// A historic alpha channel release version
+    Alpha(rust_toolchain::channel::Alpha),
+}
+
+impl ReleaseVersion {
+    /// The three component version number, or `None` for a nightly release
+    pub fn version(&self) -> Option<RustVersion> {
+        match self {
+            ReleaseVersion::Stable(stable) => Some(stable.version),
+            ReleaseVersion::Beta(beta) => Some(beta.version),
+            ReleaseVersion::Alpha(alpha) => Some(alpha.version),
+            ReleaseVersion::Nightly(_) => None,
+        }
+    }
+
+    /// The date of a nightly release, or `None` for other releases
+    pub fn date(&self) -> Option<&Date> {
+        match self {
+            ReleaseVersion::Nightly(nightly) => Some(&nightly.date),
+            _ => None,
+        }
+    }
+
+    /// The release channel of the release
+    pub fn channel(&self) -> ReleaseChannel {
+        match self {
+            ReleaseVersion::Stable(_) => ReleaseChannel::Stable,
+            ReleaseVersion::Beta(_) => ReleaseChannel::Beta,
+            ReleaseVersion::Nightly(_) => ReleaseChannel::Nightly,
+            ReleaseVersion::Alpha(_) => ReleaseChannel::Alpha,
+        }
+    }
+
+    fn sort_key(&self) -> SortKey<'_> {
+        match self {
+            ReleaseVersion::Alpha(alpha) => SortKey::Versioned(alpha.version, 0, alpha.prerelease),
+            ReleaseVersion::Beta(beta) => SortKey::Versioned(beta.version, 1, beta.prerelease),
+            ReleaseVersion::Stable(stable) => SortKey::Versioned(stable.version, 2, None),
+            ReleaseVersion::Nightly(nightly) => SortKey::Nightly(&nightly.date),
+        }
+    }
+}
+
+// Versioned releases are ordered by version, then by channel (alpha, beta, stable), and then by
+// their pre-release number. Nightly releases follow all versioned releases.
+#[derive(Eq, Ord, PartialEq, PartialOrd)]
+enum SortKey<'a> {
+    Versioned(RustVersion, u8, Option<u32>),
+    Nightly(&'a Date),
+}
+
+impl PartialOrd for ReleaseVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ReleaseVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.sort_key().cmp(&other.sort_key())
+    }
+}
+
+impl From<Stable> for ReleaseVersion {
+    fn from(stable: Stable) -> Self {
+        ReleaseVersion::Stable(stable)
+    }
+}
+
+impl From<Beta> for ReleaseVersion {
+    fn from(beta: Beta) -> Self {
+        ReleaseVersion::Beta(beta)
+    }
+}
+
+impl From<Nightly> for ReleaseVersion {
+    fn from(nightly: Nightly) -> Self {
+        ReleaseVersion::Nightly(nightly)
+    }
+}
+
+impl From<Alpha> for ReleaseVersion {
+    fn from(alpha: Alpha) -> Self {
+        ReleaseVersion::Alpha(alpha)
+    }
+}
+
+impl From<ReleaseVersion> for Channel {
+    fn from(version: ReleaseVersion) -> Self {
+        match version {
+            ReleaseVersion::Stable(stable) => Channel::Stable(stable),
+            ReleaseVersion::Beta(beta) => Channel::Beta(beta),
+            ReleaseVersion::Nightly(nightly) => Channel::Nightly(nightly),
+            ReleaseVersion::Alpha(alpha) => Channel::Alpha(alpha),
+        }
+    }
+}
+
+impl TryFrom<Channel> for ReleaseVersion {
+    type Error = CustomChannelError;
+
+    /// Converts the channel into a release version, which fails for a custom toolchain, since
+    /// it is not released by the Rust project.
+    fn try_from(channel: Channel) -> Result<Self, Self::Error> {
+        match channel {
+            Channel::Stable(stable) => Ok(ReleaseVersion::Stable(stable)),
+            Channel::Beta(beta) => Ok(ReleaseVersion::Beta(beta)),
+            Channel::Nightly(nightly) => Ok(ReleaseVersion::Nightly(nightly)),
+            Channel::Alpha(alpha) => Ok(ReleaseVersion::Alpha(alpha)),
+            Channel::Custom(custom) => Err(CustomChannelError(custom)),
+        }
+    }
+}
+
+impl FromStr for ReleaseVersion {
+    type Err = ParseToolchainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Parsing never produces a custom channel
+        s.parse::<Channel>().and_then(|channel| {
+            Self::try_from(channel).map_err(|_| ParseToolchainError::Unresolved(s.to_string()))
+        })
+    }
+}
+
+impl fmt::Display for ReleaseVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", Channel::from(self.clone()))
+    }
+}
+
+/// Returned when a [`Channel`] of a custom toolchain is converted into a [`ReleaseVersion`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CustomChannelError(pub Custom);
+
+impl fmt::Display for CustomChannelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Custom toolchain `{}` is not a release", self.0)
+    }
+}
+
+impl std::error::Error for CustomChannelError {}
+
+/// Enumerates the Rust release channels, without the version of a release.
+///
+/// Written as `stable`, `beta`, `nightly` or `alpha`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ReleaseChannel {
+    /// An identifier for the `stable` release channel
+    Stable,
+    /// An identifier for the `beta` release channel
+    Beta,
+    /// An identifier for the `nightly` release channel
+    Nightly,
+    /// An identifier for the historic `alpha` release channel
+    Alpha,
+}
+
+impl TryFrom<&str> for ReleaseChannel {
+    type Error = UnknownChannelError;
+
+    fn try_from(item: &str) -> Result<Self, Self::Error> {
+        Ok(match item {
+            "beta" => Self::Beta,
+            "nightly" => Self::Nightly,
+            "stable" => Self::Stable,
+            "alpha" => Self::Alpha,
+            unsupported => {
+                return Err(UnknownChannelError {
+                    channel: unsupported.to_string(),
+                })
+            }
+        })
+    }
+}
+
+impl From<ReleaseChannel> for &str {
+    fn from(channel: ReleaseChannel) -> Self {
+        match channel {
+            ReleaseChannel::Beta => "beta",
+            ReleaseChannel::Nightly => "nightly",
+            ReleaseChannel::Stable => "stable",
+            ReleaseChannel::Alpha => "alpha",
+        }
+    }
+}
+
+impl fmt::Display for ReleaseChannel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str((*self).into())
+    }
+}
+
+/// Returned when a name which is not one of the [`ReleaseChannel`]s is converted into one.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnknownChannelError {
+    channel: String,
+}
+
+impl fmt::Display for UnknownChannelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "No such channel: `{}`", self.channel)
+    }
+}
+
+impl std::error::Error for UnknownChannelError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stable(major: u64, minor: u64, patch: u64) -> ReleaseVersion {
+        ReleaseVersion::Stable(Stable::new(major, minor, patch))
+    }
+
+    fn beta(minor: u64, prerelease: u32) -> ReleaseVersion {
+        ReleaseVersion::Beta(Beta {
+            version: RustVersion::new(1, minor, 0),
+            prerelease: Some(prerelease),
+        })
+    }
+
+    fn nightly(year: u16, month: u8, day: u8) -> ReleaseVersion {
+        ReleaseVersion::Nightly(Nightly {
+            date: Date::new(year, month, day),
+        })
+    }
+
+    #[yare::parameterized(
+        stable_patch = { stable(1, 70, 0), stable(1, 70, 1) },
+        stable_minor = { stable(1, 70, 1), stable(1, 71, 0) },
+        beta_precedes_stable = { beta(71, 2), stable(1, 71, 0) },
+        beta_follows_previous_stable = { stable(1, 70, 1), beta(71, 1) },
+        beta_prerelease = { beta(71, 1), beta(71, 2) },
+        alpha_precedes_beta = { ReleaseVersion::Alpha(Alpha::prerelease(RustVersion::new(1, 0, 0), 2)), ReleaseVersion::Beta(Beta { version: RustVersion::new(1, 0, 0), prerelease: None }) },
+        alpha_precedes_stable = { ReleaseVersion::Alpha(Alpha::new(0, 12, 0)), stable(1, 0, 0) },
+        nightly_follows_versioned = { stable(99, 0, 0), nightly(2015, 1, 1) },
+        nightly_date = { nightly(2024, 1, 31), nightly(2024, 2, 1) },
+    )]
+    fn ord(lesser: ReleaseVersion, greater: ReleaseVersion) {
+        assert!(lesser < greater);
+        assert_eq!(greater.cmp(&lesser), Ordering::Greater);
+    }
+
+    #[yare::parameterized(
+        stable_release = { "1.70.0", stable(1, 70, 0) },
+        beta_release = { "1.71.0-beta.2", beta(71, 2) },
+        nightly_release = { "nightly-2024-02-01", nightly(2024, 2, 1) },
+        alpha = { "0.12.0", ReleaseVersion::Alpha(Alpha::new(0, 12, 0)) },
+        alpha_prerelease = { "1.0.0-alpha.2", ReleaseVersion::Alpha(Alpha::prerelease(RustVersion::new(1, 0, 0), 2)) },
+    )]
+    fn parse_and_display(input: &str, expected: ReleaseVersion) {
+        assert_eq!(input.parse::<ReleaseVersion>().unwrap(), expected);
+        assert_eq!(expected.to_string(), input);
+    }
+
+    #[yare::parameterized(
+        symbolic = { "stable" },
+        partial = { "1.70" },
+        custom = { "stage2" },
+    )]
+    fn parse_rejects(input: &str) {
+        assert!(input.parse::<ReleaseVersion>().is_err());
+    }
+
+    #[test]
+    fn version_and_date() {
+        assert_eq!(stable(1, 70, 0).version(), Some(RustVersion::new(1, 70, 0)));
+        assert_eq!(stable(1, 70, 0).date(), None);
+        assert_eq!(nightly(2024, 2, 1).version(), None);
+        assert_eq!(nightly(2024, 2, 1).date(), Some(&Date::new(2024, 2, 1)));
+    }
+
+    #[yare::parameterized(
+        stable_release = { stable(1, 70, 0), ReleaseChannel::Stable },
+        beta_release = { beta(71, 2), ReleaseChannel::Beta },
+        nightly_release = { nightly(2024, 2, 1), ReleaseChannel::Nightly },
+        alpha_release = { ReleaseVersion::Alpha(Alpha::new(0, 12, 0)), ReleaseChannel::Alpha },
+    )]
+    fn release_channel(version: ReleaseVersion, expected: ReleaseChannel) {
+        assert_eq!(version.channel(), expected);
+    }
+
+    #[test]
+    fn channel_conversions() {
+        let version = beta(71, 2);
+
+        let channel = Channel::from(version.clone());
+
+        assert_eq!(
+            channel,
+            Channel::Beta(Beta {
+                version: RustVersion::new(1, 71, 0),
+                prerelease: Some(2)
+            })
+        );
+        assert_eq!(ReleaseVersion::try_from(channel), Ok(version));
+
+        let custom = Channel::custom(Custom::Named("stage2".to_string()));
+        assert!(ReleaseVersion::try_from(custom).is_err());
+    }
 }
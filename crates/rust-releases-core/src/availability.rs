@@ -0,0 +1,243 @@
+//! Answers which components were available for which nightly, like the
+//! [`rustup component history`] does.
+//!
+//! The queries require nightly releases of which the toolchains, and their components, are
+//! populated, e.g. from the release manifests.
+//!
+//! [`rustup component history`]: https://rust-lang.github.io/rustup-components-history/
+
+use crate::{Nightly, NightlyReleases, RustRelease};
+use rust_release::date::Date;
+use rust_release::toolchain::{Component, Target};
+
+/// The availability of a set of components on a target, for a single nightly release.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Availability {
+    date: Date,
+    available: Vec<Component>,
+    missing: Vec<Component>,
+}
+
+impl Availability {
+    /// The date of the nightly release
+    pub fn date(&self) -> &Date {
+        &self.date
+    }
+
+    /// The requested components which were available
+    pub fn available(&self) -> &[Component] {
+        &self.available
+    }
+
+    /// The requested components which were missing
+    pub fn missing(&self) -> &[Component] {
+        &self.missing
+    }
+
+    /// Whether all requested components were available
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty()
+    }
+}
+
+impl<C> NightlyReleases<C> {
+    /// Find the most recent nightly release for which all given components are available on
+    /// the given target.
+    ///
+    /// A component is considered available if the toolchain of the nightly for the given host
+    /// target lists it, by its current, or by a previous name (e.g. `rustfmt-preview`).
+    pub fn last_available(
+        &self,
+        components: &[Component],
+        target: &Target,
+    ) -> Option<&RustRelease<Nightly, C>> {
+        self.iter().rfind(|release| {
+            components
+                .iter()
+                .all(|component| is_available(release, component, target))
+        })
+    }
+
+    /// The availability of the given components on the given target, for each nightly release,
+    /// in order of the release date.
+    pub fn availability(&self, components: &[Component], target: &Target) -> Vec<Availability> {
+        self.iter()
+            .map(|release| {
+                let (available, missing) = components
+                    .iter()
+                    .cloned()
+                    .partition(|component| is_available(release, component, target));
+
+                Availability {
+                    date: release.version().date.clone(),
+                    available,
+                    missing,
+                }
+            })
+            .collect()
+    }
+}
+
+fn is_available<C>(
+    release: &RustRelease<Nightly, C>,
+    requested: &Component,
+    target: &Target,
+) -> bool {
+    release
+        .toolchains_iter()
+        .filter(|toolchain| toolchain.host() == target)
+        .flat_map(|toolchain| toolchain.components())
+        .any(|component| provides(component, requested))
+}
+
+// A component provides the requested component if both refer to the same component, and, if the
+// requested component is specific to a target, both are specific to the same target.
+fn provides(component: &Component, requested: &Component) -> bool {
+    let same_component = match (component.known(), requested.known()) {
+        (Some(known), Some(requested)) => known == requested,
+        _ => component.name() == requested.name(),
+    };
+
+    let same_target = requested
+        .target()
+        .map_or(true, |target| component.target() == Some(target));
+
+    same_component && same_target
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_release::toolchain::{Channel, Toolchain};
+    use std::collections::HashSet;
+    use yare::parameterized;
+
+    fn aarch64() -> Target {
+        Target::try_from_target_triple("aarch64-unknown-linux-gnu").unwrap()
+    }
+
+    fn x86_64() -> Target {
+        Target::try_from_target_triple("x86_64-unknown-linux-gnu").unwrap()
+    }
+
+    fn nightly(day: u8, toolchains: Vec<(Target, &[&'static str])>) -> RustRelease<Nightly> {
+        let date = Date::new(2024, 2, day);
+
+        let toolchains = toolchains
+            .into_iter()
+            .map(|(host, components)| {
+                Toolchain::new(
+                    Channel::nightly(date.clone()),
+                    Some(date.clone()),
+                    host,
+                    components
+                        .iter()
+                        .map(|name| Component::new(*name))
+                        .collect(),
+                    HashSet::new(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        RustRelease::new(Nightly { date: date.clone() }, Some(date), toolchains)
+    }
+
+    fn index() -> NightlyReleases {
+        vec![
+            nightly(
+                1,
+                vec![(aarch64(), &["rustc", "miri-preview", "clippy-preview"])],
+            ),
+            nightly(2, vec![(aarch64(), &["rustc", "miri", "clippy"])]),
+            nightly(
+                3,
+                vec![
+                    (aarch64(), &["rustc", "clippy"]),
+                    (x86_64(), &["rustc", "miri", "clippy"]),
+                ],
+            ),
+            nightly(4, vec![]),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    fn components(names: &[&'static str]) -> Vec<Component> {
+        names.iter().map(|name| Component::new(*name)).collect()
+    }
+
+    #[parameterized(
+        miri_and_clippy_on_aarch64 = { &["miri", "clippy"], aarch64(), Some(2) },
+        miri_and_clippy_on_x86_64 = { &["miri", "clippy"], x86_64(), Some(3) },
+        previous_name = { &["clippy-preview"], aarch64(), Some(3) },
+        never = { &["rust-analyzer"], aarch64(), None },
+        no_components = { &[], aarch64(), Some(4) },
+    )]
+    fn last_available(names: &[&'static str], target: Target, expected_day: Option<u8>) {
+        let index = index();
+
+        let last = index.last_available(&components(names), &target);
+
+        assert_eq!(
+            last.map(|release| release.version().date.clone()),
+            expected_day.map(|day| Date::new(2024, 2, day))
+        );
+    }
+
+    #[test]
+    fn availability_timeline() {
+        let index = index();
+        let requested = components(&["miri", "clippy"]);
+
+        let timeline = index.availability(&requested, &aarch64());
+
+        let summary = timeline
+            .iter()
+            .map(|availability| {
+                (
+                    availability.date().day(),
+                    availability.is_complete(),
+                    availability.missing().len(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            summary,
+            vec![(1, true, 0), (2, true, 0), (3, false, 1), (4, false, 2)]
+        );
+        assert_eq!(timeline[2].available(), &[Component::new("clippy")]);
+        assert_eq!(timeline[2].missing(), &[Component::new("miri")]);
+    }
+
+    #[test]
+    fn target_specific_component() {
+        let wasm = Target::try_from_target_triple("wasm32-unknown-unknown").unwrap();
+        let date = Date::new(2024, 2, 1);
+
+        let mut toolchain_components = HashSet::new();
+        toolchain_components.insert(Component::for_target("rust-std", wasm.clone()));
+
+        let release = RustRelease::new(
+            Nightly { date: date.clone() },
+            Some(date.clone()),
+            [Toolchain::new(
+                Channel::nightly(date),
+                None,
+                x86_64(),
+                toolchain_components,
+                HashSet::new(),
+            )],
+        );
+        let index = vec![release].into_iter().collect::<NightlyReleases>();
+
+        let wasm_std = [Component::for_target("rust-std", wasm)];
+        let arm_std = [Component::for_target(
+            "rust-std",
+            Target::try_from_target_triple("thumbv7em-none-eabihf").unwrap(),
+        )];
+
+        assert!(index.last_available(&wasm_std, &x86_64()).is_some());
+        assert!(index.last_available(&arm_std, &x86_64()).is_none());
+    }
+}
@@ -0,0 +1,271 @@
+//! Detects the changes between two builds of an index, e.g. to alert on newly published
+//! releases since the last run of a scheduled job.
+//!
+//! Persist the index of each run as a snapshot, with [`RustReleases::to_snapshot`], read the
+//! snapshot of the previous run back with [`RustReleases::from_snapshot`], and compare it with
+//! a fresh index with [`RustReleases::changes_since`], or compare the collections of a single
+//! channel with [`Releases::changes_since`].
+//!
+//! The documents cached by a source can not be used to rebuild the previous index, since they
+//! are overwritten once a newer document is fetched.
+
+use crate::{ReleaseVersion, Releases, RustRelease, RustReleases};
+use rust_release::{date, toolchain};
+use std::fmt::Debug;
+
+/// A change to a release, between a previous and a current build of an index.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Change<V: Debug, C = ()> {
+    /// The release was not part of the previous index, i.e. it was newly published
+    Published(RustRelease<V, C>),
+    /// The release was part of the previous index, but is no longer part of the current index
+    Withdrawn(RustRelease<V, C>),
+    /// The release date of the release changed, or became known
+    ReleaseDateChanged {
+        /// The version of the release
+        version: V,
+        /// The release date in the previous index
+        previous: Option<date::Date>,
+        /// The release date in the current index
+        current: Option<date::Date>,
+    },
+    /// A toolchain was added to the release
+    ToolchainAdded {
+        /// The version of the release
+        version: V,
+        /// The added toolchain
+        toolchain: toolchain::Toolchain,
+    },
+    /// A toolchain was removed from the release
+    ToolchainRemoved {
+        /// The version of the release
+        version: V,
+        /// The removed toolchain
+        toolchain: toolchain::Toolchain,
+    },
+}
+
+impl<V: Debug, C> Change<V, C> {
+    /// The version of the release which changed
+    pub fn version(&self) -> &V {
+        match self {
+            Change::Published(release) | Change::Withdrawn(release) => release.version(),
+            Change::ReleaseDateChanged { version, .. }
+            | Change::ToolchainAdded { version, .. }
+            | Change::ToolchainRemoved { version, .. } => version,
+        }
+    }
+}
+
+impl<V: Debug + Ord + Clone, C: Clone> Releases<V, C> {
+    /// The changes from a previous build of this collection to this collection, ordered by
+    /// version.
+    ///
+    /// The changes of a single version are ordered as follows: a changed release date first,
+    /// then added toolchains, then removed toolchains. The context of releases which exist in
+    /// both collections is not compared.
+    pub fn changes_since(&self, previous: &Releases<V, C>) -> Vec<Change<V, C>> {
+        let mut changes = Vec::new();
+
+        for release in self.iter() {
+            match previous.get(release.version()) {
+                Some(before) => changes.extend(changes_of(before, release)),
+                None => changes.push(Change::Published(release.clone())),
+            }
+        }
+
+        changes.extend(previous.difference(self).into_iter().map(Change::Withdrawn));
+        changes.sort_by(|lhs, rhs| lhs.version().cmp(rhs.version()));
+
+        changes
+    }
+}
+
+impl RustReleases {
+    /// The changes from a previous build of the index to this index, for all channels, ordered
+    /// by [`ReleaseVersion`].
+    ///
+    /// See [`Releases::changes_since`] for the order of the changes of a single version.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_releases_core::changes::Change;
+    /// use rust_releases_core::{ReleaseVersion, RustReleases, Stable, StableReleases};
+    /// use rust_release::RustRelease;
+    /// use rust_release::date::Date;
+    ///
+    /// let index = |releases: Vec<RustRelease<Stable>>| {
+    ///     let stable = releases.into_iter().collect::<StableReleases>();
+    ///     RustReleases::new(stable, Default::default(), Default::default(), Default::default())
+    /// };
+    ///
+    /// let stable = |minor| RustRelease::new(Stable::new(1, minor, 0), Some(Date::new(2023, 6, 1)), []);
+    ///
+    /// let previous = index(vec![stable(69)]);
+    /// let current = index(vec![stable(69), stable(70)]);
+    ///
+    /// let changes = current.changes_since(&previous);
+    ///
+    /// assert_eq!(changes.len(), 1);
+    /// assert!(matches!(
+    ///     &changes[0],
+    ///     Change::Published(release) if release.version() == &ReleaseVersion::Stable(Stable::new(1, 70, 0))
+    /// ));
+    /// ```
+    pub fn changes_since(&self, previous: &RustReleases) -> Vec<Change<ReleaseVersion>> {
+        let current = self.erased().collect::<Releases<ReleaseVersion>>();
+        let previous = previous.erased().collect::<Releases<ReleaseVersion>>();
+
+        current.changes_since(&previous)
+    }
+}
+
+fn changes_of<V: Debug + Clone, C>(
+    previous: &RustRelease<V, C>,
+    current: &RustRelease<V, C>,
+) -> Vec<Change<V, C>> {
+    let mut changes = Vec::new();
+    let version = current.version();
+
+    if previous.release_date() != current.release_date() {
+        changes.push(Change::ReleaseDateChanged {
+            version: version.clone(),
+            previous: previous.release_date().cloned(),
+            current: current.release_date().cloned(),
+        });
+    }
+
+    changes.extend(
+        current
+            .toolchains_iter()
+            .filter(|toolchain| !previous.toolchains().contains(toolchain))
+            .map(|toolchain| Change::ToolchainAdded {
+                version: version.clone(),
+                toolchain: toolchain.clone(),
+            }),
+    );
+
+    changes.extend(
+        previous
+            .toolchains_iter()
+            .filter(|toolchain| !current.toolchains().contains(toolchain))
+            .map(|toolchain| Change::ToolchainRemoved {
+                version: version.clone(),
+                toolchain: toolchain.clone(),
+            }),
+    );
+
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::toolchain;
+    use crate::{Beta, BetaReleases, NightlyReleases, Stable, StableReleases};
+    use rust_release::date::Date;
+    use rust_release::toolchain::RustVersion;
+
+    fn stable(minor: u64, day: Option<u8>, targets: &[&str]) -> RustRelease<Stable> {
+        RustRelease::new(
+            Stable::new(1, minor, 0),
+            day.map(|day| Date::new(2023, 6, day)),
+            targets.iter().map(|target| toolchain(target)),
+        )
+    }
+
+    #[test]
+    fn unchanged() {
+        let releases = vec![stable(69, Some(1), &["x86_64-unknown-linux-gnu"])]
+            .into_iter()
+            .collect::<StableReleases>();
+
+        assert!(releases.changes_since(&releases.clone()).is_empty());
+    }
+
+    #[test]
+    fn published_and_withdrawn() {
+        let previous = vec![stable(68, None, &[]), stable(69, None, &[])]
+            .into_iter()
+            .collect::<StableReleases>();
+        let current = vec![stable(69, None, &[]), stable(70, None, &[])]
+            .into_iter()
+            .collect::<StableReleases>();
+
+        let changes = current.changes_since(&previous);
+
+        assert_eq!(changes.len(), 2);
+        assert!(
+            matches!(&changes[0], Change::Withdrawn(release) if release.version() == &Stable::new(1, 68, 0))
+        );
+        assert!(
+            matches!(&changes[1], Change::Published(release) if release.version() == &Stable::new(1, 70, 0))
+        );
+    }
+
+    #[test]
+    fn release_date_and_toolchains() {
+        let previous = vec![stable(70, None, &["x86_64-unknown-linux-gnu"])]
+            .into_iter()
+            .collect::<StableReleases>();
+        let current = vec![stable(70, Some(1), &["wasm32-unknown-unknown"])]
+            .into_iter()
+            .collect::<StableReleases>();
+
+        let version = Stable::new(1, 70, 0);
+
+        assert_eq!(
+            current.changes_since(&previous),
+            vec![
+                Change::ReleaseDateChanged {
+                    version: version.clone(),
+                    previous: None,
+                    current: Some(Date::new(2023, 6, 1)),
+                },
+                Change::ToolchainAdded {
+                    version: version.clone(),
+                    toolchain: toolchain("wasm32-unknown-unknown"),
+                },
+                Change::ToolchainRemoved {
+                    version,
+                    toolchain: toolchain("x86_64-unknown-linux-gnu"),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn index_changes_of_all_channels() {
+        let beta = |n| RustRelease::new(Beta::prerelease(RustVersion::new(1, 71, 0), n), None, []);
+
+        let previous = RustReleases::new(
+            vec![stable(69, None, &[])].into_iter().collect(),
+            vec![beta(1)].into_iter().collect::<BetaReleases>(),
+            NightlyReleases::default(),
+            Default::default(),
+        );
+        let current = RustReleases::new(
+            vec![stable(69, None, &[]), stable(70, None, &[])]
+                .into_iter()
+                .collect(),
+            vec![beta(1), beta(2)].into_iter().collect::<BetaReleases>(),
+            NightlyReleases::default(),
+            Default::default(),
+        );
+
+        let published = current
+            .changes_since(&previous)
+            .iter()
+            .map(|change| change.version().clone())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            published,
+            vec![
+                ReleaseVersion::Stable(Stable::new(1, 70, 0)),
+                ReleaseVersion::Beta(Beta::prerelease(RustVersion::new(1, 71, 0), 2)),
+            ]
+        );
+    }
+}
@@ -1,66 +1,25 @@
-use std::convert::TryFrom;
-use std::fmt::{Display, Formatter};
-
-/// Enumerates the Rust release channels
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
-pub enum Channel {
-    /// An identifier for the `stable` release channel
-    Stable,
-    /// An identifier for the `beta` release channel
-    Beta,
-    /// An identifier for the `nightly` release channel
-    Nightly,
-}
-
-impl TryFrom<&str> for Channel {
-    type Error = Error;
-
-    fn try_from(item: &str) -> Result<Self, Self::Error> {
-        Ok(match item {
-            "beta" => Self::Beta,
-            "nightly" => Self::Nightly,
-            "stable" => Self::Stable,
-            unsupported => {
-                return Err(Error {
-                    channel: unsupported.to_string(),
-                })
-            }
-        })
-    }
-}
-
-impl From<Channel> for &str {
-    fn from(channel: Channel) -> Self {
-        match channel {
-            Channel::Beta => "beta",
-            Channel::Nightly => "nightly",
-            Channel::Stable => "stable",
-        }
-    }
-}
-
-impl Display for Channel {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let channel: Channel = *self;
-        f.write_str(channel.into())
-    }
-}
-
-#[derive(Debug, thiserror::Error)]
-#[error("No such channel: `{}`", .channel)]
-pub struct Error {
-    channel: String,
-}
+/// Enumerates the Rust release channels.
+///
+/// Defined in the `rust-release` crate as [`ReleaseChannel`], which is returned by
+/// [`ReleaseVersion::channel`].
+///
+/// [`ReleaseChannel`]: rust_release::ReleaseChannel
+/// [`ReleaseVersion::channel`]: rust_release::ReleaseVersion::channel
+pub use rust_release::ReleaseChannel as Channel;
+/// Returned when a name which is not a release channel is converted into a [`Channel`].
+pub use rust_release::UnknownChannelError as Error;
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::convert::TryFrom;
     use yare::parameterized;
 
     #[parameterized(
         beta = { "beta", Channel::Beta },
         nightly = { "nightly", Channel::Nightly },
         stable = { "stable", Channel::Stable },
+        alpha = { "alpha", Channel::Alpha },
     )]
     fn channel_from_str(input: &str, expected: Channel) {
         assert_eq!(Channel::try_from(input).unwrap(), expected);
@@ -70,6 +29,7 @@ mod tests {
         beta = { Channel::Beta, "beta" },
         nightly = { Channel::Nightly, "nightly" },
         stable = { Channel::Stable, "stable" },
+        alpha = { Channel::Alpha, "alpha" },
     )]
     fn channel_into_str(input: Channel, expected: &str) {
         assert_eq!(Into::<&str>::into(input), expected);
@@ -0,0 +1,517 @@
+//! Renders the latest releases of an index as an Atom, RSS or JSON Feed document.
+//!
+//! The entries of a feed are the latest releases, by release date, of the chosen channels.
+//! Releases of which the release date is not known are not included.
+//!
+//! The content of an entry may be supplied by a lookup, e.g. from the release notes in the
+//! official Rust changelog. Otherwise, the content is a short summary of the release.
+
+use crate::channel::Channel;
+use crate::json;
+use crate::{ReleaseVersion, RustRelease, RustReleases};
+use rust_release::date::Date;
+use std::fmt::{self, Write};
+
+/// The document format of a feed
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum FeedFormat {
+    /// An [Atom](https://www.rfc-editor.org/rfc/rfc4287) feed
+    Atom,
+    /// An [RSS 2.0](https://www.rssboard.org/rss-specification) feed
+    Rss,
+    /// A [JSON Feed 1.1](https://www.jsonfeed.org/version/1.1/) feed
+    Json,
+}
+
+/// Describes a feed of the latest releases of an index.
+///
+/// # Example
+///
+/// ```
+/// use rust_releases_core::channel::Channel;
+/// use rust_releases_core::feed::{Feed, FeedFormat};
+/// use rust_releases_core::{RustReleases, Stable, StableReleases};
+/// use rust_release::RustRelease;
+/// use rust_release::date::Date;
+///
+/// let stable = vec![RustRelease::new(Stable::new(1, 70, 0), Some(Date::new(2023, 6, 1)), [])]
+///     .into_iter()
+///     .collect::<StableReleases>();
+/// let index = RustReleases::new(stable, Default::default(), Default::default(), Default::default());
+///
+/// let feed = Feed::new("Rust releases", "https://example.com/rust-releases.xml")
+///     .channels(vec![Channel::Stable, Channel::Beta])
+///     .limit(5);
+///
+/// let atom = feed.render_with_notes(&index, FeedFormat::Atom, |_version| {
+///     Some("- Stabilized `OnceCell`")
+/// });
+///
+/// assert!(atom.contains("<title>Rust 1.70.0</title>"));
+/// assert!(atom.contains("<updated>2023-06-01T00:00:00Z</updated>"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Feed {
+    title: String,
+    link: String,
+    author: String,
+    channels: Vec<Channel>,
+    limit: usize,
+}
+
+impl Feed {
+    /// Describe a feed of the latest ten stable releases.
+    ///
+    /// The `link` is the location at which the feed is published. It also serves as the
+    /// identifier of the feed, and of its entries.
+    pub fn new(title: impl Into<String>, link: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            link: link.into(),
+            author: "The Rust Project Developers".to_string(),
+            channels: vec![Channel::Stable],
+            limit: 10,
+        }
+    }
+
+    /// Include the releases of the given channels.
+    pub fn channels(self, channels: impl IntoIterator<Item = Channel>) -> Self {
+        Self {
+            channels: channels.into_iter().collect(),
+            ..self
+        }
+    }
+
+    /// Include at most the given number of releases.
+    pub fn limit(self, limit: usize) -> Self {
+        Self { limit, ..self }
+    }
+
+    /// Use a different author for the feed.
+    pub fn author(self, author: impl Into<String>) -> Self {
+        Self {
+            author: author.into(),
+            ..self
+        }
+    }
+
+    /// The releases included in the feed, the latest release first.
+    pub fn releases(&self, index: &RustReleases) -> Vec<RustRelease<ReleaseVersion>> {
+        let releases = index
+            .timeline()
+            .filter(|release| release.release_date().is_some())
+            .filter(|release| self.channels.contains(&release.version().channel()))
+            .collect::<Vec<_>>();
+
+        releases.into_iter().rev().take(self.limit).collect()
+    }
+
+    /// Render the feed, where the content of each entry is a short summary of the release.
+    pub fn render(&self, index: &RustReleases, format: FeedFormat) -> String {
+        self.render_with_notes(index, format, |_| None)
+    }
+
+    /// Render the feed, where the content of each entry is looked up by `notes`, e.g. in the
+    /// release notes of the official Rust changelog. Entries for which `notes` returns `None`
+    /// fall back to a short summary of the release.
+    pub fn render_with_notes<'notes, F>(
+        &self,
+        index: &RustReleases,
+        format: FeedFormat,
+        notes: F,
+    ) -> String
+    where
+        F: Fn(&ReleaseVersion) -> Option<&'notes str>,
+    {
+        let entries = self
+            .releases(index)
+            .into_iter()
+            .map(|release| Entry::new(self, release, &notes))
+            .collect::<Vec<_>>();
+
+        let mut out = String::new();
+        let written = match format {
+            FeedFormat::Atom => self.write_atom(&entries, &mut out),
+            FeedFormat::Rss => self.write_rss(&entries, &mut out),
+            FeedFormat::Json => self.write_json(&entries, &mut out),
+        };
+        written.expect("writing to a String does not fail");
+
+        out
+    }
+
+    fn write_atom(&self, entries: &[Entry], out: &mut String) -> fmt::Result {
+        let updated = entries.first().map_or_else(
+            || "1970-01-01T00:00:00Z".to_string(),
+            |entry| rfc3339(&entry.date),
+        );
+
+        writeln!(out, r#"<?xml version="1.0" encoding="utf-8"?>"#)?;
+        writeln!(out, r#"<feed xmlns="http://www.w3.org/2005/Atom">"#)?;
+        writeln!(out, "  <title>{}</title>", xml(&self.title))?;
+        writeln!(out, "  <id>{}</id>", xml(&self.link))?;
+        writeln!(out, r#"  <link rel="self" href="{}"/>"#, xml(&self.link))?;
+        writeln!(out, "  <updated>{}</updated>", updated)?;
+        writeln!(out, "  <author><name>{}</name></author>", xml(&self.author))?;
+
+        for entry in entries {
+            writeln!(out, "  <entry>")?;
+            writeln!(out, "    <title>{}</title>", xml(&entry.title))?;
+            writeln!(out, "    <id>{}</id>", xml(&entry.id))?;
+            writeln!(out, "    <updated>{}</updated>", rfc3339(&entry.date))?;
+            writeln!(out, r#"    <category term="{}"/>"#, entry.channel)?;
+            writeln!(
+                out,
+                r#"    <content type="text">{}</content>"#,
+                xml(&entry.content)
+            )?;
+            writeln!(out, "  </entry>")?;
+        }
+
+        writeln!(out, "</feed>")
+    }
+
+    fn write_rss(&self, entries: &[Entry], out: &mut String) -> fmt::Result {
+        writeln!(out, r#"<?xml version="1.0" encoding="utf-8"?>"#)?;
+        writeln!(out, r#"<rss version="2.0">"#)?;
+        writeln!(out, "  <channel>")?;
+        writeln!(out, "    <title>{}</title>", xml(&self.title))?;
+        writeln!(out, "    <link>{}</link>", xml(&self.link))?;
+        writeln!(out, "    <description>{}</description>", xml(&self.title))?;
+
+        if let Some(entry) = entries.first() {
+            writeln!(
+                out,
+                "    <lastBuildDate>{}</lastBuildDate>",
+                rfc822(&entry.date)
+            )?;
+        }
+
+        for entry in entries {
+            writeln!(out, "    <item>")?;
+            writeln!(out, "      <title>{}</title>", xml(&entry.title))?;
+            writeln!(
+                out,
+                r#"      <guid isPermaLink="false">{}</guid>"#,
+                xml(&entry.id)
+            )?;
+            writeln!(out, "      <pubDate>{}</pubDate>", rfc822(&entry.date))?;
+            writeln!(out, "      <category>{}</category>", entry.channel)?;
+            writeln!(
+                out,
+                "      <description>{}</description>",
+                xml(&entry.content)
+            )?;
+            writeln!(out, "    </item>")?;
+        }
+
+        writeln!(out, "  </channel>")?;
+        writeln!(out, "</rss>")
+    }
+
+    fn write_json(&self, entries: &[Entry], out: &mut String) -> fmt::Result {
+        writeln!(out, "{{")?;
+        writeln!(out, r#"  "version": "https://jsonfeed.org/version/1.1","#)?;
+        writeln!(out, r#"  "title": {},"#, json::string(&self.title))?;
+        writeln!(out, r#"  "feed_url": {},"#, json::string(&self.link))?;
+        writeln!(
+            out,
+            r#"  "authors": [{{ "name": {} }}],"#,
+            json::string(&self.author)
+        )?;
+        write!(out, r#"  "items": ["#)?;
+
+        for (i, entry) in entries.iter().enumerate() {
+            let separator = if i == 0 { "" } else { "," };
+
+            writeln!(out, "{}", separator)?;
+            writeln!(out, "    {{")?;
+            writeln!(out, r#"      "id": {},"#, json::string(&entry.id))?;
+            writeln!(out, r#"      "title": {},"#, json::string(&entry.title))?;
+            writeln!(
+                out,
+                r#"      "date_published": "{}","#,
+                rfc3339(&entry.date)
+            )?;
+            writeln!(out, r#"      "tags": ["{}"],"#, entry.channel)?;
+            writeln!(
+                out,
+                r#"      "content_text": {}"#,
+                json::string(&entry.content)
+            )?;
+            write!(out, "    }}")?;
+        }
+
+        if !entries.is_empty() {
+            writeln!(out)?;
+            write!(out, "  ")?;
+        }
+
+        writeln!(out, "]")?;
+        writeln!(out, "}}")
+    }
+}
+
+// A release, as presented in a feed
+struct Entry {
+    id: String,
+    title: String,
+    date: Date,
+    channel: Channel,
+    content: String,
+}
+
+impl Entry {
+    fn new<'notes, F>(feed: &Feed, release: RustRelease<ReleaseVersion>, notes: &F) -> Self
+    where
+        F: Fn(&ReleaseVersion) -> Option<&'notes str>,
+    {
+        let version = release.version();
+        let title = format!("Rust {}", version);
+        let date = release
+            .release_date()
+            .cloned()
+            .expect("releases without a release date are not part of a feed");
+
+        let content = notes(version).map_or_else(
+            || format!("{} was released on {}.", title, date),
+            str::to_string,
+        );
+
+        Self {
+            id: format!("{}#{}", feed.link, version),
+            title,
+            date,
+            channel: version.channel(),
+            content,
+        }
+    }
+}
+
+fn rfc3339(date: &Date) -> String {
+    format!("{}T00:00:00Z", date)
+}
+
+// The day of the week is optional, and is omitted for dates which are not valid
+fn rfc822(date: &Date) -> String {
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+
+    let weekday = date
+        .weekday()
+        .map(|weekday| format!("{}, ", &weekday.to_string()[..3]))
+        .unwrap_or_default();
+    let month = MONTHS
+        .get(usize::from(date.month()).wrapping_sub(1))
+        .unwrap_or(&"Jan");
+
+    format!(
+        "{}{:02} {} {:04} 00:00:00 +0000",
+        weekday,
+        date.day(),
+        month,
+        date.year()
+    )
+}
+
+// Escapes the markup characters, and replaces the characters which XML 1.0 does not allow in a
+// document, even as a character reference, with U+FFFD
+fn xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\t' | '\n' | '\r' => escaped.push(c),
+            '\u{0}'..='\u{1f}' | '\u{fffe}' | '\u{ffff}' => {
+                escaped.push(char::REPLACEMENT_CHARACTER)
+            }
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::index;
+    use crate::{Stable, StableReleases};
+    use yare::parameterized;
+
+    fn feed() -> Feed {
+        Feed::new("Rust releases", "https://example.com/feed")
+    }
+
+    fn titles(feed: &Feed) -> Vec<String> {
+        feed.releases(&index())
+            .iter()
+            .map(|release| release.version().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn latest_releases_first() {
+        assert_eq!(
+            titles(&feed()),
+            vec!["1.71.0", "1.70.1", "1.70.0", "1.69.0"]
+        );
+        assert_eq!(titles(&feed().limit(1)), vec!["1.71.0"]);
+        assert_eq!(
+            titles(
+                &feed()
+                    .channels(vec![Channel::Stable, Channel::Beta])
+                    .limit(4)
+            ),
+            vec!["1.71.0", "1.70.1", "1.71.0-beta.2", "1.71.0-beta.1"]
+        );
+    }
+
+    #[test]
+    fn undated_releases_are_excluded() {
+        let stable = vec![
+            RustRelease::new(Stable::new(1, 70, 0), Some(Date::new(2023, 6, 1)), []),
+            RustRelease::new(Stable::new(1, 71, 0), None, []),
+        ]
+        .into_iter()
+        .collect::<StableReleases>();
+        let index = RustReleases::new(
+            stable,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        );
+
+        let releases = feed().releases(&index);
+
+        assert_eq!(releases.len(), 1);
+        assert_eq!(
+            releases[0].version(),
+            &ReleaseVersion::Stable(Stable::new(1, 70, 0))
+        );
+    }
+
+    #[test]
+    fn atom() {
+        let atom = feed()
+            .limit(1)
+            .render_with_notes(&index(), FeedFormat::Atom, |_| Some("- `a` < `b` & c"));
+
+        let expected = r#"<?xml version="1.0" encoding="utf-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+  <title>Rust releases</title>
+  <id>https://example.com/feed</id>
+  <link rel="self" href="https://example.com/feed"/>
+  <updated>2023-07-13T00:00:00Z</updated>
+  <author><name>The Rust Project Developers</name></author>
+  <entry>
+    <title>Rust 1.71.0</title>
+    <id>https://example.com/feed#1.71.0</id>
+    <updated>2023-07-13T00:00:00Z</updated>
+    <category term="stable"/>
+    <content type="text">- `a` &lt; `b` &amp; c</content>
+  </entry>
+</feed>
+"#;
+
+        assert_eq!(atom, expected);
+    }
+
+    #[test]
+    fn rss() {
+        let rss = feed().limit(1).render(&index(), FeedFormat::Rss);
+
+        let expected = r#"<?xml version="1.0" encoding="utf-8"?>
+<rss version="2.0">
+  <channel>
+    <title>Rust releases</title>
+    <link>https://example.com/feed</link>
+    <description>Rust releases</description>
+    <lastBuildDate>Thu, 13 Jul 2023 00:00:00 +0000</lastBuildDate>
+    <item>
+      <title>Rust 1.71.0</title>
+      <guid isPermaLink="false">https://example.com/feed#1.71.0</guid>
+      <pubDate>Thu, 13 Jul 2023 00:00:00 +0000</pubDate>
+      <category>stable</category>
+      <description>Rust 1.71.0 was released on 2023-07-13.</description>
+    </item>
+  </channel>
+</rss>
+"#;
+
+        assert_eq!(rss, expected);
+    }
+
+    #[test]
+    fn json_feed() {
+        let feed = feed()
+            .channels(vec![Channel::Nightly, Channel::Beta])
+            .limit(2);
+        let json = feed.render_with_notes(&index(), FeedFormat::Json, |version| match version {
+            ReleaseVersion::Beta(_) => Some("\"quoted\"\n\tnotes"),
+            _ => None,
+        });
+
+        let expected = r#"{
+  "version": "https://jsonfeed.org/version/1.1",
+  "title": "Rust releases",
+  "feed_url": "https://example.com/feed",
+  "authors": [{ "name": "The Rust Project Developers" }],
+  "items": [
+    {
+      "id": "https://example.com/feed#1.71.0-beta.2",
+      "title": "Rust 1.71.0-beta.2",
+      "date_published": "2023-06-09T00:00:00Z",
+      "tags": ["beta"],
+      "content_text": "\"quoted\"\n\tnotes"
+    },
+    {
+      "id": "https://example.com/feed#nightly-2023-06-02",
+      "title": "Rust nightly-2023-06-02",
+      "date_published": "2023-06-02T00:00:00Z",
+      "tags": ["nightly"],
+      "content_text": "Rust nightly-2023-06-02 was released on 2023-06-02."
+    }
+  ]
+}
+"#;
+
+        assert_eq!(json, expected);
+    }
+
+    #[parameterized(
+        atom = { FeedFormat::Atom, "<updated>1970-01-01T00:00:00Z</updated>" },
+        rss = { FeedFormat::Rss, "</channel>" },
+        json = { FeedFormat::Json, r#""items": []"# },
+    )]
+    fn empty(format: FeedFormat, expected: &str) {
+        let rendered = feed().limit(0).render(&index(), format);
+
+        assert!(rendered.contains(expected), "{}", rendered);
+    }
+
+    #[parameterized(
+        valid = { Date::new(2015, 5, 15), "Fri, 15 May 2015 00:00:00 +0000" },
+        invalid = { Date::new(2015, 2, 30), "30 Feb 2015 00:00:00 +0000" },
+    )]
+    fn rfc822_date(date: Date, expected: &str) {
+        assert_eq!(rfc822(&date), expected);
+    }
+
+    #[parameterized(
+        markup = { "<a href=\"x\">'&'</a>", "&lt;a href=&quot;x&quot;&gt;&apos;&amp;&apos;&lt;/a&gt;" },
+        whitespace = { "a\tb\r\nc", "a\tb\r\nc" },
+        control_characters = { "a\u{0}b\u{1b}[0mc\u{1f}", "a\u{fffd}b\u{fffd}[0mc\u{fffd}" },
+        noncharacters = { "a\u{fffe}b\u{ffff}", "a\u{fffd}b\u{fffd}" },
+        non_ascii = { "ü\u{a0}\u{7f}", "ü\u{a0}\u{7f}" },
+    )]
+    fn xml_text(text: &str, expected: &str) {
+        assert_eq!(xml(text), expected);
+    }
+}
@@ -0,0 +1,69 @@
+//! Fixtures shared by the tests of this crate.
+
+use crate::{
+    Alpha, AlphaReleases, Beta, BetaReleases, Nightly, NightlyReleases, RustRelease, RustReleases,
+    Stable, StableReleases,
+};
+use rust_release::date::Date;
+use rust_release::toolchain::{Channel, RustVersion, Target, Toolchain};
+use std::collections::HashSet;
+
+/// A `1.70.0` toolchain for the given target, without components.
+pub(crate) fn toolchain(target: &str) -> Toolchain {
+    Toolchain::new(
+        Channel::stable(RustVersion::new(1, 70, 0)),
+        None,
+        Target::from_target_triple_or_unknown(target),
+        HashSet::new(),
+        HashSet::new(),
+    )
+}
+
+/// An index with a few releases of each channel, all with a release date:
+///
+/// * stable: `1.69.0`, `1.70.0`, `1.70.1` and `1.71.0`
+/// * beta: `1.71.0-beta.1` and `1.71.0-beta.2`
+/// * nightly: `nightly-2023-06-01` and `nightly-2023-06-02`
+/// * alpha: `0.10.0` and `0.12.0`
+pub(crate) fn index() -> RustReleases {
+    let stable = vec![
+        RustRelease::new(Stable::new(1, 69, 0), Some(Date::new(2023, 4, 20)), []),
+        RustRelease::new(Stable::new(1, 70, 0), Some(Date::new(2023, 6, 1)), []),
+        RustRelease::new(Stable::new(1, 70, 1), Some(Date::new(2023, 6, 20)), []),
+        RustRelease::new(Stable::new(1, 71, 0), Some(Date::new(2023, 7, 13)), []),
+    ];
+
+    let beta = vec![
+        RustRelease::new(beta(1), Some(Date::new(2023, 6, 2)), []),
+        RustRelease::new(beta(2), Some(Date::new(2023, 6, 9)), []),
+    ];
+
+    let nightly = vec![
+        RustRelease::new(nightly(1), Some(Date::new(2023, 6, 1)), []),
+        RustRelease::new(nightly(2), Some(Date::new(2023, 6, 2)), []),
+    ];
+
+    let alpha = vec![
+        RustRelease::new(Alpha::new(0, 10, 0), Some(Date::new(2014, 1, 9)), []),
+        RustRelease::new(Alpha::new(0, 12, 0), Some(Date::new(2014, 10, 9)), []),
+    ];
+
+    RustReleases::new(
+        stable.into_iter().collect::<StableReleases>(),
+        beta.into_iter().collect::<BetaReleases>(),
+        nightly.into_iter().collect::<NightlyReleases>(),
+        alpha.into_iter().collect::<AlphaReleases>(),
+    )
+}
+
+/// The `1.71.0-beta.N` pre-release
+pub(crate) fn beta(prerelease: u32) -> Beta {
+    Beta::prerelease(RustVersion::new(1, 71, 0), prerelease)
+}
+
+/// The nightly of the given day in June 2023
+pub(crate) fn nightly(day: u8) -> Nightly {
+    Nightly {
+        date: Date::new(2023, 6, day),
+    }
+}
@@ -0,0 +1,190 @@
+//! Compares installed toolchains, e.g. those discovered in a rustup home, against a
+//! [`RustReleases`] index, to find which of them are missing from the index, outdated,
+//! or superseded by a newer patch release.
+//!
+//! [`RustReleases`]: crate::RustReleases
+
+use crate::{ResolvedRelease, RustRelease, RustReleases, Stable};
+use rust_release::toolchain::Toolchain;
+
+/// The status of an installed toolchain, compared to a [`RustReleases`] index.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum InstalledStatus<'r> {
+    /// The toolchain is the newest release of its channel
+    Latest,
+    /// The toolchain is a stable release, for which a newer patch release exists, e.g. `1.70.1`
+    /// for `1.70.0`
+    Superseded(&'r RustRelease<Stable>),
+    /// A newer release of the same channel exists, e.g. `1.71.0` for `1.70.0`, or a newer
+    /// nightly
+    Outdated(ResolvedRelease<'r>),
+    /// The index does not contain the release of the toolchain
+    Missing,
+    /// The toolchain is a custom toolchain, which is not released by the Rust project
+    Custom,
+}
+
+/// An installed toolchain, and its status compared to a [`RustReleases`] index.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct InstalledRelease<'t, 'r> {
+    toolchain: &'t Toolchain,
+    status: InstalledStatus<'r>,
+}
+
+impl<'t, 'r> InstalledRelease<'t, 'r> {
+    /// The installed toolchain
+    pub fn toolchain(&self) -> &'t Toolchain {
+        self.toolchain
+    }
+
+    /// The status of the installed toolchain
+    pub fn status(&self) -> InstalledStatus<'r> {
+        self.status
+    }
+}
+
+impl RustReleases {
+    /// Compare the installed toolchain against the releases in this index.
+    ///
+    /// A stable toolchain for which a newer patch release exists is reported as
+    /// [`InstalledStatus::Superseded`], even if a newer minor release exists too, since
+    /// updating to the patch release does not change the language version.
+    pub fn installed_status(&self, toolchain: &Toolchain) -> InstalledStatus<'_> {
+        let channel = toolchain.channel();
+
+        if channel.is_custom() {
+            return InstalledStatus::Custom;
+        }
+
+        let installed = match self.resolve_exact(channel) {
+            Some(installed) => installed,
+            None => return InstalledStatus::Missing,
+        };
+
+        // Releases are iterated in ascending order, so the last release is the newest.
+        let newest = match installed {
+            ResolvedRelease::Stable(release) => {
+                let version = release.version().version;
+                let patch = self
+                    .stable()
+                    .into_iter()
+                    .filter(|candidate| {
+                        let candidate = candidate.version().version;
+                        candidate.major() == version.major() && candidate.minor() == version.minor()
+                    })
+                    .last();
+
+                if let Some(patch) = patch.filter(|patch| patch.version() != release.version()) {
+                    return InstalledStatus::Superseded(patch);
+                }
+
+                self.stable()
+                    .into_iter()
+                    .last()
+                    .map(ResolvedRelease::Stable)
+            }
+            ResolvedRelease::Beta(_) => self.beta().into_iter().last().map(ResolvedRelease::Beta),
+            ResolvedRelease::Nightly(_) => self
+                .nightly()
+                .into_iter()
+                .last()
+                .map(ResolvedRelease::Nightly),
+            ResolvedRelease::Alpha(_) => {
+                self.alpha().into_iter().last().map(ResolvedRelease::Alpha)
+            }
+        };
+
+        match newest {
+            Some(newest) if newest != installed => InstalledStatus::Outdated(newest),
+            _ => InstalledStatus::Latest,
+        }
+    }
+
+    /// Compare each of the installed toolchains against the releases in this index.
+    ///
+    /// See [`RustReleases::installed_status`].
+    pub fn compare_installed<'t>(
+        &self,
+        toolchains: impl IntoIterator<Item = &'t Toolchain>,
+    ) -> Vec<InstalledRelease<'t, '_>> {
+        toolchains
+            .into_iter()
+            .map(|toolchain| InstalledRelease {
+                toolchain,
+                status: self.installed_status(toolchain),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{beta, index, nightly};
+    use rust_release::toolchain::ToolchainSpec;
+    use rust_release::ReleaseVersion;
+    use std::convert::TryFrom;
+    use yare::parameterized;
+
+    fn toolchain(name: &str) -> Toolchain {
+        let spec = ToolchainSpec::parse_or_custom(name).unwrap();
+
+        Toolchain::try_from(spec).unwrap()
+    }
+
+    fn stable(major: u64, minor: u64, patch: u64) -> Option<ReleaseVersion> {
+        Some(ReleaseVersion::Stable(Stable::new(major, minor, patch)))
+    }
+
+    // The kind of status, and the version of the newer release, if any
+    fn summary(status: InstalledStatus<'_>) -> (&'static str, Option<ReleaseVersion>) {
+        match status {
+            InstalledStatus::Latest => ("latest", None),
+            InstalledStatus::Superseded(release) => (
+                "superseded",
+                Some(ReleaseVersion::Stable(release.version().clone())),
+            ),
+            InstalledStatus::Outdated(release) => ("outdated", Some(release.version())),
+            InstalledStatus::Missing => ("missing", None),
+            InstalledStatus::Custom => ("custom", None),
+        }
+    }
+
+    #[parameterized(
+        stable_latest = { "1.71.0", "latest", None },
+        stable_superseded = { "1.70.0", "superseded", stable(1, 70, 1) },
+        stable_outdated = { "1.70.1", "outdated", stable(1, 71, 0) },
+        stable_outdated_without_patch = { "1.69.0", "outdated", stable(1, 71, 0) },
+        stable_missing = { "1.68.0", "missing", None },
+        beta_latest = { "1.71.0-beta.2", "latest", None },
+        beta_outdated = { "1.71.0-beta.1", "outdated", Some(ReleaseVersion::Beta(beta(2))) },
+        nightly_latest = { "nightly-2023-06-02", "latest", None },
+        nightly_outdated = { "nightly-2023-06-01", "outdated", Some(ReleaseVersion::Nightly(nightly(2))) },
+        nightly_missing = { "nightly-2023-05-01", "missing", None },
+        custom = { "stage2", "custom", None },
+    )]
+    fn installed_status(name: &str, expected: &str, newer: Option<ReleaseVersion>) {
+        let index = index();
+
+        let status = index.installed_status(&toolchain(name));
+
+        assert_eq!(summary(status), (expected, newer));
+    }
+
+    #[test]
+    fn compare_installed() {
+        let index = index();
+        let toolchains = vec![toolchain("1.70.0"), toolchain("nightly-2023-06-02")];
+
+        let report = index.compare_installed(&toolchains);
+
+        assert_eq!(report.len(), 2);
+        assert_eq!(report[0].toolchain(), &toolchains[0]);
+        assert_eq!(
+            summary(report[0].status()),
+            ("superseded", stable(1, 70, 1))
+        );
+        assert_eq!(report[1].toolchain(), &toolchains[1]);
+        assert_eq!(report[1].status(), InstalledStatus::Latest);
+    }
+}
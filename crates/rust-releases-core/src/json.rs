@@ -0,0 +1,44 @@
+//! Writes JSON values, for the documents rendered from an index, like a JSON Feed.
+
+use std::fmt::Write;
+
+/// Format the text as a JSON string, i.e. quoted, with quotes, backslashes and control
+/// characters escaped.
+pub fn string(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len() + 2);
+    escaped.push('"');
+
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(escaped, "\\u{:04x}", u32::from(c));
+            }
+            c => escaped.push(c),
+        }
+    }
+
+    escaped.push('"');
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use yare::parameterized;
+
+    #[parameterized(
+        plain = { "1.70.0", r#""1.70.0""# },
+        quotes = { r#"a "b""#, r#""a \"b\"""# },
+        backslash = { r"a\b", r#""a\\b""# },
+        whitespace = { "a\n\tb\r", r#""a\n\tb\r""# },
+        control = { "a\u{1}", r#""a\u0001""# },
+    )]
+    fn escape(text: &str, expected: &str) {
+        assert_eq!(string(text), expected);
+    }
+}
@@ -8,22 +8,56 @@
 #![deny(clippy::all)]
 #![deny(unsafe_code)]
 
-pub use crate::releases::{BetaReleases, NightlyReleases, StableReleases};
+pub use crate::releases::{AlphaReleases, BetaReleases, NightlyReleases, Releases, StableReleases};
 /// Defines release channels, such as the stable, beta and nightly release channels.
-pub use rust_release::{self, Beta, Nightly, RustRelease, Stable};
+pub use rust_release::{self, Alpha, Beta, Nightly, ReleaseVersion, RustRelease, Stable};
 
+pub mod availability;
+pub mod changes;
 pub mod channel;
+pub mod feed;
+pub mod installed;
+pub mod json;
 pub mod merge;
 pub mod releases;
+pub mod resolve;
+pub mod snapshot;
+pub mod train;
+
+#[cfg(test)]
+mod fixtures;
+
+pub use crate::availability::Availability;
+pub use crate::changes::Change;
+pub use crate::installed::{InstalledRelease, InstalledStatus};
+pub use crate::resolve::ResolvedRelease;
+pub use crate::snapshot::SnapshotError;
+pub use crate::train::NightlyRange;
 
 #[derive(Debug, Default)]
 pub struct RustReleases {
     stable: StableReleases,
     beta: BetaReleases,
     nightly: NightlyReleases,
+    alpha: AlphaReleases,
 }
 
 impl RustReleases {
+    /// Create a new index from the releases of each channel
+    pub fn new(
+        stable: StableReleases,
+        beta: BetaReleases,
+        nightly: NightlyReleases,
+        alpha: AlphaReleases,
+    ) -> Self {
+        Self {
+            stable,
+            beta,
+            nightly,
+            alpha,
+        }
+    }
+
     /// Iterate over set of stable releases
     pub fn stable(&self) -> impl IntoIterator<Item = &RustRelease<Stable>> {
         self.stable.iter()
@@ -38,11 +72,85 @@ impl RustReleases {
     pub fn nightly(&self) -> impl IntoIterator<Item = &RustRelease<Nightly>> {
         self.nightly.iter()
     }
+
+    /// Iterate over set of historic alpha releases
+    pub fn alpha(&self) -> impl IntoIterator<Item = &RustRelease<Alpha>> {
+        self.alpha.iter()
+    }
+
+    /// Iterate over the releases of all channels, ordered by version.
+    ///
+    /// Unlike the `Ord` implementation of [`ReleaseVersion`], which places nightly releases
+    /// after all versioned releases, a nightly release is placed relative to its release train:
+    /// after the releases of the preceding version, and before the beta and stable releases of
+    /// the version it was building toward. For example, `nightly-2024-02-10`, which was building
+    /// toward `1.78.0`, follows `1.77.0` and precedes `1.78.0-beta.1`. Nightly releases which
+    /// precede the releases in the index, such that their release train is not known, are
+    /// placed first.
+    ///
+    /// See [`RustReleases::release_train`].
+    pub fn all(&self) -> impl Iterator<Item = RustRelease<ReleaseVersion>> {
+        let branch_dates = self.branch_dates();
+
+        let mut releases = self
+            .erased()
+            .map(|release| {
+                (
+                    train::train_order(&branch_dates, release.version()),
+                    release,
+                )
+            })
+            .collect::<Vec<_>>();
+        releases.sort_by(|(lhs_order, lhs), (rhs_order, rhs)| {
+            lhs_order
+                .cmp(rhs_order)
+                .then_with(|| lhs.version().cmp(rhs.version()))
+        });
+
+        releases.into_iter().map(|(_, release)| release)
+    }
+
+    /// Iterate over the releases of all channels, ordered by their release date, i.e. a single
+    /// timeline of all channels.
+    ///
+    /// Releases which were released on the same date are ordered by their [`ReleaseVersion`].
+    /// Releases of which the release date is not known are placed last.
+    pub fn timeline(&self) -> impl Iterator<Item = RustRelease<ReleaseVersion>> {
+        let mut releases = self.erased().collect::<Vec<_>>();
+        releases.sort_by(|lhs, rhs| {
+            let key = |release: &RustRelease<ReleaseVersion>| {
+                (
+                    release.release_date().is_none(),
+                    release.release_date().cloned(),
+                )
+            };
+
+            key(lhs)
+                .cmp(&key(rhs))
+                .then_with(|| lhs.version().cmp(rhs.version()))
+        });
+
+        releases.into_iter()
+    }
+
+    fn erased(&self) -> impl Iterator<Item = RustRelease<ReleaseVersion>> + '_ {
+        let stable = self.stable.iter().cloned();
+        let beta = self.beta.iter().cloned();
+        let nightly = self.nightly.iter().cloned();
+        let alpha = self.alpha.iter().cloned();
+
+        stable
+            .map(|release| release.map_version(ReleaseVersion::Stable))
+            .chain(beta.map(|release| release.map_version(ReleaseVersion::Beta)))
+            .chain(nightly.map(|release| release.map_version(ReleaseVersion::Nightly)))
+            .chain(alpha.map(|release| release.map_version(ReleaseVersion::Alpha)))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fixtures::index;
     use crate::merge;
 
     #[test]
@@ -58,6 +166,92 @@ mod tests {
         assert!(out.is_empty());
     }
 
+    #[test]
+    fn all_by_version() {
+        let versions = index()
+            .all()
+            .map(|release| release.version().to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            versions,
+            vec![
+                "0.10.0",
+                "0.12.0",
+                "1.69.0",
+                "1.70.0",
+                "1.70.1",
+                "nightly-2023-06-01",
+                "1.71.0-beta.1",
+                "1.71.0-beta.2",
+                "1.71.0",
+                "nightly-2023-06-02",
+            ]
+        );
+    }
+
+    #[test]
+    fn untrained_and_undated_nightly() {
+        use rust_release::date::Date;
+
+        let nightly = Nightly {
+            date: Date::new(2015, 1, 1),
+        };
+        let index = RustReleases::new(
+            vec![RustRelease::new(
+                Stable::new(1, 80, 0),
+                Some(Date::new(2024, 7, 25)),
+                [],
+            )]
+            .into_iter()
+            .collect(),
+            Default::default(),
+            vec![RustRelease::new(nightly, None, [])]
+                .into_iter()
+                .collect(),
+            Default::default(),
+        );
+
+        let versions = index
+            .all()
+            .map(|release| release.version().to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(versions, vec!["nightly-2015-01-01", "1.80.0"]);
+
+        let timeline = index
+            .timeline()
+            .map(|release| release.version().to_string())
+            .collect::<Vec<_>>();
+
+        // The nightly is undated, so it is placed last
+        assert_eq!(timeline, vec!["1.80.0", "nightly-2015-01-01"]);
+    }
+
+    #[test]
+    fn timeline_by_release_date() {
+        let versions = index()
+            .timeline()
+            .map(|release| release.version().to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            versions,
+            vec![
+                "0.10.0",
+                "0.12.0",
+                "1.69.0",
+                "1.70.0",
+                "nightly-2023-06-01",
+                "1.71.0-beta.1",
+                "nightly-2023-06-02",
+                "1.71.0-beta.2",
+                "1.70.1",
+                "1.71.0",
+            ]
+        );
+    }
+
     #[test]
     fn base() {
         let left = RustRelease::new(Stable::new(1, 2, 0), None, []);
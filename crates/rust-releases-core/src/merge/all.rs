@@ -0,0 +1,384 @@
+//! Merges the releases of any number of sources in one pass.
+//!
+//! Where [`merge`] combines two releases at a time, the strategies in this module see all
+//! candidates for a version at once, e.g. to take a majority vote on the release date.
+//!
+//! The releases of all sources must share a context type. Releases with differing context
+//! types can be given a common context with [`RustRelease::map_context`].
+//!
+//! [`merge`]: crate::merge::merge
+
+use crate::merge::VersionMismatch;
+use rust_release::{date, toolchain, RustRelease};
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+
+/// Resolves how to combine the `release_date` field of all candidates of a version.
+pub trait MergeAllReleaseDates {
+    fn merge_all_release_dates(&self, candidates: Vec<Option<date::Date>>) -> Option<date::Date>;
+}
+
+/// Resolves how to combine the `toolchains` field of all candidates of a version.
+pub trait MergeAllToolchains {
+    fn merge_all_toolchains(
+        &self,
+        candidates: Vec<Vec<toolchain::Toolchain>>,
+    ) -> Vec<toolchain::Toolchain>;
+}
+
+/// Resolves how to combine the `context` field of all candidates of a version.
+pub trait MergeAllContexts<C> {
+    type Output;
+
+    fn merge_all_contexts(&self, candidates: Vec<C>) -> Self::Output;
+}
+
+/// Merges the candidates of a single version into one release.
+///
+/// The candidates are passed to the strategies in the given order.
+///
+/// Returns `Ok(None)` if there are no candidates, and [`VersionMismatch`] if a candidate has a
+/// different version than the first candidate.
+pub fn merge_candidates<V, C, D, T, X>(
+    candidates: Vec<RustRelease<V, C>>,
+    date_merge: &D,
+    toolchains_merge: &T,
+    context_merge: &X,
+) -> Result<Option<RustRelease<V, X::Output>>, VersionMismatch<V>>
+where
+    V: Eq + Debug,
+    D: MergeAllReleaseDates,
+    T: MergeAllToolchains,
+    X: MergeAllContexts<C>,
+{
+    let mut candidates = candidates.into_iter();
+    let first = match candidates.next() {
+        Some(first) => first,
+        None => return Ok(None),
+    };
+
+    let mut fields = Candidates::default();
+    fields.push(first.release_date, first.toolchains, first.context);
+
+    for candidate in candidates {
+        if candidate.version != first.version {
+            return Err(VersionMismatch {
+                left: first.version,
+                right: candidate.version,
+            });
+        }
+
+        fields.push(
+            candidate.release_date,
+            candidate.toolchains,
+            candidate.context,
+        );
+    }
+
+    Ok(Some(fields.merge(
+        first.version,
+        date_merge,
+        toolchains_merge,
+        context_merge,
+    )))
+}
+
+/// Merges the release collections of any number of sources.
+///
+/// The releases of all collections are grouped by version, and each group is merged with
+/// [`merge_candidates`], in the order of the collections. The merged releases are returned in
+/// ascending order, and can be collected into a release collection, like `StableReleases`.
+///
+/// # Example
+///
+/// ```
+/// use rust_releases_core::merge::all::merge_all;
+/// use rust_releases_core::merge::strategy::context::UnitContext;
+/// use rust_releases_core::merge::strategy::release_date::MajorityDate;
+/// use rust_releases_core::merge::strategy::toolchains::UnionToolchains;
+/// use rust_releases_core::StableReleases;
+/// use rust_release::{RustRelease, Stable};
+/// use rust_release::date::Date;
+///
+/// let source = |day| {
+///     vec![RustRelease::new(Stable::new(1, 70, 0), Some(Date::new(2023, 6, day)), [])]
+///         .into_iter()
+///         .collect::<StableReleases>()
+/// };
+///
+/// let merged = merge_all(
+///     vec![source(2), source(1), source(1)],
+///     &MajorityDate,
+///     &UnionToolchains,
+///     &UnitContext,
+/// )
+/// .into_iter()
+/// .collect::<StableReleases>();
+///
+/// let release = merged.iter().next().unwrap();
+/// assert_eq!(release.release_date(), Some(&Date::new(2023, 6, 1)));
+/// ```
+pub fn merge_all<V, C, I, D, T, X>(
+    collections: impl IntoIterator<Item = I>,
+    date_merge: &D,
+    toolchains_merge: &T,
+    context_merge: &X,
+) -> Vec<RustRelease<V, X::Output>>
+where
+    V: Ord + Debug,
+    I: IntoIterator<Item = RustRelease<V, C>>,
+    D: MergeAllReleaseDates,
+    T: MergeAllToolchains,
+    X: MergeAllContexts<C>,
+{
+    let mut versions: BTreeMap<V, Candidates<C>> = BTreeMap::new();
+
+    for collection in collections {
+        for release in collection {
+            versions.entry(release.version).or_default().push(
+                release.release_date,
+                release.toolchains,
+                release.context,
+            );
+        }
+    }
+
+    versions
+        .into_iter()
+        .map(|(version, fields)| fields.merge(version, date_merge, toolchains_merge, context_merge))
+        .collect()
+}
+
+// The fields of the candidates of a single version
+struct Candidates<C> {
+    dates: Vec<Option<date::Date>>,
+    toolchains: Vec<Vec<toolchain::Toolchain>>,
+    contexts: Vec<C>,
+}
+
+impl<C> Default for Candidates<C> {
+    fn default() -> Self {
+        Self {
+            dates: Vec::new(),
+            toolchains: Vec::new(),
+            contexts: Vec::new(),
+        }
+    }
+}
+
+impl<C> Candidates<C> {
+    fn push(
+        &mut self,
+        date: Option<date::Date>,
+        toolchains: Vec<toolchain::Toolchain>,
+        context: C,
+    ) {
+        self.dates.push(date);
+        self.toolchains.push(toolchains);
+        self.contexts.push(context);
+    }
+
+    fn merge<V, D, T, X>(
+        self,
+        version: V,
+        date_merge: &D,
+        toolchains_merge: &T,
+        context_merge: &X,
+    ) -> RustRelease<V, X::Output>
+    where
+        V: Debug,
+        D: MergeAllReleaseDates,
+        T: MergeAllToolchains,
+        X: MergeAllContexts<C>,
+    {
+        RustRelease {
+            version,
+            release_date: date_merge.merge_all_release_dates(self.dates),
+            toolchains: toolchains_merge.merge_all_toolchains(self.toolchains),
+            context: context_merge.merge_all_contexts(self.contexts),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::toolchain;
+    use crate::merge::strategy::context::{CollectContexts, UnitContext};
+    use crate::merge::strategy::release_date::{LatestDate, MajorityDate, PreferLeftDate};
+    use crate::merge::strategy::toolchains::{PreferLeftToolchains, UnionToolchains};
+    use crate::{Stable, StableReleases};
+    use rust_release::date::Date;
+    use yare::parameterized;
+
+    fn dates(days: &[Option<u8>]) -> Vec<Option<Date>> {
+        days.iter()
+            .map(|day| day.map(|day| Date::new(2023, 6, day)))
+            .collect()
+    }
+
+    #[parameterized(
+        majority = { &[Some(2), Some(1), Some(1)], Some(1) },
+        missing_dates_do_not_vote = { &[None, None, Some(2), Some(1), Some(1)], Some(1) },
+        tie_prefers_first = { &[Some(2), Some(1), Some(1), Some(2)], Some(2) },
+        none = { &[None, None], None },
+        empty = { &[], None },
+    )]
+    fn majority_date(days: &[Option<u8>], expected: Option<u8>) {
+        let date = MajorityDate.merge_all_release_dates(dates(days));
+
+        assert_eq!(date, expected.map(|day| Date::new(2023, 6, day)));
+    }
+
+    #[test]
+    fn pairwise_strategies() {
+        let candidates = dates(&[None, Some(2), Some(3), Some(1)]);
+
+        assert_eq!(
+            PreferLeftDate.merge_all_release_dates(candidates.clone()),
+            Some(Date::new(2023, 6, 2))
+        );
+        assert_eq!(
+            LatestDate.merge_all_release_dates(candidates),
+            Some(Date::new(2023, 6, 3))
+        );
+
+        let toolchains = vec![
+            vec![toolchain("x86_64-unknown-linux-gnu")],
+            vec![
+                toolchain("wasm32-unknown-unknown"),
+                toolchain("x86_64-unknown-linux-gnu"),
+            ],
+        ];
+
+        assert_eq!(
+            PreferLeftToolchains.merge_all_toolchains(toolchains.clone()),
+            vec![toolchain("x86_64-unknown-linux-gnu")]
+        );
+        assert_eq!(
+            UnionToolchains.merge_all_toolchains(toolchains),
+            vec![
+                toolchain("x86_64-unknown-linux-gnu"),
+                toolchain("wasm32-unknown-unknown")
+            ]
+        );
+    }
+
+    #[test]
+    fn merge_candidates_empty() {
+        let candidates: Vec<RustRelease<Stable>> = Vec::new();
+
+        let merged = merge_candidates(candidates, &MajorityDate, &UnionToolchains, &UnitContext);
+
+        assert!(merged.unwrap().is_none());
+    }
+
+    #[test]
+    fn merge_candidates_of_one_version() {
+        let candidates = vec![
+            RustRelease::new(Stable::new(1, 70, 0), Some(Date::new(2023, 6, 2)), []),
+            RustRelease::new(Stable::new(1, 70, 0), Some(Date::new(2023, 6, 1)), []),
+            RustRelease::new(Stable::new(1, 70, 0), Some(Date::new(2023, 6, 1)), []),
+        ];
+
+        let merged = merge_candidates(candidates, &MajorityDate, &UnionToolchains, &UnitContext)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(merged.version(), &Stable::new(1, 70, 0));
+        assert_eq!(merged.release_date(), Some(&Date::new(2023, 6, 1)));
+    }
+
+    #[test]
+    fn merge_candidates_version_mismatch() {
+        let candidates = vec![
+            RustRelease::new(Stable::new(1, 70, 0), None, []),
+            RustRelease::new(Stable::new(1, 70, 0), None, []),
+            RustRelease::new(Stable::new(1, 71, 0), None, []),
+        ];
+
+        let merged = merge_candidates(candidates, &MajorityDate, &UnionToolchains, &UnitContext);
+
+        assert_eq!(
+            merged.unwrap_err(),
+            VersionMismatch {
+                left: Stable::new(1, 70, 0),
+                right: Stable::new(1, 71, 0),
+            }
+        );
+    }
+
+    #[test]
+    fn merge_all_sources() {
+        let source = |releases: &[(u64, u8)]| {
+            releases
+                .iter()
+                .map(|&(minor, day)| {
+                    RustRelease::new(Stable::new(1, minor, 0), Some(Date::new(2023, 6, day)), [])
+                })
+                .collect::<StableReleases>()
+        };
+
+        let merged = merge_all(
+            vec![
+                source(&[(70, 1), (71, 3)]),
+                source(&[(69, 1), (70, 2)]),
+                source(&[(70, 2)]),
+            ],
+            &MajorityDate,
+            &UnionToolchains,
+            &UnitContext,
+        );
+
+        let releases = merged
+            .iter()
+            .map(|release| (release.version().clone(), release.release_date().cloned()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            releases,
+            vec![
+                (Stable::new(1, 69, 0), Some(Date::new(2023, 6, 1))),
+                (Stable::new(1, 70, 0), Some(Date::new(2023, 6, 2))),
+                (Stable::new(1, 71, 0), Some(Date::new(2023, 6, 3))),
+            ]
+        );
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum Source {
+        Changelog(()),
+        Dist(u32),
+    }
+
+    #[test]
+    fn merge_all_heterogeneous_contexts() {
+        let changelog = vec![RustRelease::new(Stable::new(1, 70, 0), None, [])]
+            .into_iter()
+            .map(|release| release.map_context(Source::Changelog))
+            .collect::<Vec<_>>();
+
+        let dist = vec![RustRelease::new_with_context(
+            Stable::new(1, 70, 0),
+            None,
+            [],
+            42u32,
+        )]
+        .into_iter()
+        .map(|release| release.map_context(Source::Dist))
+        .collect::<Vec<_>>();
+
+        let merged = merge_all(
+            vec![changelog, dist],
+            &PreferLeftDate,
+            &UnionToolchains,
+            &CollectContexts,
+        );
+
+        assert_eq!(merged.len(), 1);
+        assert_eq!(
+            merged[0].context(),
+            &vec![Source::Changelog(()), Source::Dist(42)]
+        );
+    }
+}
@@ -1,7 +1,11 @@
+use crate::merge::report::MergeReport;
 use crate::merge::strategy::context::UnitContext;
 use crate::merge::strategy::release_date::PreferLeftDate;
 use crate::merge::strategy::toolchains::UnionToolchains;
-use crate::merge::{merge, MergeContext, MergeReleaseDate, MergeToolchains};
+use crate::merge::{
+    merge, merge_reported, try_merge, MergeContext, MergeReleaseDate, MergeToolchains,
+    VersionMismatch,
+};
 use rust_release::RustRelease;
 use std::fmt::Debug;
 
@@ -88,4 +92,35 @@ where
             &self.context,
         )
     }
+
+    /// Like [`MergeBuilder::finish`], but returns [`VersionMismatch`] if the releases have
+    /// different versions.
+    pub fn try_finish(self) -> Result<RustRelease<V, C::Output>, VersionMismatch<V>> {
+        try_merge(
+            self.left,
+            self.right,
+            &self.date,
+            &self.toolchains,
+            &self.context,
+        )
+    }
+
+    /// Like [`MergeBuilder::try_finish`], and records the conflicts between the releases in
+    /// the `report`.
+    pub fn finish_reported(
+        self,
+        report: &mut MergeReport<V>,
+    ) -> Result<RustRelease<V, C::Output>, VersionMismatch<V>>
+    where
+        V: Clone,
+    {
+        merge_reported(
+            self.left,
+            self.right,
+            &self.date,
+            &self.toolchains,
+            &self.context,
+            report,
+        )
+    }
 }
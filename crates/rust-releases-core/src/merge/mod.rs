@@ -1,5 +1,8 @@
+pub mod all;
 pub mod builder;
 pub mod from_fn;
+pub mod provenance;
+pub mod report;
 pub mod strategy;
 
 use std::fmt::Debug;
@@ -8,6 +11,8 @@ use rust_release::date;
 use rust_release::toolchain;
 use rust_release::RustRelease;
 
+use crate::merge::report::{Conflict, MergeReport};
+
 /// Resolves how to combine the `release_date` field during a merge.
 pub trait MergeReleaseDate {
     fn merge_release_date(
@@ -44,6 +49,7 @@ pub trait MergeContext<CL, CR> {
 /// # Panics (debug)
 ///
 /// Panics if `left` and `right` have different versions (when compiling with `debug` on).
+/// Use [`try_merge`] to handle a version mismatch in release builds as well.
 pub fn merge<V, CL, CR, D, T, C>(
     left: RustRelease<V, CL>,
     right: RustRelease<V, CR>,
@@ -70,6 +76,80 @@ where
     }
 }
 
+/// Returned when two releases with different versions are merged.
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+#[error("Cannot merge releases with different versions: {left:?} and {right:?}")]
+pub struct VersionMismatch<V: Debug> {
+    /// The version of the left release
+    pub left: V,
+    /// The version of the right release
+    pub right: V,
+}
+
+/// Merges two releases that share the same version, like [`merge`].
+///
+/// Returns [`VersionMismatch`] if `left` and `right` have different versions.
+pub fn try_merge<V, CL, CR, D, T, C>(
+    left: RustRelease<V, CL>,
+    right: RustRelease<V, CR>,
+    date_merge: &D,
+    toolchains_merge: &T,
+    context_merge: &C,
+) -> Result<RustRelease<V, C::Output>, VersionMismatch<V>>
+where
+    V: Eq + Debug,
+    D: MergeReleaseDate,
+    T: MergeToolchains,
+    C: MergeContext<CL, CR>,
+{
+    if left.version != right.version {
+        return Err(VersionMismatch {
+            left: left.version,
+            right: right.version,
+        });
+    }
+
+    Ok(merge(
+        left,
+        right,
+        date_merge,
+        toolchains_merge,
+        context_merge,
+    ))
+}
+
+/// Merges two releases that share the same version, like [`try_merge`], and records where
+/// `left` and `right` disagree in the `report`.
+///
+/// See [`Conflict::between`] for what is considered a conflict.
+pub fn merge_reported<V, CL, CR, D, T, C>(
+    left: RustRelease<V, CL>,
+    right: RustRelease<V, CR>,
+    date_merge: &D,
+    toolchains_merge: &T,
+    context_merge: &C,
+    report: &mut MergeReport<V>,
+) -> Result<RustRelease<V, C::Output>, VersionMismatch<V>>
+where
+    V: Clone + Eq + Debug,
+    D: MergeReleaseDate,
+    T: MergeToolchains,
+    C: MergeContext<CL, CR>,
+{
+    let conflicts = Conflict::between(&left, &right);
+    let merged = try_merge(left, right, date_merge, toolchains_merge, context_merge)?;
+
+    for mut conflict in conflicts {
+        if let Conflict::ReleaseDate { merged: date, .. } = &mut conflict {
+            *date = merged.release_date.clone();
+        }
+
+        report.push(merged.version.clone(), conflict);
+    }
+
+    Ok(merged)
+}
+
 /// Merges two releases with sane defaults: prefer left date, union
 /// toolchains, unit context.
 pub fn merge_default<V>(left: RustRelease<V, ()>, right: RustRelease<V, ()>) -> RustRelease<V, ()>
@@ -78,3 +158,104 @@ where
 {
     builder::MergeBuilder::new(left, right).finish()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::toolchain;
+    use crate::merge::strategy::context::UnitContext;
+    use crate::merge::strategy::release_date::LatestDate;
+    use crate::merge::strategy::toolchains::UnionToolchains;
+    use crate::Stable;
+    use rust_release::date::Date;
+
+    #[test]
+    fn try_merge_version_mismatch() {
+        let left = RustRelease::new(Stable::new(1, 70, 0), None, []);
+        let right = RustRelease::new(Stable::new(1, 71, 0), None, []);
+
+        let result = try_merge(left, right, &LatestDate, &UnionToolchains, &UnitContext);
+
+        assert_eq!(
+            result.unwrap_err(),
+            VersionMismatch {
+                left: Stable::new(1, 70, 0),
+                right: Stable::new(1, 71, 0),
+            }
+        );
+    }
+
+    #[test]
+    fn merge_reported_without_conflicts() {
+        let left = RustRelease::new(Stable::new(1, 70, 0), Some(Date::new(2023, 6, 1)), []);
+        let right = RustRelease::new(
+            Stable::new(1, 70, 0),
+            None,
+            [toolchain("x86_64-unknown-linux-gnu")],
+        );
+        let mut report = MergeReport::new();
+
+        let merged = merge_reported(
+            left,
+            right,
+            &LatestDate,
+            &UnionToolchains,
+            &UnitContext,
+            &mut report,
+        )
+        .unwrap();
+
+        assert_eq!(merged.release_date(), Some(&Date::new(2023, 6, 1)));
+        assert!(report.is_empty());
+    }
+
+    #[test]
+    fn merge_reported_conflicts() {
+        let left = RustRelease::new(
+            Stable::new(1, 70, 0),
+            Some(Date::new(2023, 6, 1)),
+            [toolchain("x86_64-unknown-linux-gnu")],
+        );
+        let right = RustRelease::new(
+            Stable::new(1, 70, 0),
+            Some(Date::new(2023, 6, 2)),
+            [
+                toolchain("x86_64-unknown-linux-gnu"),
+                toolchain("wasm32-unknown-unknown"),
+            ],
+        );
+        let mut report = MergeReport::new();
+
+        let merged = merge_reported(
+            left,
+            right,
+            &LatestDate,
+            &UnionToolchains,
+            &UnitContext,
+            &mut report,
+        )
+        .unwrap();
+
+        assert_eq!(merged.toolchains().len(), 2);
+        assert_eq!(
+            report.into_iter().collect::<Vec<_>>(),
+            vec![
+                (
+                    Stable::new(1, 70, 0),
+                    Conflict::ReleaseDate {
+                        left: Date::new(2023, 6, 1),
+                        right: Date::new(2023, 6, 2),
+                        merged: Some(Date::new(2023, 6, 2)),
+                    }
+                ),
+                (
+                    Stable::new(1, 70, 0),
+                    Conflict::Toolchains {
+                        left_only: vec![],
+                        right_only: vec![toolchain("wasm32-unknown-unknown")],
+                    }
+                ),
+            ]
+        );
+    }
+}
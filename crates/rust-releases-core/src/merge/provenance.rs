@@ -0,0 +1,101 @@
+use rust_release::{date, toolchain, RustRelease};
+use std::fmt::Debug;
+
+/// A context which records, per field, which source supplied which value.
+///
+/// Attach it to the releases of each source with [`with_provenance`], and merge it with
+/// [`ProvenanceContext`], to trace each datum of a merged release back to the sources
+/// which supplied it.
+///
+/// [`ProvenanceContext`]: crate::merge::strategy::context::ProvenanceContext
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Provenance<S> {
+    release_dates: Vec<(S, date::Date)>,
+    toolchains: Vec<(S, toolchain::Toolchain)>,
+}
+
+impl<S> Default for Provenance<S> {
+    fn default() -> Self {
+        Self {
+            release_dates: Vec::new(),
+            toolchains: Vec::new(),
+        }
+    }
+}
+
+impl<S> Provenance<S> {
+    /// Record the release date and toolchains of a release, as supplied by the given source.
+    pub fn of<V: Debug, C>(source: S, release: &RustRelease<V, C>) -> Self
+    where
+        S: Clone,
+    {
+        Self {
+            release_dates: release
+                .release_date()
+                .map(|date| (source.clone(), date.clone()))
+                .into_iter()
+                .collect(),
+            toolchains: release
+                .toolchains_iter()
+                .map(|toolchain| (source.clone(), toolchain.clone()))
+                .collect(),
+        }
+    }
+
+    /// Combine the records of two provenances.
+    pub fn merge(mut self, other: Provenance<S>) -> Self {
+        self.release_dates.extend(other.release_dates);
+        self.toolchains.extend(other.toolchains);
+        self
+    }
+
+    /// Iterate over the release dates supplied by each source
+    pub fn release_dates(&self) -> impl Iterator<Item = (&S, &date::Date)> {
+        self.release_dates
+            .iter()
+            .map(|(source, date)| (source, date))
+    }
+
+    /// Iterate over the toolchains supplied by each source
+    pub fn toolchains(&self) -> impl Iterator<Item = (&S, &toolchain::Toolchain)> {
+        self.toolchains
+            .iter()
+            .map(|(source, toolchain)| (source, toolchain))
+    }
+
+    /// The sources which supplied the given release date
+    pub fn sources_of_date(&self, date: &date::Date) -> Vec<&S> {
+        self.release_dates
+            .iter()
+            .filter(|(_, supplied)| supplied == date)
+            .map(|(source, _)| source)
+            .collect()
+    }
+
+    /// The sources which supplied the given toolchain
+    pub fn sources_of_toolchain(&self, toolchain: &toolchain::Toolchain) -> Vec<&S> {
+        self.toolchains
+            .iter()
+            .filter(|(_, supplied)| supplied == toolchain)
+            .map(|(source, _)| source)
+            .collect()
+    }
+}
+
+/// Attach a [`Provenance`] context to a release, which records that its release date and
+/// toolchains were supplied by the given source.
+///
+/// The previous context of the release is discarded.
+pub fn with_provenance<V: Debug, C, S: Clone>(
+    release: RustRelease<V, C>,
+    source: S,
+) -> RustRelease<V, Provenance<S>> {
+    let provenance = Provenance::of(source, &release);
+
+    RustRelease {
+        version: release.version,
+        release_date: release.release_date,
+        toolchains: release.toolchains,
+        context: provenance,
+    }
+}
@@ -0,0 +1,136 @@
+use rust_release::{date, toolchain, RustRelease};
+use std::fmt::Debug;
+
+/// A disagreement between two sources on a field of the same release.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Conflict {
+    /// Both sources supplied a release date, but the dates differ.
+    ReleaseDate {
+        /// The date supplied by the left source
+        left: date::Date,
+        /// The date supplied by the right source
+        right: date::Date,
+        /// The date picked by the merge strategy
+        merged: Option<date::Date>,
+    },
+    /// Both sources supplied toolchains, but the sets of toolchains differ.
+    Toolchains {
+        /// The toolchains which were only supplied by the left source
+        left_only: Vec<toolchain::Toolchain>,
+        /// The toolchains which were only supplied by the right source
+        right_only: Vec<toolchain::Toolchain>,
+    },
+}
+
+impl Conflict {
+    /// Find the conflicts between two releases of the same version.
+    ///
+    /// A field which was not supplied by one of the sources, i.e. a missing release date or an
+    /// empty set of toolchains, is not a conflict.
+    pub fn between<V: Debug, CL, CR>(
+        left: &RustRelease<V, CL>,
+        right: &RustRelease<V, CR>,
+    ) -> Vec<Conflict> {
+        let mut conflicts = Vec::new();
+
+        if let (Some(l), Some(r)) = (left.release_date(), right.release_date()) {
+            if l != r {
+                conflicts.push(Conflict::ReleaseDate {
+                    left: l.clone(),
+                    right: r.clone(),
+                    merged: None,
+                });
+            }
+        }
+
+        if !left.toolchains().is_empty() && !right.toolchains().is_empty() {
+            let left_only = difference(left.toolchains(), right.toolchains());
+            let right_only = difference(right.toolchains(), left.toolchains());
+
+            if !left_only.is_empty() || !right_only.is_empty() {
+                conflicts.push(Conflict::Toolchains {
+                    left_only,
+                    right_only,
+                });
+            }
+        }
+
+        conflicts
+    }
+}
+
+fn difference(
+    this: &[toolchain::Toolchain],
+    other: &[toolchain::Toolchain],
+) -> Vec<toolchain::Toolchain> {
+    this.iter()
+        .filter(|toolchain| !other.contains(toolchain))
+        .cloned()
+        .collect()
+}
+
+/// The conflicts found while merging releases, by version.
+///
+/// Use it to audit where sources, like the changelog and the distribution manifests, disagree.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MergeReport<V> {
+    conflicts: Vec<(V, Conflict)>,
+}
+
+impl<V> Default for MergeReport<V> {
+    fn default() -> Self {
+        Self {
+            conflicts: Vec::new(),
+        }
+    }
+}
+
+impl<V> MergeReport<V> {
+    /// Create an empty report
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a conflict for the given version
+    pub fn push(&mut self, version: V, conflict: Conflict) {
+        self.conflicts.push((version, conflict));
+    }
+
+    /// Iterate over the recorded conflicts, in the order in which they were found
+    pub fn conflicts(&self) -> impl Iterator<Item = (&V, &Conflict)> {
+        self.conflicts
+            .iter()
+            .map(|(version, conflict)| (version, conflict))
+    }
+
+    /// The conflicts recorded for the given version
+    pub fn conflicts_of(&self, version: &V) -> Vec<&Conflict>
+    where
+        V: PartialEq,
+    {
+        self.conflicts
+            .iter()
+            .filter(|(v, _)| v == version)
+            .map(|(_, conflict)| conflict)
+            .collect()
+    }
+
+    /// Get the number of recorded conflicts
+    pub fn len(&self) -> usize {
+        self.conflicts.len()
+    }
+
+    /// Returns true if no conflicts were recorded, and false otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+}
+
+impl<V> IntoIterator for MergeReport<V> {
+    type Item = (V, Conflict);
+    type IntoIter = std::vec::IntoIter<(V, Conflict)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.conflicts.into_iter()
+    }
+}
@@ -1,3 +1,5 @@
+use crate::merge::all::MergeAllContexts;
+use crate::merge::provenance::Provenance;
 use crate::merge::MergeContext;
 
 /// Trivial context merge for the unit type.
@@ -8,3 +10,56 @@ impl MergeContext<(), ()> for UnitContext {
 
     fn merge_context(&self, _left: (), _right: ()) {}
 }
+
+impl MergeAllContexts<()> for UnitContext {
+    type Output = ();
+
+    fn merge_all_contexts(&self, _candidates: Vec<()>) {}
+}
+
+/// Combines the [`Provenance`] records of both releases.
+///
+/// [`Provenance`]: crate::merge::provenance::Provenance
+pub struct ProvenanceContext;
+
+impl<S> MergeContext<Provenance<S>, Provenance<S>> for ProvenanceContext {
+    type Output = Provenance<S>;
+
+    fn merge_context(&self, left: Provenance<S>, right: Provenance<S>) -> Provenance<S> {
+        left.merge(right)
+    }
+}
+
+impl<S> MergeAllContexts<Provenance<S>> for ProvenanceContext {
+    type Output = Provenance<S>;
+
+    fn merge_all_contexts(&self, candidates: Vec<Provenance<S>>) -> Provenance<S> {
+        candidates
+            .into_iter()
+            .fold(Provenance::default(), Provenance::merge)
+    }
+}
+
+/// Collects the contexts of all candidates, in order.
+///
+/// Useful to keep the context of each source, e.g. after giving the releases of each source a
+/// common context type with [`RustRelease::map_context`].
+///
+/// [`RustRelease::map_context`]: rust_release::RustRelease::map_context
+pub struct CollectContexts;
+
+impl<CL, CR> MergeContext<CL, CR> for CollectContexts {
+    type Output = (CL, CR);
+
+    fn merge_context(&self, left: CL, right: CR) -> (CL, CR) {
+        (left, right)
+    }
+}
+
+impl<C> MergeAllContexts<C> for CollectContexts {
+    type Output = Vec<C>;
+
+    fn merge_all_contexts(&self, candidates: Vec<C>) -> Vec<C> {
+        candidates
+    }
+}
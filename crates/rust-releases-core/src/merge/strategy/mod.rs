@@ -1,3 +1,4 @@
 pub mod context;
+pub mod priority;
 pub mod release_date;
 pub mod toolchains;
@@ -0,0 +1,319 @@
+use crate::merge::provenance::Provenance;
+use crate::merge::strategy::context::ProvenanceContext;
+use crate::merge::strategy::release_date::PreferLeftDate;
+use crate::merge::strategy::toolchains::UnionToolchains;
+use crate::merge::{try_merge, VersionMismatch};
+use rust_release::{date, toolchain, RustRelease};
+use std::fmt::Debug;
+
+/// Orders sources by how authoritative they are, the most authoritative source first.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SourcePriority<S> {
+    sources: Vec<S>,
+}
+
+impl<S: PartialEq> SourcePriority<S> {
+    /// Create a priority from sources, ordered from most to least authoritative.
+    pub fn new(sources: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            sources: sources.into_iter().collect(),
+        }
+    }
+
+    /// The rank of a source, where a lower rank is more authoritative.
+    ///
+    /// Sources which are not part of the priority share the lowest rank.
+    pub fn rank(&self, source: &S) -> usize {
+        self.sources
+            .iter()
+            .position(|known| known == source)
+            .unwrap_or(self.sources.len())
+    }
+}
+
+/// Merges releases which carry a [`Provenance`] context, by the priority of their sources.
+///
+/// * The release date is the date supplied by the most authoritative source, out of the dates
+///   on which at least the required number of sources agree (one, unless configured otherwise
+///   with [`PriorityMerge::require_agreement`]). If no date meets the required agreement, the
+///   merged release has no release date.
+/// * The toolchains are those supplied by the most authoritative source which supplied any.
+///   Toolchains of sources with the same rank are combined.
+/// * The provenance records of both releases are combined, so a later merge with another
+///   source takes all sources into account.
+///
+/// # Example
+///
+/// ```
+/// use rust_releases_core::merge::provenance::with_provenance;
+/// use rust_releases_core::merge::strategy::priority::{PriorityMerge, SourcePriority};
+/// use rust_release::{RustRelease, Stable};
+/// use rust_release::date::Date;
+///
+/// let changelog = RustRelease::new(Stable::new(1, 70, 0), Some(Date::new(2023, 6, 2)), []);
+/// let dist = RustRelease::new(Stable::new(1, 70, 0), Some(Date::new(2023, 6, 1)), []);
+///
+/// // Trust the dates of the distribution manifests over those of the changelog
+/// let strategy = PriorityMerge::new(SourcePriority::new(["dist", "changelog"]));
+///
+/// let merged = strategy
+///     .try_merge(
+///         with_provenance(changelog, "changelog"),
+///         with_provenance(dist, "dist"),
+///     )
+///     .unwrap();
+///
+/// assert_eq!(merged.release_date(), Some(&Date::new(2023, 6, 1)));
+/// assert_eq!(merged.context().sources_of_date(&Date::new(2023, 6, 1)), vec![&"dist"]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct PriorityMerge<S> {
+    date_priority: SourcePriority<S>,
+    toolchains_priority: SourcePriority<S>,
+    agreement: usize,
+}
+
+impl<S: Clone + PartialEq> PriorityMerge<S> {
+    /// Create a strategy which uses the given priority for both the release date and the
+    /// toolchains.
+    pub fn new(priority: SourcePriority<S>) -> Self {
+        Self {
+            date_priority: priority.clone(),
+            toolchains_priority: priority,
+            agreement: 1,
+        }
+    }
+
+    /// Use a different priority to pick the toolchains.
+    pub fn toolchains_priority(self, priority: SourcePriority<S>) -> Self {
+        Self {
+            toolchains_priority: priority,
+            ..self
+        }
+    }
+
+    /// Only accept a release date on which at least the given number of distinct sources agree.
+    pub fn require_agreement(self, sources: usize) -> Self {
+        Self {
+            agreement: sources,
+            ..self
+        }
+    }
+
+    /// Merges two releases that share the same version.
+    ///
+    /// Returns [`VersionMismatch`] if `left` and `right` have different versions.
+    pub fn try_merge<V: Eq + Debug>(
+        &self,
+        left: RustRelease<V, Provenance<S>>,
+        right: RustRelease<V, Provenance<S>>,
+    ) -> Result<RustRelease<V, Provenance<S>>, VersionMismatch<V>> {
+        let merged = try_merge(
+            left,
+            right,
+            &PreferLeftDate,
+            &UnionToolchains,
+            &ProvenanceContext,
+        )?;
+
+        Ok(RustRelease {
+            release_date: self.release_date(merged.context()),
+            toolchains: self.toolchains(merged.context()),
+            ..merged
+        })
+    }
+
+    /// The release date picked from the dates recorded in the provenance.
+    pub fn release_date(&self, provenance: &Provenance<S>) -> Option<date::Date> {
+        let mut candidates: Vec<(&date::Date, Vec<&S>)> = Vec::new();
+
+        for (source, date) in provenance.release_dates() {
+            match candidates
+                .iter_mut()
+                .find(|(candidate, _)| *candidate == date)
+            {
+                Some((_, sources)) if !sources.contains(&source) => sources.push(source),
+                Some(_) => {}
+                None => candidates.push((date, vec![source])),
+            }
+        }
+
+        candidates
+            .into_iter()
+            .filter(|(_, sources)| sources.len() >= self.agreement)
+            .min_by_key(|(_, sources)| {
+                sources
+                    .iter()
+                    .map(|source| self.date_priority.rank(source))
+                    .min()
+            })
+            .map(|(date, _)| date.clone())
+    }
+
+    /// The toolchains picked from the toolchains recorded in the provenance.
+    pub fn toolchains(&self, provenance: &Provenance<S>) -> Vec<toolchain::Toolchain> {
+        let best = provenance
+            .toolchains()
+            .map(|(source, _)| self.toolchains_priority.rank(source))
+            .min();
+
+        let mut toolchains = Vec::new();
+        for (source, toolchain) in provenance.toolchains() {
+            if Some(self.toolchains_priority.rank(source)) == best
+                && !toolchains.contains(toolchain)
+            {
+                toolchains.push(toolchain.clone());
+            }
+        }
+
+        toolchains
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::toolchain;
+    use crate::merge::provenance::with_provenance;
+    use crate::Stable;
+    use rust_release::date::Date;
+    use yare::parameterized;
+
+    fn release(
+        source: &'static str,
+        day: Option<u8>,
+        targets: &[&str],
+    ) -> RustRelease<Stable, Provenance<&'static str>> {
+        let release = RustRelease::new(
+            Stable::new(1, 70, 0),
+            day.map(|day| Date::new(2023, 6, day)),
+            targets.iter().map(|target| toolchain(target)),
+        );
+
+        with_provenance(release, source)
+    }
+
+    fn strategy() -> PriorityMerge<&'static str> {
+        PriorityMerge::new(SourcePriority::new(vec!["manifest", "dist", "changelog"]))
+    }
+
+    #[test]
+    fn rank() {
+        let priority = SourcePriority::new(vec!["manifest", "changelog"]);
+
+        assert_eq!(priority.rank(&"manifest"), 0);
+        assert_eq!(priority.rank(&"changelog"), 1);
+        assert_eq!(priority.rank(&"github"), 2);
+    }
+
+    #[parameterized(
+        authoritative_left = { release("manifest", Some(1), &[]), release("changelog", Some(2), &[]), Some(1) },
+        authoritative_right = { release("changelog", Some(2), &[]), release("manifest", Some(1), &[]), Some(1) },
+        authoritative_without_date = { release("manifest", None, &[]), release("changelog", Some(2), &[]), Some(2) },
+        unknown_source = { release("github", Some(3), &[]), release("changelog", Some(2), &[]), Some(2) },
+    )]
+    fn prefers_authoritative_date(
+        left: RustRelease<Stable, Provenance<&'static str>>,
+        right: RustRelease<Stable, Provenance<&'static str>>,
+        expected: Option<u8>,
+    ) {
+        let merged = strategy().try_merge(left, right).unwrap();
+
+        assert_eq!(
+            merged.release_date(),
+            expected.map(|day| Date::new(2023, 6, day)).as_ref()
+        );
+    }
+
+    #[test]
+    fn prefers_authoritative_toolchains() {
+        let left = release("changelog", None, &["x86_64-unknown-linux-gnu"]);
+        let right = release("dist", None, &["wasm32-unknown-unknown"]);
+
+        let merged = strategy().try_merge(left, right).unwrap();
+
+        assert_eq!(
+            merged.toolchains(),
+            &vec![toolchain("wasm32-unknown-unknown")]
+        );
+        assert_eq!(
+            merged
+                .context()
+                .sources_of_toolchain(&toolchain("x86_64-unknown-linux-gnu")),
+            vec![&"changelog"]
+        );
+    }
+
+    #[test]
+    fn separate_toolchains_priority() {
+        let left = release("changelog", Some(2), &["x86_64-unknown-linux-gnu"]);
+        let right = release("dist", Some(1), &["wasm32-unknown-unknown"]);
+
+        let merged = strategy()
+            .toolchains_priority(SourcePriority::new(vec!["changelog"]))
+            .try_merge(left, right)
+            .unwrap();
+
+        assert_eq!(merged.release_date(), Some(&Date::new(2023, 6, 1)));
+        assert_eq!(
+            merged.toolchains(),
+            &vec![toolchain("x86_64-unknown-linux-gnu")]
+        );
+    }
+
+    #[test]
+    fn require_agreement() {
+        let strategy = strategy().require_agreement(2);
+
+        let merged = strategy
+            .try_merge(
+                release("manifest", Some(1), &[]),
+                release("changelog", Some(2), &[]),
+            )
+            .unwrap();
+        assert_eq!(merged.release_date(), None);
+
+        // The provenance is retained, so a third source can still establish agreement
+        let merged = strategy
+            .try_merge(merged, release("dist", Some(2), &[]))
+            .unwrap();
+        assert_eq!(merged.release_date(), Some(&Date::new(2023, 6, 2)));
+        assert_eq!(
+            merged.context().sources_of_date(&Date::new(2023, 6, 2)),
+            vec![&"changelog", &"dist"]
+        );
+    }
+
+    #[test]
+    fn agreement_counts_distinct_sources() {
+        let strategy = strategy().require_agreement(2);
+
+        let merged = strategy
+            .try_merge(
+                release("changelog", Some(2), &[]),
+                release("changelog", Some(2), &[]),
+            )
+            .unwrap();
+
+        assert_eq!(merged.release_date(), None);
+    }
+
+    #[test]
+    fn version_mismatch() {
+        let left = release("manifest", Some(1), &[]);
+        let right = with_provenance(
+            RustRelease::new(Stable::new(1, 71, 0), Some(Date::new(2023, 7, 13)), []),
+            "changelog",
+        );
+
+        let result = strategy().try_merge(left, right);
+
+        assert_eq!(
+            result.unwrap_err(),
+            VersionMismatch {
+                left: Stable::new(1, 70, 0),
+                right: Stable::new(1, 71, 0),
+            }
+        );
+    }
+}
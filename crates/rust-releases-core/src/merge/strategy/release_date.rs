@@ -1,7 +1,10 @@
+use crate::merge::all::MergeAllReleaseDates;
 use crate::merge::MergeReleaseDate;
 use rust_release::date;
 
 /// Prefers the left date if present, otherwise the right.
+///
+/// When merging many candidates, prefers the date of the first candidate which has one.
 pub struct PreferLeftDate;
 
 impl MergeReleaseDate for PreferLeftDate {
@@ -14,6 +17,12 @@ impl MergeReleaseDate for PreferLeftDate {
     }
 }
 
+impl MergeAllReleaseDates for PreferLeftDate {
+    fn merge_all_release_dates(&self, candidates: Vec<Option<date::Date>>) -> Option<date::Date> {
+        candidates.into_iter().flatten().next()
+    }
+}
+
 /// Picks the later date when both are present, otherwise whichever is defined.
 pub struct LatestDate;
 
@@ -29,3 +38,47 @@ impl MergeReleaseDate for LatestDate {
         }
     }
 }
+
+impl MergeAllReleaseDates for LatestDate {
+    fn merge_all_release_dates(&self, candidates: Vec<Option<date::Date>>) -> Option<date::Date> {
+        candidates.into_iter().flatten().max()
+    }
+}
+
+/// Picks the date supplied by the most candidates.
+///
+/// Candidates without a date do not vote. A tie is broken in favour of the date which was
+/// supplied first.
+pub struct MajorityDate;
+
+impl MergeReleaseDate for MajorityDate {
+    /// With two candidates, there is either agreement or a tie, so this is the same as
+    /// [`PreferLeftDate`].
+    fn merge_release_date(
+        &self,
+        left: Option<date::Date>,
+        right: Option<date::Date>,
+    ) -> Option<date::Date> {
+        left.or(right)
+    }
+}
+
+impl MergeAllReleaseDates for MajorityDate {
+    fn merge_all_release_dates(&self, candidates: Vec<Option<date::Date>>) -> Option<date::Date> {
+        let mut votes: Vec<(date::Date, usize)> = Vec::new();
+
+        for date in candidates.into_iter().flatten() {
+            match votes.iter_mut().find(|(candidate, _)| candidate == &date) {
+                Some((_, count)) => *count += 1,
+                None => votes.push((date, 1)),
+            }
+        }
+
+        // `max_by_key` returns the last maximum, so we iterate in reverse to prefer the first
+        votes
+            .into_iter()
+            .rev()
+            .max_by_key(|(_, count)| *count)
+            .map(|(date, _)| date)
+    }
+}
@@ -1,7 +1,10 @@
+use crate::merge::all::MergeAllToolchains;
 use crate::merge::MergeToolchains;
 use rust_release::toolchain;
 
 /// Prefers the left toolchains, ignoring the right entirely.
+///
+/// When merging many candidates, prefers the toolchains of the first candidate.
 pub struct PreferLeftToolchains;
 
 impl MergeToolchains for PreferLeftToolchains {
@@ -14,6 +17,15 @@ impl MergeToolchains for PreferLeftToolchains {
     }
 }
 
+impl MergeAllToolchains for PreferLeftToolchains {
+    fn merge_all_toolchains(
+        &self,
+        candidates: Vec<Vec<toolchain::Toolchain>>,
+    ) -> Vec<toolchain::Toolchain> {
+        candidates.into_iter().next().unwrap_or_default()
+    }
+}
+
 /// Deduplicates and concatenates toolchains from both sides.
 ///
 /// Toolchains from the right side are appended only if they are not
@@ -34,3 +46,14 @@ impl MergeToolchains for UnionToolchains {
         left
     }
 }
+
+impl MergeAllToolchains for UnionToolchains {
+    fn merge_all_toolchains(
+        &self,
+        candidates: Vec<Vec<toolchain::Toolchain>>,
+    ) -> Vec<toolchain::Toolchain> {
+        candidates
+            .into_iter()
+            .fold(Vec::new(), |left, right| self.merge_toolchains(left, right))
+    }
+}
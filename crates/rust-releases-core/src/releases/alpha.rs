@@ -0,0 +1,31 @@
+use crate::releases::Releases;
+use crate::Alpha;
+
+/// A collection of alpha releases
+pub type AlphaReleases<C = ()> = Releases<Alpha, C>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_release::RustRelease;
+
+    #[test]
+    fn merge_overlapping_sets() {
+        let mut left = AlphaReleases::default();
+        left.add(RustRelease::new(Alpha::new(0, 10, 0), None, []));
+        left.add(RustRelease::new(Alpha::new(0, 11, 0), None, []));
+
+        let mut right = AlphaReleases::default();
+        right.add(RustRelease::new(Alpha::new(0, 11, 0), None, []));
+        right.add(RustRelease::new(Alpha::new(0, 12, 0), None, []));
+
+        let merged = left.merge(right);
+
+        assert_eq!(merged.len(), 3);
+
+        let versions: Vec<_> = merged.iter().map(|r| &r.version).collect();
+        assert!(versions.contains(&&Alpha::new(0, 10, 0)));
+        assert!(versions.contains(&&Alpha::new(0, 11, 0)));
+        assert!(versions.contains(&&Alpha::new(0, 12, 0)));
+    }
+}
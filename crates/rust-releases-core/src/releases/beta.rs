@@ -1,94 +1,28 @@
-use crate::releases::impls;
+use crate::releases::Releases;
 use crate::Beta;
+use rust_release::toolchain::RustVersion;
 use rust_release::RustRelease;
-use std::iter::FromIterator;
 
-#[derive(Clone, Debug, Default, PartialEq)]
-pub struct BetaReleases<C = ()>(impls::ReleasesImpl<Beta, C>);
+/// A collection of beta releases
+pub type BetaReleases<C = ()> = Releases<Beta, C>;
 
-impl<C> BetaReleases<C> {
-    /// Add a stable release
-    pub fn add(&mut self, release: RustRelease<Beta, C>) {
-        self.0.add(release);
+impl<C> Releases<Beta, C> {
+    /// Iterate over the beta pre-releases of the given version, e.g. `1.80.0-beta.1`,
+    /// `1.80.0-beta.2`, ..., in ascending order
+    pub fn prereleases(&self, version: RustVersion) -> impl Iterator<Item = &RustRelease<Beta, C>> {
+        self.iter()
+            .filter(move |release| release.version().version == version)
     }
 
-    /// Get the number of releases
-    pub fn len(&self) -> usize {
-        self.0.len()
-    }
-
-    /// Returns true if there are no releases, and false otherwise.
-    pub fn is_empty(&self) -> bool {
-        self.0.is_empty()
-    }
-
-    /// Iterate over the releases
-    pub fn iter(&self) -> impl Iterator<Item = &RustRelease<Beta, C>> {
-        self.0.iter()
-    }
-
-    /// Merge two collections, applying `merge_fn` to releases that exist in both.
-    ///
-    /// Releases that exist in only one collection are included unchanged.
-    pub fn merge_with<F>(self, right: BetaReleases<C>, merge_fn: F) -> BetaReleases<C>
-    where
-        F: Fn(RustRelease<Beta, C>, RustRelease<Beta, C>) -> RustRelease<Beta, C>,
-    {
-        BetaReleases(self.0.merge_with(right.0, merge_fn))
-    }
-}
-
-impl<C> IntoIterator for BetaReleases<C> {
-    type Item = RustRelease<Beta, C>;
-    type IntoIter = std::collections::btree_set::IntoIter<RustRelease<Beta, C>>;
-
-    fn into_iter(self) -> Self::IntoIter {
-        self.0.into_iter()
-    }
-}
-
-impl<C> FromIterator<RustRelease<Beta, C>> for BetaReleases<C> {
-    fn from_iter<T: IntoIterator<Item = RustRelease<Beta, C>>>(iter: T) -> Self {
-        Self(iter.into_iter().collect())
-    }
-}
-
-impl BetaReleases<()> {
-    /// Create a new, but empty, instance.
-    ///
-    /// NB: This function is only available for `C = ()`. Instances which use a different type `C`
-    ///     can be created using `BetaReleases::<C>::default()`.
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// # use rust_releases_core::BetaReleases;
-    ///
-    /// let releases = BetaReleases::empty();
-    ///
-    /// assert!(releases.is_empty());
-    /// ```
-    ///
-    /// # See also
-    ///
-    /// [`BetaReleases::default`]: create an empty collection, with any context type `C`.
-    /// [`BetaReleases::add`]: add releases to the collection.
-    pub fn empty() -> Self {
-        Self(impls::ReleasesImpl::default())
-    }
-
-    /// Merge two collections using default strategies (prefer left date, union toolchains).
-    ///
-    /// Releases that exist in only one collection are included unchanged.
-    pub fn merge(self, right: BetaReleases<()>) -> BetaReleases<()> {
-        self.merge_with(right, crate::merge::merge_default)
+    /// The latest beta pre-release of the given version, e.g. `1.80.0-beta.6` for `1.80.0`
+    pub fn latest_prerelease(&self, version: RustVersion) -> Option<&RustRelease<Beta, C>> {
+        self.prereleases(version).last()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use rust_release::toolchain::RustVersion;
 
     fn make_release(major: u64, minor: u64, patch: u64) -> RustRelease<Beta> {
         RustRelease::new(
@@ -129,4 +63,33 @@ mod tests {
             prerelease: None
         }));
     }
+
+    #[test]
+    fn latest_prerelease() {
+        let releases = [
+            Beta::prerelease(RustVersion::new(1, 79, 0), 5),
+            Beta::prerelease(RustVersion::new(1, 80, 0), 1),
+            Beta::prerelease(RustVersion::new(1, 80, 0), 6),
+            Beta::prerelease(RustVersion::new(1, 80, 0), 2),
+            Beta::prerelease(RustVersion::new(1, 81, 0), 1),
+        ]
+        .iter()
+        .map(|beta| RustRelease::new(beta.clone(), None, []))
+        .collect::<BetaReleases>();
+
+        let prereleases = releases
+            .prereleases(RustVersion::new(1, 80, 0))
+            .map(|release| release.version().prerelease)
+            .collect::<Vec<_>>();
+        assert_eq!(prereleases, vec![Some(1), Some(2), Some(6)]);
+
+        let latest = releases.latest_prerelease(RustVersion::new(1, 80, 0));
+        assert_eq!(
+            latest.map(|release| release.version()),
+            Some(&Beta::prerelease(RustVersion::new(1, 80, 0), 6))
+        );
+        assert!(releases
+            .latest_prerelease(RustVersion::new(1, 82, 0))
+            .is_none());
+    }
 }
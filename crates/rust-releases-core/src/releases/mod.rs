@@ -1,121 +1,307 @@
+use crate::merge::builder::MergeBuilder;
+use crate::merge::report::MergeReport;
 use rust_release::RustRelease;
 use std::collections::BTreeSet;
+use std::fmt::Debug;
+use std::iter::FromIterator;
+use std::ops::Index;
 
+mod alpha;
 mod beta;
 mod nightly;
 mod stable;
 
+pub use alpha::AlphaReleases;
 pub use beta::BetaReleases;
 pub use nightly::NightlyReleases;
 pub use stable::StableReleases;
 
-// shared implementation for StableReleases, BetaReleases and NightlyReleases (implementation detail)
-pub(in crate::releases) mod impls {
-    use super::*;
-    use std::fmt::Debug;
-    use std::iter::FromIterator;
+/// A collection of releases, ordered by version.
+///
+/// The collection is generic over the version type `V`, which identifies a release, so code
+/// can be written once for all channels. The [`StableReleases`], [`BetaReleases`],
+/// [`NightlyReleases`] and [`AlphaReleases`] aliases name the collections of each channel.
+/// A collection of any other version type, which implements [`Ord`] and [`Debug`], can be
+/// created as well.
+///
+/// A collection contains at most one release per version. Each release may carry an arbitrary
+/// context `C`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Releases<V: Debug, C = ()> {
+    releases: BTreeSet<RustRelease<V, C>>,
+}
 
-    #[derive(Clone, Debug, PartialEq)]
-    pub struct ReleasesImpl<V: Debug, C> {
-        releases: BTreeSet<RustRelease<V, C>>,
+impl<V: Debug, C> Default for Releases<V, C> {
+    fn default() -> Self {
+        Self {
+            releases: BTreeSet::default(),
+        }
     }
+}
 
-    impl<V: Debug, C> Default for ReleasesImpl<V, C> {
-        fn default() -> Self {
-            Self {
-                releases: BTreeSet::default(),
-            }
-        }
+impl<V: Debug + Ord, C> Releases<V, C> {
+    /// Add a release
+    ///
+    /// If the collection already contains a release of the same version, it is not replaced.
+    pub fn add(&mut self, release: RustRelease<V, C>) {
+        self.releases.insert(release);
+    }
+
+    /// Get the number of releases
+    pub fn len(&self) -> usize {
+        self.releases.len()
+    }
+
+    /// Returns true if there are no releases, and false otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.releases.is_empty()
     }
 
-    impl<V: Debug, C> ReleasesImpl<V, C>
+    /// Iterate over the releases, in ascending order
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &RustRelease<V, C>> {
+        self.releases.iter()
+    }
+
+    /// Merge two collections, applying `merge_fn` to releases that exist in both.
+    ///
+    /// Releases that exist in only one collection are included unchanged.
+    pub fn merge_with<F>(self, right: Releases<V, C>, mut merge_fn: F) -> Releases<V, C>
     where
-        V: Ord,
+        F: FnMut(RustRelease<V, C>, RustRelease<V, C>) -> RustRelease<V, C>,
     {
-        /// Add a release to the collection
-        pub fn add(&mut self, release: RustRelease<V, C>) {
-            self.releases.insert(release);
-        }
+        let mut result = BTreeSet::new();
+        let mut others = right.releases;
 
-        /// Returns the amount of releases
-        pub fn len(&self) -> usize {
-            self.releases.len()
+        for release in self.releases {
+            if let Some(other_release) = others.take(&release) {
+                result.insert(merge_fn(release, other_release));
+            } else {
+                result.insert(release);
+            }
         }
 
-        /// Returns true if there are no releases, and false otherwise.
-        pub fn is_empty(&self) -> bool {
-            self.releases.is_empty()
-        }
+        result.extend(others);
+        Self { releases: result }
+    }
 
-        /// Iterate over the releases.
-        pub fn iter(&self) -> impl Iterator<Item = &RustRelease<V, C>> {
-            self.releases.iter()
-        }
+    /// Get the release of the given version
+    pub fn get(&self, version: &V) -> Option<&RustRelease<V, C>> {
+        self.releases.get(version)
+    }
 
-        /// Merge two sets of releases.
-        ///
-        /// If a release exists in both, apply the `merge_fn` to resolve the conflict. Releases that
-        /// exist in only one set are included unchanged.
-        pub fn merge_with<F>(self, right: Self, merge_fn: F) -> Self
-        where
-            V: Ord,
-            F: Fn(RustRelease<V, C>, RustRelease<V, C>) -> RustRelease<V, C>,
-        {
-            let mut result = BTreeSet::new();
-            let mut others = right.releases;
-
-            for release in self.releases {
-                if let Some(other_release) = others.take(&release) {
-                    result.insert(merge_fn(release, other_release));
-                } else {
-                    result.insert(release);
-                }
-            }
+    /// Returns true if the collection contains a release of the given version
+    pub fn contains(&self, version: &V) -> bool {
+        self.releases.contains(version)
+    }
 
-            result.extend(others);
-            Self { releases: result }
-        }
+    /// Remove and return the release of the given version
+    pub fn remove(&mut self, version: &V) -> Option<RustRelease<V, C>> {
+        self.releases.take(version)
+    }
+
+    /// The release with the lowest version
+    pub fn first(&self) -> Option<&RustRelease<V, C>> {
+        self.releases.iter().next()
+    }
+
+    /// The release with the highest version
+    pub fn last(&self) -> Option<&RustRelease<V, C>> {
+        self.releases.iter().next_back()
     }
 
-    impl<V: Debug, C> IntoIterator for ReleasesImpl<V, C>
+    /// Retain only the releases for which `predicate` returns true
+    pub fn retain<F>(&mut self, predicate: F)
     where
-        V: Ord,
+        F: FnMut(&RustRelease<V, C>) -> bool,
     {
-        type Item = RustRelease<V, C>;
-        type IntoIter = std::collections::btree_set::IntoIter<RustRelease<V, C>>;
+        self.releases.retain(predicate);
+    }
 
-        fn into_iter(self) -> Self::IntoIter {
-            self.releases.into_iter()
-        }
+    /// Copy the releases for which `predicate` returns true into a new collection
+    pub fn filter<F>(&self, mut predicate: F) -> Releases<V, C>
+    where
+        F: FnMut(&RustRelease<V, C>) -> bool,
+        V: Clone,
+        C: Clone,
+    {
+        self.releases
+            .iter()
+            .filter(|release| predicate(release))
+            .cloned()
+            .collect()
     }
 
-    impl<V: Debug, C> FromIterator<RustRelease<V, C>> for ReleasesImpl<V, C>
+    /// Copy the releases of which the version does not exist in `other` into a new collection,
+    /// e.g. to find the releases which are known by one source, but not by another.
+    ///
+    /// The collections may have different context types.
+    pub fn difference<C2>(&self, other: &Releases<V, C2>) -> Releases<V, C>
     where
-        V: Ord,
+        V: Clone,
+        C: Clone,
     {
-        fn from_iter<T: IntoIterator<Item = RustRelease<V, C>>>(iter: T) -> Self {
-            Self {
-                releases: iter.into_iter().collect(),
-            }
-        }
+        self.filter(|release| !other.contains(release.version()))
     }
 
-    #[cfg(test)]
-    mod tests {
-        use super::*;
-        use crate::Stable;
+    /// Copy the releases of which the version also exists in `other` into a new collection.
+    ///
+    /// The release date, toolchains and context are taken from `self`. The collections may have
+    /// different context types.
+    pub fn intersection<C2>(&self, other: &Releases<V, C2>) -> Releases<V, C>
+    where
+        V: Clone,
+        C: Clone,
+    {
+        self.filter(|release| other.contains(release.version()))
+    }
 
-        #[test]
-        fn merge_with_is_additive() {
-            let mut left = ReleasesImpl::default();
-            left.add(RustRelease::new(Stable::new(1, 0, 0), None, []));
+    /// Convert the context of each release
+    pub fn map_context<D, F>(self, mut f: F) -> Releases<V, D>
+    where
+        F: FnMut(C) -> D,
+    {
+        self.releases
+            .into_iter()
+            .map(|release| release.map_context(&mut f))
+            .collect()
+    }
+}
 
-            let mut right = ReleasesImpl::default();
-            right.add(RustRelease::new(Stable::new(2, 0, 0), None, []));
+impl<V: Debug + Ord> Releases<V, ()> {
+    /// Create a new, but empty, instance.
+    ///
+    /// NB: This function is only available for `C = ()`. Instances which use a different type `C`
+    ///     can be created using `Releases::<V, C>::default()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_releases_core::StableReleases;
+    ///
+    /// let releases = StableReleases::empty();
+    ///
+    /// assert!(releases.is_empty());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// [`Releases::default`]: create an empty collection, with any context type `C`.
+    /// [`Releases::add`]: add releases to the collection.
+    pub fn empty() -> Self {
+        Self::default()
+    }
+
+    /// Merge two collections using default strategies (prefer left date, union toolchains).
+    ///
+    /// Releases that exist in only one collection are included unchanged.
+    pub fn merge(self, right: Releases<V, ()>) -> Releases<V, ()> {
+        self.merge_with(right, crate::merge::merge_default)
+    }
+
+    /// Merge two collections using default strategies, like [`Releases::merge`], and
+    /// report the conflicts between releases that exist in both.
+    ///
+    /// See [`Conflict::between`] for what is considered a conflict.
+    ///
+    /// [`Conflict::between`]: crate::merge::report::Conflict::between
+    pub fn merge_reported(self, right: Releases<V, ()>) -> (Releases<V, ()>, MergeReport<V>)
+    where
+        V: Clone,
+    {
+        let mut report = MergeReport::new();
+        let merged = self.merge_with(right, |left, right| {
+            MergeBuilder::new(left, right)
+                .finish_reported(&mut report)
+                .expect("releases in a collection are merged by version")
+        });
 
-            let merged = left.merge_with(right, |_l, _r| panic!("must not be called"));
+        (merged, report)
+    }
+}
 
-            assert_eq!(merged.len(), 2);
+impl<V: Debug + Ord, C> IntoIterator for Releases<V, C> {
+    type Item = RustRelease<V, C>;
+    type IntoIter = std::collections::btree_set::IntoIter<RustRelease<V, C>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.releases.into_iter()
+    }
+}
+
+impl<'a, V: Debug + Ord, C> IntoIterator for &'a Releases<V, C> {
+    type Item = &'a RustRelease<V, C>;
+    type IntoIter = std::collections::btree_set::Iter<'a, RustRelease<V, C>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.releases.iter()
+    }
+}
+
+impl<V: Debug + Ord, C> FromIterator<RustRelease<V, C>> for Releases<V, C> {
+    fn from_iter<T: IntoIterator<Item = RustRelease<V, C>>>(iter: T) -> Self {
+        Self {
+            releases: iter.into_iter().collect(),
         }
     }
 }
+
+impl<V: Debug + Ord, C> Extend<RustRelease<V, C>> for Releases<V, C> {
+    fn extend<T: IntoIterator<Item = RustRelease<V, C>>>(&mut self, iter: T) {
+        self.releases.extend(iter);
+    }
+}
+
+impl<V: Debug + Ord, C> Index<&V> for Releases<V, C> {
+    type Output = RustRelease<V, C>;
+
+    /// Get the release of the given version
+    ///
+    /// # Panics
+    ///
+    /// Panics if the collection does not contain a release of the given version.
+    /// Use [`Releases::get`] for a fallible lookup.
+    fn index(&self, version: &V) -> &Self::Output {
+        self.get(version)
+            .unwrap_or_else(|| panic!("no release of version {:?}", version))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Stable;
+
+    #[test]
+    fn merge_with_is_additive() {
+        let mut left = Releases::default();
+        left.add(RustRelease::new(Stable::new(1, 0, 0), None, []));
+
+        let mut right = Releases::default();
+        right.add(RustRelease::new(Stable::new(2, 0, 0), None, []));
+
+        let merged = left.merge_with(right, |_l, _r| panic!("must not be called"));
+
+        assert_eq!(merged.len(), 2);
+    }
+
+    // A version type of a custom channel, e.g. for locally built toolchains
+    #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+    struct Build(u32);
+
+    // Written once, for the collections of all version types
+    fn newest<V: Debug + Ord, C>(releases: &Releases<V, C>) -> Option<&V> {
+        releases.last().map(RustRelease::version)
+    }
+
+    #[test]
+    fn custom_version_type() {
+        let releases = vec![3, 1, 2]
+            .into_iter()
+            .map(|build| RustRelease::new(Build(build), None, []))
+            .collect::<Releases<Build>>();
+
+        assert_eq!(newest(&releases), Some(&Build(3)));
+        assert_eq!(releases[&Build(1)].version(), &Build(1));
+        assert_eq!(newest(&releases.merge(Releases::empty())), Some(&Build(3)));
+    }
+}
@@ -1,94 +1,14 @@
-use crate::releases::impls;
+use crate::releases::Releases;
 use crate::Nightly;
-use rust_release::RustRelease;
-use std::iter::FromIterator;
 
-#[derive(Clone, Debug, Default, PartialEq)]
-pub struct NightlyReleases<C = ()>(impls::ReleasesImpl<Nightly, C>);
-
-impl<C> NightlyReleases<C> {
-    /// Add a stable release
-    pub fn add(&mut self, release: RustRelease<Nightly, C>) {
-        self.0.add(release);
-    }
-
-    /// Get the number of releases
-    pub fn len(&self) -> usize {
-        self.0.len()
-    }
-
-    /// Returns true if there are no releases, and false otherwise.
-    pub fn is_empty(&self) -> bool {
-        self.0.is_empty()
-    }
-
-    /// Iterate over the releases
-    pub fn iter(&self) -> impl Iterator<Item = &RustRelease<Nightly, C>> {
-        self.0.iter()
-    }
-
-    /// Merge two collections, applying `merge_fn` to releases that exist in both.
-    ///
-    /// Releases that exist in only one collection are included unchanged.
-    pub fn merge_with<F>(self, right: NightlyReleases<C>, merge_fn: F) -> NightlyReleases<C>
-    where
-        F: Fn(RustRelease<Nightly, C>, RustRelease<Nightly, C>) -> RustRelease<Nightly, C>,
-    {
-        NightlyReleases(self.0.merge_with(right.0, merge_fn))
-    }
-}
-
-impl<C> IntoIterator for NightlyReleases<C> {
-    type Item = RustRelease<Nightly, C>;
-    type IntoIter = std::collections::btree_set::IntoIter<RustRelease<Nightly, C>>;
-
-    fn into_iter(self) -> Self::IntoIter {
-        self.0.into_iter()
-    }
-}
-
-impl<C> FromIterator<RustRelease<Nightly, C>> for NightlyReleases<C> {
-    fn from_iter<T: IntoIterator<Item = RustRelease<Nightly, C>>>(iter: T) -> Self {
-        Self(iter.into_iter().collect())
-    }
-}
-
-impl NightlyReleases<()> {
-    /// Create a new, but empty, instance.
-    ///
-    /// NB: This function is only available for `C = ()`. Instances which use a different type `C`
-    ///     can be created using `NightlyReleases::<C>::default()`.
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// # use rust_releases_core::NightlyReleases;
-    ///
-    /// let releases = NightlyReleases::empty();
-    ///
-    /// assert!(releases.is_empty());
-    /// ```
-    ///
-    /// # See also
-    ///
-    /// [`NightlyReleases::default`]: create an empty collection, with any context type `C`.
-    /// [`NightlyReleases::add`]: add releases to the collection.
-    pub fn empty() -> Self {
-        Self(impls::ReleasesImpl::default())
-    }
-
-    /// Merge two collections using default strategies (prefer left date, union toolchains).
-    ///
-    /// Releases that exist in only one collection are included unchanged.
-    pub fn merge(self, right: NightlyReleases<()>) -> NightlyReleases<()> {
-        self.merge_with(right, crate::merge::merge_default)
-    }
-}
+/// A collection of nightly releases
+pub type NightlyReleases<C = ()> = Releases<Nightly, C>;
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use rust_release::date::Date;
+    use rust_release::RustRelease;
 
     fn make_release(year: u16, month: u8, day: u8) -> RustRelease<Nightly> {
         RustRelease::new(
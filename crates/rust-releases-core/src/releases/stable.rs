@@ -1,95 +1,15 @@
-use crate::releases::impls;
+use crate::releases::Releases;
 use crate::Stable;
-use rust_release::RustRelease;
-use std::fmt::Debug;
-use std::iter::FromIterator;
 
-#[derive(Clone, Debug, Default, PartialEq)]
-pub struct StableReleases<C = ()>(impls::ReleasesImpl<Stable, C>);
-
-impl<C> StableReleases<C> {
-    /// Add a stable release
-    pub fn add(&mut self, release: RustRelease<Stable, C>) {
-        self.0.add(release);
-    }
-
-    /// Get the number of releases
-    pub fn len(&self) -> usize {
-        self.0.len()
-    }
-
-    /// Returns true if there are no releases, and false otherwise.
-    pub fn is_empty(&self) -> bool {
-        self.0.is_empty()
-    }
-
-    /// Iterate over the releases
-    pub fn iter(&self) -> impl Iterator<Item = &RustRelease<Stable, C>> {
-        self.0.iter()
-    }
-
-    /// Merge two collections, applying `merge_fn` to releases that exist in both.
-    ///
-    /// Releases that exist in only one collection are included unchanged.
-    pub fn merge_with<F>(self, right: StableReleases<C>, merge_fn: F) -> StableReleases<C>
-    where
-        F: Fn(RustRelease<Stable, C>, RustRelease<Stable, C>) -> RustRelease<Stable, C>,
-    {
-        StableReleases(self.0.merge_with(right.0, merge_fn))
-    }
-}
-
-impl<C> IntoIterator for StableReleases<C> {
-    type Item = RustRelease<Stable, C>;
-    type IntoIter = std::collections::btree_set::IntoIter<RustRelease<Stable, C>>;
-
-    fn into_iter(self) -> Self::IntoIter {
-        self.0.into_iter()
-    }
-}
-
-impl<C> FromIterator<RustRelease<Stable, C>> for StableReleases<C> {
-    fn from_iter<T: IntoIterator<Item = RustRelease<Stable, C>>>(iter: T) -> Self {
-        Self(iter.into_iter().collect())
-    }
-}
-
-impl StableReleases<()> {
-    /// Create a new, but empty, instance.
-    ///
-    /// NB: This function is only available for `C = ()`. Instances which use a different type `C`
-    ///     can be created using `StableReleases::<C>::default()`.
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// # use rust_releases_core::StableReleases;
-    ///
-    /// let releases = StableReleases::empty();
-    ///
-    /// assert!(releases.is_empty());
-    /// ```
-    ///
-    /// # See also
-    ///
-    /// [`StableReleases::default`]: create an empty collection, with any context type `C`.
-    /// [`StableReleases::add`]: add releases to the collection.
-    pub fn empty() -> Self {
-        Self(impls::ReleasesImpl::default())
-    }
-
-    /// Merge two collections using default strategies (prefer left date, union toolchains).
-    ///
-    /// Releases that exist in only one collection are included unchanged.
-    pub fn merge(self, right: StableReleases<()>) -> StableReleases<()> {
-        self.merge_with(right, crate::merge::merge_default)
-    }
-}
+/// A collection of stable releases
+pub type StableReleases<C = ()> = Releases<Stable, C>;
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::merge::builder::MergeBuilder;
+    use crate::merge::report::Conflict;
+    use rust_release::RustRelease;
     use rust_release::{
         date::Date,
         toolchain::{Channel, RustVersion, Target, Toolchain},
@@ -201,6 +121,36 @@ mod tests {
         assert!(versions.contains(&&Stable::new(3, 0, 0)));
     }
 
+    #[test]
+    fn merge_reported_records_conflicts() {
+        let mut left = StableReleases::default();
+        left.add(make_release((1, 0, 0), Some(Date::new(2015, 5, 15))));
+        left.add(make_release((2, 0, 0), Some(Date::new(2030, 1, 1))));
+
+        let mut right = StableReleases::default();
+        right.add(make_release((1, 0, 0), Some(Date::new(2015, 5, 15))));
+        right.add(make_release((2, 0, 0), Some(Date::new(2030, 1, 2))));
+
+        let (merged, report) = left.merge_reported(right);
+
+        assert_eq!(merged.len(), 2);
+        assert_eq!(report.len(), 2);
+        assert!(report.conflicts_of(&Stable::new(1, 0, 0)).is_empty());
+
+        let conflicts = report.conflicts_of(&Stable::new(2, 0, 0));
+
+        assert_eq!(
+            conflicts[0],
+            &Conflict::ReleaseDate {
+                left: Date::new(2030, 1, 1),
+                right: Date::new(2030, 1, 2),
+                merged: Some(Date::new(2030, 1, 1)),
+            }
+        );
+        // The toolchains carry the release date, so they differ too
+        assert!(matches!(conflicts[1], Conflict::Toolchains { .. }));
+    }
+
     #[test]
     fn empty() {
         let releases = StableReleases::empty();
@@ -237,4 +187,94 @@ mod tests {
 
         assert_eq!(original, out);
     }
+
+    fn releases(versions: &[(u64, u64, u64)]) -> StableReleases {
+        versions
+            .iter()
+            .map(|&version| make_release(version, None))
+            .collect()
+    }
+
+    fn versions<C>(releases: &StableReleases<C>) -> Vec<String> {
+        releases
+            .iter()
+            .map(|release| release.version().version.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn lookup() {
+        let mut releases = releases(&[(1, 70, 0), (1, 71, 0), (1, 69, 0)]);
+
+        assert!(releases.contains(&Stable::new(1, 70, 0)));
+        assert!(!releases.contains(&Stable::new(1, 72, 0)));
+        assert_eq!(
+            releases
+                .get(&Stable::new(1, 71, 0))
+                .map(RustRelease::version),
+            Some(&Stable::new(1, 71, 0))
+        );
+        assert_eq!(
+            releases[&Stable::new(1, 70, 0)].version(),
+            &Stable::new(1, 70, 0)
+        );
+        assert_eq!(releases.first().unwrap().version(), &Stable::new(1, 69, 0));
+        assert_eq!(releases.last().unwrap().version(), &Stable::new(1, 71, 0));
+
+        let removed = releases.remove(&Stable::new(1, 71, 0));
+        assert_eq!(removed.map(|r| r.version), Some(Stable::new(1, 71, 0)));
+        assert!(releases.remove(&Stable::new(1, 71, 0)).is_none());
+        assert_eq!(releases.last().unwrap().version(), &Stable::new(1, 70, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "no release of version")]
+    fn index_missing() {
+        let releases = releases(&[(1, 70, 0)]);
+
+        let _ = &releases[&Stable::new(1, 71, 0)];
+    }
+
+    #[test]
+    fn difference_and_intersection() {
+        let changelog = releases(&[(1, 69, 0), (1, 70, 0), (1, 71, 0)]);
+        let dist = releases(&[(1, 70, 0), (1, 71, 0), (1, 72, 0)]).map_context(|()| "dist");
+
+        assert_eq!(versions(&changelog.difference(&dist)), vec!["1.69.0"]);
+        assert_eq!(
+            versions(&changelog.intersection(&dist)),
+            vec!["1.70.0", "1.71.0"]
+        );
+        assert_eq!(versions(&dist.difference(&changelog)), vec!["1.72.0"]);
+    }
+
+    #[test]
+    fn retain_and_filter() {
+        let mut releases = releases(&[(1, 69, 0), (1, 70, 0), (1, 70, 1), (1, 71, 0)]);
+
+        let patches = releases.filter(|release| release.version().version.patch() > 0);
+        assert_eq!(versions(&patches), vec!["1.70.1"]);
+        assert_eq!(releases.len(), 4);
+
+        releases.retain(|release| release.version().version.minor() >= 70);
+        assert_eq!(versions(&releases), vec!["1.70.0", "1.70.1", "1.71.0"]);
+    }
+
+    #[test]
+    fn map_context_and_extend() {
+        let mut releases = releases(&[(1, 70, 0)]).map_context(|()| 1u8);
+        releases.extend(vec![RustRelease::new_with_context(
+            Stable::new(1, 71, 0),
+            None,
+            [],
+            2u8,
+        )]);
+
+        let contexts = releases
+            .iter()
+            .map(|release| *release.context())
+            .collect::<Vec<_>>();
+
+        assert_eq!(contexts, vec![1, 2]);
+    }
 }
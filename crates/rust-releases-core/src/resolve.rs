@@ -0,0 +1,257 @@
+//! Resolves toolchain specifiers, like `stable`, `1.70` or `nightly-2024-02-01`, to the
+//! release they denote in a [`RustReleases`] index.
+//!
+//! [`RustReleases`]: crate::RustReleases
+
+use crate::{Alpha, Beta, Nightly, RustRelease, RustReleases, Stable};
+use rust_release::date::Date;
+use rust_release::toolchain::{Channel, ChannelSpec, PartialRustVersion, ToolchainSpec};
+use rust_release::ReleaseVersion;
+
+/// A release, of any channel, to which a [`ToolchainSpec`] was resolved.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ResolvedRelease<'r> {
+    /// A stable release
+    Stable(&'r RustRelease<Stable>),
+    /// A beta release
+    Beta(&'r RustRelease<Beta>),
+    /// A nightly release
+    Nightly(&'r RustRelease<Nightly>),
+    /// A historic alpha release
+    Alpha(&'r RustRelease<Alpha>),
+}
+
+impl ResolvedRelease<'_> {
+    /// The version of the resolved release
+    pub fn version(&self) -> ReleaseVersion {
+        match self {
+            ResolvedRelease::Stable(release) => ReleaseVersion::Stable(release.version().clone()),
+            ResolvedRelease::Beta(release) => ReleaseVersion::Beta(release.version().clone()),
+            ResolvedRelease::Nightly(release) => ReleaseVersion::Nightly(release.version().clone()),
+            ResolvedRelease::Alpha(release) => ReleaseVersion::Alpha(release.version().clone()),
+        }
+    }
+
+    /// The release date of the resolved release, if known
+    pub fn release_date(&self) -> Option<&Date> {
+        match self {
+            ResolvedRelease::Stable(release) => release.release_date(),
+            ResolvedRelease::Beta(release) => release.release_date(),
+            ResolvedRelease::Nightly(release) => release.release_date(),
+            ResolvedRelease::Alpha(release) => release.release_date(),
+        }
+    }
+
+    /// A copy of the resolved release, of which the version is a [`ReleaseVersion`]
+    pub fn to_release(&self) -> RustRelease<ReleaseVersion> {
+        match self {
+            ResolvedRelease::Stable(release) => {
+                (*release).clone().map_version(ReleaseVersion::Stable)
+            }
+            ResolvedRelease::Beta(release) => (*release).clone().map_version(ReleaseVersion::Beta),
+            ResolvedRelease::Nightly(release) => {
+                (*release).clone().map_version(ReleaseVersion::Nightly)
+            }
+            ResolvedRelease::Alpha(release) => {
+                (*release).clone().map_version(ReleaseVersion::Alpha)
+            }
+        }
+    }
+}
+
+impl RustReleases {
+    /// Resolve a toolchain specifier to the release it denotes today.
+    ///
+    /// See [`RustReleases::resolve_at`].
+    pub fn resolve(&self, spec: &ToolchainSpec) -> Option<ResolvedRelease<'_>> {
+        self.resolve_at(spec, &Date::today())
+    }
+
+    /// Resolve a toolchain specifier to the release it denotes at the given date.
+    ///
+    /// * `stable`, `beta` and `nightly` resolve to the newest release of their channel which
+    ///   was released on, or before, the given date. If the specifier is dated, like
+    ///   `stable-2023-06-01`, its own date is used instead.
+    /// * A dated nightly, like `nightly-2024-02-01`, resolves to the nightly of exactly that date.
+    /// * A partial version, like `1.70`, resolves to the newest `1.70.x` patch release which
+    ///   was released on, or before, the given date.
+    /// * A full version, like `1.70.0` or `1.71.0-beta.2`, resolves to exactly that release,
+    ///   regardless of the given date.
+    ///
+    /// * A custom toolchain never resolves, since it is not released by the Rust project.
+    ///
+    /// Releases for which the release date is not known, are assumed to have been released.
+    /// The host of the specifier is not taken into account.
+    ///
+    /// Returns `None` if the index does not contain a matching release.
+    pub fn resolve_at(&self, spec: &ToolchainSpec, date: &Date) -> Option<ResolvedRelease<'_>> {
+        let date = spec.date().unwrap_or(date);
+
+        match spec.channel() {
+            ChannelSpec::Stable => newest(self.stable(), date).map(ResolvedRelease::Stable),
+            ChannelSpec::Beta => newest(self.beta(), date).map(ResolvedRelease::Beta),
+            ChannelSpec::Nightly if spec.date().is_some() => {
+                find(self.nightly(), |nightly| &nightly.date == date).map(ResolvedRelease::Nightly)
+            }
+            ChannelSpec::Nightly => newest(self.nightly(), date).map(ResolvedRelease::Nightly),
+            ChannelSpec::Partial(partial) if partial.major() == 0 => {
+                newest_matching(self.alpha(), date, |alpha| {
+                    alpha.prerelease.is_none() && is_match(partial, alpha.version)
+                })
+                .map(ResolvedRelease::Alpha)
+            }
+            ChannelSpec::Partial(partial) => newest_matching(self.stable(), date, |stable| {
+                is_match(partial, stable.version)
+            })
+            .map(ResolvedRelease::Stable),
+            ChannelSpec::Exact(channel) => self.resolve_exact(channel),
+            // Custom toolchains are not released by the Rust project
+            ChannelSpec::Custom(_) => None,
+        }
+    }
+
+    pub(crate) fn resolve_exact(&self, channel: &Channel) -> Option<ResolvedRelease<'_>> {
+        match channel {
+            Channel::Stable(stable) => {
+                find(self.stable(), |version| version == stable).map(ResolvedRelease::Stable)
+            }
+            Channel::Beta(beta) => {
+                find(self.beta(), |version| version == beta).map(ResolvedRelease::Beta)
+            }
+            Channel::Nightly(nightly) => {
+                find(self.nightly(), |version| version == nightly).map(ResolvedRelease::Nightly)
+            }
+            Channel::Alpha(alpha) => {
+                find(self.alpha(), |version| version == alpha).map(ResolvedRelease::Alpha)
+            }
+            Channel::Custom(_) => None,
+        }
+    }
+}
+
+fn is_match(partial: &PartialRustVersion, version: rust_release::toolchain::RustVersion) -> bool {
+    version.major() == partial.major() && version.minor() == partial.minor()
+}
+
+fn is_released<V: std::fmt::Debug>(release: &RustRelease<V>, date: &Date) -> bool {
+    release
+        .release_date()
+        .map_or(true, |released| released <= date)
+}
+
+fn newest<'r, V: std::fmt::Debug>(
+    releases: impl IntoIterator<Item = &'r RustRelease<V>>,
+    date: &Date,
+) -> Option<&'r RustRelease<V>> {
+    newest_matching(releases, date, |_| true)
+}
+
+// Releases are iterated in ascending order, so the last match is the newest.
+fn newest_matching<'r, V: std::fmt::Debug>(
+    releases: impl IntoIterator<Item = &'r RustRelease<V>>,
+    date: &Date,
+    predicate: impl Fn(&V) -> bool,
+) -> Option<&'r RustRelease<V>> {
+    releases
+        .into_iter()
+        .filter(|release| is_released(release, date) && predicate(release.version()))
+        .last()
+}
+
+fn find<'r, V: std::fmt::Debug>(
+    releases: impl IntoIterator<Item = &'r RustRelease<V>>,
+    predicate: impl Fn(&V) -> bool,
+) -> Option<&'r RustRelease<V>> {
+    releases
+        .into_iter()
+        .find(|release| predicate(release.version()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{self, index};
+    use crate::{AlphaReleases, BetaReleases, NightlyReleases};
+    use yare::parameterized;
+
+    fn stable(major: u64, minor: u64, patch: u64) -> Option<ReleaseVersion> {
+        Some(ReleaseVersion::Stable(Stable::new(major, minor, patch)))
+    }
+
+    fn beta(prerelease: u32) -> Option<ReleaseVersion> {
+        Some(ReleaseVersion::Beta(fixtures::beta(prerelease)))
+    }
+
+    fn nightly(day: u8) -> Option<ReleaseVersion> {
+        Some(ReleaseVersion::Nightly(fixtures::nightly(day)))
+    }
+
+    #[parameterized(
+        stable_latest = { "stable", Date::new(2024, 1, 1), stable(1, 71, 0) },
+        stable_as_of = { "stable", Date::new(2023, 6, 19), stable(1, 70, 0) },
+        stable_on_release_day = { "stable", Date::new(2023, 6, 1), stable(1, 70, 0) },
+        stable_dated = { "stable-2023-06-25", Date::new(2024, 1, 1), stable(1, 70, 1) },
+        stable_before_first = { "stable", Date::new(2023, 1, 1), None },
+        beta_latest = { "beta", Date::new(2024, 1, 1), beta(2) },
+        beta_dated = { "beta-2023-06-05", Date::new(2024, 1, 1), beta(1) },
+        nightly_latest = { "nightly", Date::new(2024, 1, 1), nightly(2) },
+        nightly_dated = { "nightly-2023-06-01", Date::new(2024, 1, 1), nightly(1) },
+        nightly_dated_missing = { "nightly-2023-06-03", Date::new(2024, 1, 1), None },
+        partial = { "1.70", Date::new(2024, 1, 1), stable(1, 70, 1) },
+        partial_as_of = { "1.70", Date::new(2023, 6, 10), stable(1, 70, 0) },
+        partial_missing = { "1.68", Date::new(2024, 1, 1), None },
+        partial_alpha = { "0.12", Date::new(2024, 1, 1), Some(ReleaseVersion::Alpha(Alpha::new(0, 12, 0))) },
+        exact = { "1.70.0", Date::new(2000, 1, 1), stable(1, 70, 0) },
+        exact_beta = { "1.71.0-beta.1", Date::new(2024, 1, 1), beta(1) },
+        exact_missing = { "1.70.2", Date::new(2024, 1, 1), None },
+        custom = { "stage2", Date::new(2024, 1, 1), None },
+        host_is_ignored = { "1.70-x86_64-unknown-linux-gnu", Date::new(2024, 1, 1), stable(1, 70, 1) },
+    )]
+    fn resolve_at(spec: &str, date: Date, expected: Option<ReleaseVersion>) {
+        let index = index();
+        let spec = ToolchainSpec::parse_or_custom(spec).unwrap();
+
+        let resolved = index.resolve_at(&spec, &date);
+
+        assert_eq!(resolved.map(|release| release.version()), expected);
+    }
+
+    #[test]
+    fn resolve_today() {
+        let index = index();
+        let spec = "stable".parse::<ToolchainSpec>().unwrap();
+
+        let resolved = index.resolve(&spec).unwrap();
+
+        assert_eq!(resolved.version(), stable(1, 71, 0).unwrap());
+        assert_eq!(resolved.release_date(), Some(&Date::new(2023, 7, 13)));
+    }
+
+    #[test]
+    fn to_release() {
+        let index = index();
+        let spec = "1.71.0-beta.2".parse::<ToolchainSpec>().unwrap();
+
+        let release = index.resolve(&spec).unwrap().to_release();
+
+        assert_eq!(release.version(), &beta(2).unwrap());
+        assert_eq!(release.release_date(), Some(&Date::new(2023, 6, 9)));
+    }
+
+    #[test]
+    fn undated_release_is_assumed_released() {
+        let index = RustReleases::new(
+            vec![RustRelease::new(Stable::new(1, 70, 0), None, [])]
+                .into_iter()
+                .collect(),
+            BetaReleases::default(),
+            NightlyReleases::default(),
+            AlphaReleases::default(),
+        );
+        let spec = "stable".parse::<ToolchainSpec>().unwrap();
+
+        let resolved = index.resolve_at(&spec, &Date::new(2000, 1, 1));
+
+        assert_eq!(resolved.map(|release| release.version()), stable(1, 70, 0));
+    }
+}
@@ -0,0 +1,443 @@
+//! Persists an index as a snapshot, a plain text format from which the index can be read back,
+//! e.g. to compare the index of a scheduled job with the index of its previous run, with
+//! [`RustReleases::changes_since`].
+//!
+//! A snapshot consists of one record per line, of which the fields are separated by whitespace.
+//! A `release` record is followed by the `toolchain` records of the release, and a `toolchain`
+//! record by the `component` and `target` records of the toolchain:
+//!
+//! ```text
+//! release 1.70.0 2023-06-01
+//! toolchain 1.70.0 x86_64-unknown-linux-gnu 2023-06-01
+//! component rustc
+//! component rust-std wasm32-unknown-unknown
+//! target wasm32-unknown-unknown
+//! release nightly-2023-06-02
+//! ```
+//!
+//! The release date of a release, and the date of a toolchain, are optional. Empty lines are
+//! ignored.
+//!
+//! The context of a release is not part of a snapshot. Toolchains of a custom channel, and
+//! names which contain whitespace, can not be read back, since neither is part of a release
+//! of the Rust project.
+//!
+//! [`RustReleases::changes_since`]: crate::RustReleases::changes_since
+
+use crate::{
+    AlphaReleases, BetaReleases, NightlyReleases, ReleaseVersion, Releases, RustRelease,
+    RustReleases, StableReleases,
+};
+use rust_release::date::Date;
+use rust_release::toolchain::{Channel, Component, Target, Toolchain};
+use std::collections::HashSet;
+use std::str::{FromStr, SplitWhitespace};
+
+impl Releases<ReleaseVersion> {
+    /// Write the releases as a snapshot, in ascending order.
+    ///
+    /// See the [module documentation](crate::snapshot) for the format.
+    pub fn to_snapshot(&self) -> String {
+        let mut snapshot = String::new();
+
+        for release in self.iter() {
+            for record in records(release) {
+                snapshot.push_str(&record);
+                snapshot.push('\n');
+            }
+        }
+
+        snapshot
+    }
+
+    /// Read the releases of a snapshot, written by [`Releases::to_snapshot`].
+    pub fn from_snapshot(snapshot: &str) -> Result<Self, SnapshotError> {
+        let mut releases = Releases::default();
+        let mut current: Option<RustRelease<ReleaseVersion>> = None;
+
+        for (index, line) in snapshot.lines().enumerate() {
+            let mut fields = Fields {
+                line: index + 1,
+                fields: line.split_whitespace(),
+            };
+
+            let record = match fields.fields.next() {
+                Some(record) => record,
+                None => continue,
+            };
+
+            match record {
+                "release" => {
+                    let version = fields.required("release version")?;
+                    let release_date = fields.optional("release date")?;
+                    fields.end()?;
+
+                    if let Some(release) = current.take() {
+                        releases.add(release);
+                    }
+
+                    if releases.contains(&version) {
+                        return Err(SnapshotError::DuplicateRelease(fields.line, version));
+                    }
+
+                    current = Some(RustRelease::new(version, release_date, []));
+                }
+                "toolchain" => {
+                    let channel = fields.required::<Channel>("channel")?;
+                    let host = fields.required::<Target>("host")?;
+                    let date = fields.optional::<Date>("date")?;
+                    fields.end()?;
+
+                    let toolchain =
+                        Toolchain::new(channel, date, host, HashSet::new(), HashSet::new());
+                    fields
+                        .release(&mut current, "toolchain")?
+                        .toolchains
+                        .push(toolchain);
+                }
+                "component" => {
+                    let name = fields.required::<String>("component name")?;
+                    let target = fields.optional::<Target>("component target")?;
+                    fields.end()?;
+
+                    let component = match target {
+                        Some(target) => Component::for_target(name, target),
+                        None => Component::new(name),
+                    };
+
+                    let toolchain = fields.toolchain(&mut current, "component")?;
+                    let mut components = toolchain.components().clone();
+                    components.insert(component);
+                    toolchain.set_components(components);
+                }
+                "target" => {
+                    let target = fields.required::<Target>("target")?;
+                    fields.end()?;
+
+                    let toolchain = fields.toolchain(&mut current, "target")?;
+                    let mut targets = toolchain.targets().clone();
+                    targets.insert(target);
+                    toolchain.set_targets(targets);
+                }
+                unknown => {
+                    return Err(SnapshotError::UnknownRecord(
+                        fields.line,
+                        unknown.to_string(),
+                    ))
+                }
+            }
+        }
+
+        if let Some(release) = current {
+            releases.add(release);
+        }
+
+        Ok(releases)
+    }
+}
+
+impl RustReleases {
+    /// Write the releases of all channels as a snapshot, ordered by [`ReleaseVersion`].
+    ///
+    /// See the [module documentation](crate::snapshot) for the format.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_releases_core::{RustReleases, Stable, StableReleases};
+    /// use rust_release::RustRelease;
+    /// use rust_release::date::Date;
+    ///
+    /// let stable = vec![RustRelease::new(Stable::new(1, 70, 0), Some(Date::new(2023, 6, 1)), [])]
+    ///     .into_iter()
+    ///     .collect::<StableReleases>();
+    /// let index = RustReleases::new(stable, Default::default(), Default::default(), Default::default());
+    ///
+    /// let snapshot = index.to_snapshot();
+    /// assert_eq!(snapshot, "release 1.70.0 2023-06-01\n");
+    ///
+    /// let restored = RustReleases::from_snapshot(&snapshot).unwrap();
+    /// assert!(restored.changes_since(&index).is_empty());
+    /// ```
+    pub fn to_snapshot(&self) -> String {
+        self.erased()
+            .collect::<Releases<ReleaseVersion>>()
+            .to_snapshot()
+    }
+
+    /// Read an index from a snapshot, written by [`RustReleases::to_snapshot`].
+    pub fn from_snapshot(snapshot: &str) -> Result<Self, SnapshotError> {
+        let mut stable = StableReleases::default();
+        let mut beta = BetaReleases::default();
+        let mut nightly = NightlyReleases::default();
+        let mut alpha = AlphaReleases::default();
+
+        for release in Releases::<ReleaseVersion>::from_snapshot(snapshot)?.into_iter() {
+            let RustRelease {
+                version,
+                release_date,
+                toolchains,
+                ..
+            } = release;
+
+            match version {
+                ReleaseVersion::Stable(v) => {
+                    stable.add(RustRelease::new(v, release_date, toolchains))
+                }
+                ReleaseVersion::Beta(v) => beta.add(RustRelease::new(v, release_date, toolchains)),
+                ReleaseVersion::Nightly(v) => {
+                    nightly.add(RustRelease::new(v, release_date, toolchains))
+                }
+                ReleaseVersion::Alpha(v) => {
+                    alpha.add(RustRelease::new(v, release_date, toolchains))
+                }
+            }
+        }
+
+        Ok(RustReleases::new(stable, beta, nightly, alpha))
+    }
+}
+
+/// Errors which may occur while reading a snapshot.
+///
+/// Each error carries the line number, starting at 1, of the offending record.
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+#[non_exhaustive]
+pub enum SnapshotError {
+    /// Returned when a record does not start with a known record type, like `release`.
+    #[error("Line {0}: unknown record `{1}`")]
+    UnknownRecord(usize, String),
+
+    /// Returned when a record lacks a required field, like the version of a release.
+    #[error("Line {0}: missing {1}")]
+    MissingField(usize, &'static str),
+
+    /// Returned when a field of a record can not be parsed, like an invalid release date.
+    #[error("Line {0}: invalid {1} `{2}`")]
+    InvalidField(usize, &'static str, String),
+
+    /// Returned when a record has more fields than its record type allows.
+    #[error("Line {0}: unexpected field `{1}`")]
+    UnexpectedField(usize, String),
+
+    /// Returned when a record is not preceded by the record it belongs to, like a `component`
+    /// record without a preceding `toolchain` record.
+    #[error("Line {0}: {1} record without a preceding {2} record")]
+    Orphan(usize, &'static str, &'static str),
+
+    /// Returned when a release is listed more than once.
+    #[error("Line {0}: duplicate release `{1}`")]
+    DuplicateRelease(usize, ReleaseVersion),
+}
+
+// The records of a release, with the components and targets of each toolchain sorted by name
+fn records(release: &RustRelease<ReleaseVersion>) -> Vec<String> {
+    let mut records = vec![with_optional(
+        format!("release {}", release.version()),
+        release.release_date(),
+    )];
+
+    for toolchain in release.toolchains() {
+        records.push(with_optional(
+            format!("toolchain {} {}", toolchain.channel(), toolchain.host()),
+            toolchain.date(),
+        ));
+
+        let mut components = toolchain
+            .components()
+            .iter()
+            .map(|component| {
+                with_optional(
+                    format!("component {}", component.name()),
+                    component.target(),
+                )
+            })
+            .collect::<Vec<_>>();
+        components.sort();
+
+        let mut targets = toolchain
+            .targets()
+            .iter()
+            .map(|target| format!("target {}", target))
+            .collect::<Vec<_>>();
+        targets.sort();
+
+        records.extend(components);
+        records.extend(targets);
+    }
+
+    records
+}
+
+fn with_optional(record: String, field: Option<impl std::fmt::Display>) -> String {
+    match field {
+        Some(field) => format!("{} {}", record, field),
+        None => record,
+    }
+}
+
+// The remaining fields of a record
+struct Fields<'s> {
+    line: usize,
+    fields: SplitWhitespace<'s>,
+}
+
+impl Fields<'_> {
+    fn required<T: FromStr>(&mut self, name: &'static str) -> Result<T, SnapshotError> {
+        self.optional(name)?
+            .ok_or(SnapshotError::MissingField(self.line, name))
+    }
+
+    fn optional<T: FromStr>(&mut self, name: &'static str) -> Result<Option<T>, SnapshotError> {
+        self.fields
+            .next()
+            .map(|field| {
+                field
+                    .parse()
+                    .map_err(|_| SnapshotError::InvalidField(self.line, name, field.to_string()))
+            })
+            .transpose()
+    }
+
+    fn end(&mut self) -> Result<(), SnapshotError> {
+        match self.fields.next() {
+            Some(field) => Err(SnapshotError::UnexpectedField(self.line, field.to_string())),
+            None => Ok(()),
+        }
+    }
+
+    fn release<'r>(
+        &self,
+        current: &'r mut Option<RustRelease<ReleaseVersion>>,
+        record: &'static str,
+    ) -> Result<&'r mut RustRelease<ReleaseVersion>, SnapshotError> {
+        current
+            .as_mut()
+            .ok_or(SnapshotError::Orphan(self.line, record, "release"))
+    }
+
+    fn toolchain<'r>(
+        &self,
+        current: &'r mut Option<RustRelease<ReleaseVersion>>,
+        record: &'static str,
+    ) -> Result<&'r mut Toolchain, SnapshotError> {
+        current
+            .as_mut()
+            .and_then(|release| release.toolchains.last_mut())
+            .ok_or(SnapshotError::Orphan(self.line, record, "toolchain"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{index, toolchain};
+    use crate::Stable;
+    use yare::parameterized;
+
+    fn index_with_toolchains() -> RustReleases {
+        let mut host = toolchain("x86_64-unknown-linux-gnu");
+        host.set_date(Some(Date::new(2023, 6, 1)));
+        host.set_components(
+            vec![
+                Component::new("rustc"),
+                Component::for_target(
+                    "rust-std",
+                    Target::from_target_triple_or_unknown("wasm32-unknown-unknown"),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+        );
+        host.set_targets(
+            vec![Target::from_target_triple_or_unknown(
+                "wasm32-unknown-unknown",
+            )]
+            .into_iter()
+            .collect(),
+        );
+
+        let stable = vec![
+            RustRelease::new(
+                Stable::new(1, 70, 0),
+                Some(Date::new(2023, 6, 1)),
+                [host, toolchain("aarch64-apple-darwin")],
+            ),
+            RustRelease::new(Stable::new(1, 71, 0), None, []),
+        ];
+
+        let index = index();
+
+        RustReleases::new(
+            stable.into_iter().collect(),
+            index.beta.clone(),
+            index.nightly.clone(),
+            index.alpha.clone(),
+        )
+    }
+
+    #[test]
+    fn round_trip() {
+        let index = index_with_toolchains();
+
+        let snapshot = index.to_snapshot();
+        let restored = RustReleases::from_snapshot(&snapshot).unwrap();
+
+        assert!(restored.changes_since(&index).is_empty());
+        assert_eq!(restored.all().count(), index.all().count());
+        assert_eq!(restored.to_snapshot(), snapshot);
+    }
+
+    #[test]
+    fn format() {
+        let index = index_with_toolchains();
+
+        let snapshot = index.to_snapshot();
+
+        assert_eq!(
+            snapshot,
+            "release 0.10.0 2014-01-09\n\
+             release 0.12.0 2014-10-09\n\
+             release 1.70.0 2023-06-01\n\
+             toolchain 1.70.0 x86_64-unknown-linux-gnu 2023-06-01\n\
+             component rust-std wasm32-unknown-unknown\n\
+             component rustc\n\
+             target wasm32-unknown-unknown\n\
+             toolchain 1.70.0 aarch64-apple-darwin\n\
+             release 1.71.0-beta.1 2023-06-02\n\
+             release 1.71.0-beta.2 2023-06-09\n\
+             release 1.71.0\n\
+             release nightly-2023-06-01 2023-06-01\n\
+             release nightly-2023-06-02 2023-06-02\n"
+        );
+    }
+
+    #[test]
+    fn empty_lines_are_ignored() {
+        let snapshot = "\nrelease 1.70.0\n\n  \ntoolchain 1.70.0 x86_64-unknown-linux-gnu\n";
+
+        let releases = Releases::<ReleaseVersion>::from_snapshot(snapshot).unwrap();
+
+        assert_eq!(releases.len(), 1);
+        assert_eq!(releases.iter().next().unwrap().toolchains().len(), 1);
+    }
+
+    #[parameterized(
+        unknown_record = { "release 1.70.0\nnightly 2023-06-01", SnapshotError::UnknownRecord(2, "nightly".to_string()) },
+        missing_version = { "release", SnapshotError::MissingField(1, "release version") },
+        invalid_version = { "release stable", SnapshotError::InvalidField(1, "release version", "stable".to_string()) },
+        invalid_date = { "release 1.70.0 2023-02-30", SnapshotError::InvalidField(1, "release date", "2023-02-30".to_string()) },
+        missing_host = { "release 1.70.0\ntoolchain 1.70.0", SnapshotError::MissingField(2, "host") },
+        custom_channel = { "release 1.70.0\ntoolchain stage2 x86_64-unknown-linux-gnu", SnapshotError::InvalidField(2, "channel", "stage2".to_string()) },
+        unexpected_field = { "release 1.70.0 2023-06-01 extra", SnapshotError::UnexpectedField(1, "extra".to_string()) },
+        toolchain_without_release = { "toolchain 1.70.0 x86_64-unknown-linux-gnu", SnapshotError::Orphan(1, "toolchain", "release") },
+        component_without_toolchain = { "release 1.70.0\ncomponent rustc", SnapshotError::Orphan(2, "component", "toolchain") },
+        target_without_toolchain = { "target wasm32-unknown-unknown", SnapshotError::Orphan(1, "target", "toolchain") },
+        duplicate_release = { "release 1.70.0\nrelease 1.70.0", SnapshotError::DuplicateRelease(2, ReleaseVersion::Stable(Stable::new(1, 70, 0))) },
+    )]
+    fn invalid_snapshot(snapshot: &str, expected: SnapshotError) {
+        let result = Releases::<ReleaseVersion>::from_snapshot(snapshot);
+
+        assert_eq!(result.unwrap_err(), expected);
+    }
+}
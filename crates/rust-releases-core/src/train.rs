@@ -0,0 +1,276 @@
+//! Maps nightly releases to the release train they were building toward, and back.
+//!
+//! Every six weeks, the `master` branch of the Rust project is branched into a new beta, after
+//! which the nightlies build the next version. For example, the nightlies published after
+//! `1.77` was branched, and before `1.78` was branched, report themselves as `1.78.0-nightly`.
+//!
+//! The moment a version is branched is taken from the release date of its first beta release,
+//! if the index contains it. Otherwise, it is approximated by the release date of the preceding
+//! stable release.
+
+use crate::{Nightly, ReleaseVersion, RustRelease, RustReleases};
+use rust_release::date::Date;
+use rust_release::toolchain::RustVersion;
+use std::collections::BTreeMap;
+
+/// The dates of the nightly releases which were building toward a version.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NightlyRange {
+    start: Date,
+    end: Option<Date>,
+}
+
+impl NightlyRange {
+    /// The date from which the nightlies were building toward the version, inclusive
+    pub fn start(&self) -> &Date {
+        &self.start
+    }
+
+    /// The date from which the nightlies were building toward the next version, exclusive,
+    /// or `None` if the next version has not been branched yet
+    pub fn end(&self) -> Option<&Date> {
+        self.end.as_ref()
+    }
+
+    /// Whether a nightly of the given date was building toward the version
+    pub fn contains(&self, date: &Date) -> bool {
+        date >= &self.start && self.end.as_ref().map_or(true, |end| date < end)
+    }
+}
+
+impl RustReleases {
+    /// The version the given nightly was building toward, e.g. `1.78.0` for
+    /// `nightly-2024-02-10`, which reports itself as `1.78.0-nightly`.
+    ///
+    /// Returns `None` if the nightly precedes the releases in the index.
+    pub fn release_train(&self, nightly: &Nightly) -> Option<RustVersion> {
+        release_train(&self.branch_dates(), &nightly.date)
+    }
+
+    /// The dates of the nightlies which were building toward the given version. The patch
+    /// component of the version is ignored.
+    ///
+    /// Returns `None` if the index does not contain the releases needed to determine the
+    /// start of the range.
+    pub fn nightly_range(&self, version: RustVersion) -> Option<NightlyRange> {
+        let branch_dates = self.branch_dates();
+        let previous = version.minor().checked_sub(1)?;
+
+        let start = branch_dates.get(&(version.major(), previous))?.clone();
+        let end = branch_dates
+            .get(&(version.major(), version.minor()))
+            .cloned();
+
+        Some(NightlyRange { start, end })
+    }
+
+    /// Iterate over the nightly releases in the index which were building toward the given
+    /// version.
+    ///
+    /// See [`RustReleases::nightly_range`].
+    pub fn nightlies_for(
+        &self,
+        version: RustVersion,
+    ) -> impl Iterator<Item = &RustRelease<Nightly>> {
+        let range = self.nightly_range(version);
+
+        self.nightly().into_iter().filter(move |release| {
+            range
+                .as_ref()
+                .map_or(false, |range| range.contains(&release.version().date))
+        })
+    }
+
+    /// The date on which each `major.minor` version was branched from `master`, by version
+    pub(crate) fn branch_dates(&self) -> BTreeMap<(u64, u64), Date> {
+        let mut branch_dates = BTreeMap::new();
+
+        // Approximation: the next version is branched when the preceding stable is released
+        for release in self.stable() {
+            if let Some(date) = release.release_date() {
+                let version = release.version().version;
+                insert_earliest(
+                    &mut branch_dates,
+                    (version.major(), version.minor() + 1),
+                    date,
+                );
+            }
+        }
+
+        let mut betas = BTreeMap::new();
+        for release in self.beta() {
+            if let Some(date) = release.release_date() {
+                let version = release.version().version;
+                insert_earliest(&mut betas, (version.major(), version.minor()), date);
+            }
+        }
+
+        // The first beta release of a version is the actual moment it was branched
+        branch_dates.extend(betas);
+
+        branch_dates
+    }
+}
+
+fn release_train(branch_dates: &BTreeMap<(u64, u64), Date>, date: &Date) -> Option<RustVersion> {
+    branch_dates
+        .iter()
+        .rfind(|(_, branched)| *branched <= date)
+        .map(|((major, minor), _)| RustVersion::new(*major, minor + 1, 0))
+}
+
+/// The position of a release in the order of [`RustReleases::all`], i.e. the version of its
+/// release train, followed by its channel. A nightly precedes the beta and stable releases of
+/// the version it was building toward, and a nightly of which the release train is not known
+/// precedes all versioned releases.
+pub(crate) fn train_order(
+    branch_dates: &BTreeMap<(u64, u64), Date>,
+    version: &ReleaseVersion,
+) -> (Option<RustVersion>, u8) {
+    match version {
+        ReleaseVersion::Alpha(alpha) => (Some(alpha.version), 0),
+        ReleaseVersion::Nightly(nightly) => (release_train(branch_dates, &nightly.date), 1),
+        ReleaseVersion::Beta(beta) => (Some(beta.version), 2),
+        ReleaseVersion::Stable(stable) => (Some(stable.version), 3),
+    }
+}
+
+fn insert_earliest(dates: &mut BTreeMap<(u64, u64), Date>, key: (u64, u64), date: &Date) {
+    let entry = dates.entry(key).or_insert_with(|| date.clone());
+
+    if date < entry {
+        *entry = date.clone();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AlphaReleases, Beta, BetaReleases, NightlyReleases, Stable, StableReleases};
+    use yare::parameterized;
+
+    fn index(with_betas: bool) -> RustReleases {
+        let stable = vec![
+            RustRelease::new(Stable::new(1, 75, 0), Some(Date::new(2023, 12, 28)), []),
+            RustRelease::new(Stable::new(1, 76, 0), Some(Date::new(2024, 2, 8)), []),
+            RustRelease::new(Stable::new(1, 77, 0), Some(Date::new(2024, 3, 21)), []),
+            RustRelease::new(Stable::new(1, 77, 1), Some(Date::new(2024, 3, 28)), []),
+            RustRelease::new(Stable::new(1, 78, 0), Some(Date::new(2024, 5, 2)), []),
+        ];
+
+        let beta = if with_betas {
+            vec![
+                beta(77, 1, Date::new(2024, 2, 3)),
+                beta(77, 2, Date::new(2024, 2, 10)),
+                beta(78, 1, Date::new(2024, 3, 16)),
+                beta(79, 1, Date::new(2024, 4, 27)),
+            ]
+        } else {
+            vec![]
+        };
+
+        let nightly = vec![
+            Date::new(2024, 2, 1),
+            Date::new(2024, 2, 5),
+            Date::new(2024, 3, 15),
+            Date::new(2024, 3, 18),
+            Date::new(2024, 5, 1),
+        ]
+        .into_iter()
+        .map(|date| RustRelease::new(Nightly { date: date.clone() }, Some(date), []))
+        .collect::<Vec<_>>();
+
+        RustReleases::new(
+            stable.into_iter().collect::<StableReleases>(),
+            beta.into_iter().collect::<BetaReleases>(),
+            nightly.into_iter().collect::<NightlyReleases>(),
+            AlphaReleases::default(),
+        )
+    }
+
+    fn beta(minor: u64, prerelease: u32, date: Date) -> RustRelease<Beta> {
+        RustRelease::new(
+            Beta {
+                version: RustVersion::new(1, minor, 0),
+                prerelease: Some(prerelease),
+            },
+            Some(date),
+            [],
+        )
+    }
+
+    #[parameterized(
+        before_branch = { Date::new(2024, 2, 1), Some(RustVersion::new(1, 77, 0)) },
+        on_branch = { Date::new(2024, 2, 3), Some(RustVersion::new(1, 78, 0)) },
+        after_branch = { Date::new(2024, 3, 1), Some(RustVersion::new(1, 78, 0)) },
+        next_train = { Date::new(2024, 3, 18), Some(RustVersion::new(1, 79, 0)) },
+        latest = { Date::new(2024, 5, 10), Some(RustVersion::new(1, 80, 0)) },
+        before_index = { Date::new(2023, 1, 1), None },
+    )]
+    fn release_train_from_betas(date: Date, expected: Option<RustVersion>) {
+        let index = index(true);
+
+        assert_eq!(index.release_train(&Nightly { date }), expected);
+    }
+
+    #[parameterized(
+        before_index = { Date::new(2023, 12, 27), None },
+        before_stable = { Date::new(2024, 2, 7), Some(RustVersion::new(1, 77, 0)) },
+        on_stable = { Date::new(2024, 2, 8), Some(RustVersion::new(1, 78, 0)) },
+        after_patch = { Date::new(2024, 3, 30), Some(RustVersion::new(1, 79, 0)) },
+    )]
+    fn release_train_from_stables(date: Date, expected: Option<RustVersion>) {
+        let index = index(false);
+
+        assert_eq!(index.release_train(&Nightly { date }), expected);
+    }
+
+    #[test]
+    fn nightly_range() {
+        let index = index(true);
+
+        let range = index.nightly_range(RustVersion::new(1, 78, 0)).unwrap();
+
+        assert_eq!(range.start(), &Date::new(2024, 2, 3));
+        assert_eq!(range.end(), Some(&Date::new(2024, 3, 16)));
+        assert!(range.contains(&Date::new(2024, 3, 15)));
+        assert!(!range.contains(&Date::new(2024, 3, 16)));
+    }
+
+    #[parameterized(
+        ongoing = { RustVersion::new(1, 80, 0), Some(Date::new(2024, 4, 27)), None },
+        patch_is_ignored = { RustVersion::new(1, 78, 1), Some(Date::new(2024, 2, 3)), Some(Date::new(2024, 3, 16)) },
+        unknown = { RustVersion::new(1, 60, 0), None, None },
+    )]
+    fn nightly_range_bounds(version: RustVersion, start: Option<Date>, end: Option<Date>) {
+        let index = index(true);
+
+        let range = index.nightly_range(version);
+
+        assert_eq!(range.as_ref().map(NightlyRange::start), start.as_ref());
+        assert_eq!(range.as_ref().and_then(NightlyRange::end), end.as_ref());
+    }
+
+    #[test]
+    fn nightlies_for() {
+        let index = index(true);
+
+        let dates = index
+            .nightlies_for(RustVersion::new(1, 78, 0))
+            .map(|release| release.version().date.clone())
+            .collect::<Vec<_>>();
+
+        assert_eq!(dates, vec![Date::new(2024, 2, 5), Date::new(2024, 3, 15)]);
+    }
+
+    #[test]
+    fn round_trip() {
+        let index = index(true);
+
+        for release in index.nightly() {
+            let train = index.release_train(release.version()).unwrap();
+
+            assert!(index.nightlies_for(train).any(|nightly| nightly == release));
+        }
+    }
+}
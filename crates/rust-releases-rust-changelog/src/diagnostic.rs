@@ -0,0 +1,101 @@
+use rust_releases_core::rust_release::date::DateError;
+use std::fmt;
+
+/// Describes a release entry in the Rust changelog which could not be parsed.
+///
+/// A diagnostic points at the exact location of the offending token, so changes to the format
+/// of the upstream `RELEASES.md` are easy to spot.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Diagnostic {
+    line: usize,
+    column: usize,
+    text: String,
+    reason: DiagnosticReason,
+}
+
+impl Diagnostic {
+    pub(crate) fn new(
+        line: usize,
+        column: usize,
+        text: impl Into<String>,
+        reason: DiagnosticReason,
+    ) -> Self {
+        Self {
+            line,
+            column,
+            text: text.into(),
+            reason,
+        }
+    }
+
+    /// The line number (1-based) of the malformed release entry.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The column (1-based, in characters) of the offending token within the line.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// The raw text of the malformed release entry.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Why the release entry could not be parsed.
+    pub fn reason(&self) -> &DiagnosticReason {
+        &self.reason
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {} (in '{}')",
+            self.line, self.column, self.reason, self.text
+        )
+    }
+}
+
+/// The reason why a release entry could not be parsed.
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+#[non_exhaustive]
+pub enum DiagnosticReason {
+    /// The release entry does not contain a version.
+    #[error("unable to find a release version")]
+    MissingVersion,
+
+    /// The release entry does not contain a release date.
+    #[error("unable to find a release date")]
+    MissingDate,
+
+    /// The version could not be parsed as a three-component `major.minor.patch` version.
+    #[error("unable to parse version '{0}'")]
+    UnsupportedVersion(String),
+
+    /// The release date could not be parsed as a `YYYY-MM-DD` date.
+    #[error("unable to parse release date '{0}': {1}")]
+    InvalidDate(String, DateError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let diagnostic = Diagnostic::new(
+            3,
+            9,
+            "Version 1.x.0 (2021-01-01)",
+            DiagnosticReason::UnsupportedVersion("1.x.0".to_string()),
+        );
+
+        assert_eq!(
+            diagnostic.to_string(),
+            "line 3, column 9: unable to parse version '1.x.0' (in 'Version 1.x.0 (2021-01-01)')"
+        );
+    }
+}
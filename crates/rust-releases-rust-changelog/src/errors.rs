@@ -1,3 +1,4 @@
+use crate::Diagnostic;
 use rust_releases_core::channel::Channel;
 
 /// A result type which binds the `RustChangelogError` to the error type.
@@ -11,21 +12,13 @@ pub enum RustChangelogError {
     #[error("Channel {0} is not available for the 'RustChangelog' source type")]
     ChannelNotAvailable(Channel),
 
-    /// Returned in case of of `chrono` parse errors
-    #[error("Unable to parse release date in a release entry '{0}': {1}")]
-    TimeParseError(String, time::error::Parse),
+    /// Returned in case a release entry is malformed, see [`Diagnostic`] for its location
+    #[error("Malformed release entry at {0}")]
+    Malformed(Diagnostic),
 
-    /// Returned when a version string cannot be parsed as a three-component `major.minor.patch` version
-    #[error("Unable to parse version '{0}")]
-    VersionParseError(String),
-
-    /// Returned in a case a release entry does not contain a recognizable release date
-    #[error("Unable to find a valid release date in a release entry")]
-    NoDateInChangelogItem,
-
-    /// Returned in a case a release entry does not contain a recognizable release version
-    #[error("Unable to find a valid version in a release entry")]
-    NoVersionInChangelogItem,
+    /// Returned in case the changelog is not cached, while it may not be fetched
+    #[error("No cached copy of the Rust changelog could be read from '{}'", .0.display())]
+    NotCached(std::path::PathBuf),
 
     /// Returned in case the base cache dir could not be found
     #[error(transparent)]
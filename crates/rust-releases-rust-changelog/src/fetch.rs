@@ -1,8 +1,9 @@
-use crate::RustChangelogResult;
+use crate::{RustChangelogError, RustChangelogResult};
 use rust_releases_io::{
     base_cache_dir, Document, HttpCachedClient, ResourceFile, RustReleasesClient,
 };
-use std::path::Path;
+use std::fs;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 const URL: &str = "https://raw.githubusercontent.com/rust-lang/rust/master/RELEASES.md";
@@ -10,12 +11,16 @@ const TIMEOUT: Duration = Duration::from_secs(86_400);
 const SOURCE_CACHE_DIR: &str = "source_rust_changelog";
 const RESOURCE_NAME: &str = "RELEASES.md";
 
-pub fn fetch(cache_dir: Option<impl AsRef<Path>>) -> RustChangelogResult<Document> {
-    let cache = if let Some(cache_dir) = cache_dir {
-        cache_dir.as_ref().join(SOURCE_CACHE_DIR)
+fn cache_folder(cache_dir: Option<impl AsRef<Path>>) -> RustChangelogResult<PathBuf> {
+    if let Some(cache_dir) = cache_dir {
+        Ok(cache_dir.as_ref().join(SOURCE_CACHE_DIR))
     } else {
-        base_cache_dir()?.join(SOURCE_CACHE_DIR)
-    };
+        Ok(base_cache_dir()?.join(SOURCE_CACHE_DIR))
+    }
+}
+
+pub fn fetch(cache_dir: Option<impl AsRef<Path>>) -> RustChangelogResult<Document> {
+    let cache = cache_folder(cache_dir)?;
 
     let client = HttpCachedClient::new(cache, TIMEOUT);
     let source = client.fetch(ResourceFile::new(URL, RESOURCE_NAME))?;
@@ -23,6 +28,15 @@ pub fn fetch(cache_dir: Option<impl AsRef<Path>>) -> RustChangelogResult<Documen
     Ok(source.into_document())
 }
 
+// Reads the cached copy of the changelog, regardless of whether it is stale, without accessing
+// the network
+pub fn cached(cache_dir: Option<impl AsRef<Path>>) -> RustChangelogResult<Document> {
+    let path = cache_folder(cache_dir)?.join(RESOURCE_NAME);
+    let buffer = fs::read(&path).map_err(|_| RustChangelogError::NotCached(path))?;
+
+    Ok(Document::new(buffer))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -6,20 +6,23 @@
 //! [`rust-releases`]: https://docs.rs/rust-releases
 #[cfg(test)]
 extern crate rust_releases_io;
+use rust_release::date::Date;
 use rust_release::toolchain::RustVersion;
 use rust_releases_core::channel::Channel;
-use rust_releases_core::releases::StableReleases;
-use rust_releases_core::{rust_release, RustRelease, Stable};
+use rust_releases_core::releases::{AlphaReleases, StableReleases};
+use rust_releases_core::{rust_release, Alpha, RustRelease, Stable};
 use rust_releases_io::Document;
 
+pub(crate) mod diagnostic;
 pub(crate) mod errors;
 pub(crate) mod fetch;
+pub(crate) mod notes;
 
-use crate::fetch::fetch;
+use crate::fetch::{cached, fetch};
 
+pub use diagnostic::{Diagnostic, DiagnosticReason};
 pub use errors::{RustChangelogError, RustChangelogResult};
-use std::str::FromStr;
-use time::macros::format_description;
+pub use notes::ReleaseNotes;
 
 /// A source which obtains release data from the official Rust changelog.
 pub struct RustChangelog {
@@ -28,19 +31,21 @@ pub struct RustChangelog {
     /// Used to compare against the date of an unreleased version which does already exist in the
     /// changelog. If this date is at least as late as the time found in a release registration, we
     /// will say that such a version is released (i.e. published).
-    today: ReleaseDate,
+    today: Date,
 }
 
 impl RustChangelog {
-    pub(crate) fn from_document(source: Document) -> Self {
+    /// Create a source from a copy of the official Rust changelog (`RELEASES.md`), e.g. a
+    /// snapshot which was stored earlier.
+    pub fn from_document(source: Document) -> Self {
         Self {
             source,
-            today: ReleaseDate::today(),
+            today: Date::today(),
         }
     }
 
     #[cfg(test)]
-    pub(crate) fn from_document_with_date(source: Document, date: ReleaseDate) -> Self {
+    pub(crate) fn from_document_with_date(source: Document, date: Date) -> Self {
         Self {
             source,
             today: date,
@@ -48,25 +53,106 @@ impl RustChangelog {
     }
 
     /// Build an index of all known stable releases from the official Rust changelog.
+    ///
+    /// Release entries with a version which is not recognized are skipped. Any other malformed
+    /// release entry aborts the build with a [`RustChangelogError::Malformed`] error.
+    ///
+    /// Releases from the historic alpha period are not included, see [`RustChangelog::build_alpha_index`]
+    /// instead.
+    ///
+    /// See also [`RustChangelog::build_index_lenient`] and [`RustChangelog::build_index_strict`].
     pub fn build_index(&self) -> Result<StableReleases, RustChangelogError> {
-        let buffer = self.source.buffer();
-        let content = std::str::from_utf8(buffer).map_err(RustChangelogError::UnrecognizedText)?;
+        let mut releases = StableReleases::default();
+
+        for entry in self.entries()? {
+            if let Some(Entry::Stable(release)) = skip_unsupported_version(entry)? {
+                releases.add(release);
+            }
+        }
+
+        Ok(releases)
+    }
+
+    /// Build an index of all releases from the historic alpha period from the official Rust
+    /// changelog, i.e. the pre-1.0 `0.x` releases and the `1.0.0-alpha` pre-releases.
+    ///
+    /// Like [`RustChangelog::build_index`], release entries with a version which is not recognized
+    /// are skipped, while any other malformed release entry aborts the build.
+    pub fn build_alpha_index(&self) -> Result<AlphaReleases, RustChangelogError> {
+        let mut releases = AlphaReleases::default();
+
+        for entry in self.entries()? {
+            if let Some(Entry::Alpha(release)) = skip_unsupported_version(entry)? {
+                releases.add(release);
+            }
+        }
 
+        Ok(releases)
+    }
+
+    /// Build an index of all known stable releases from the official Rust changelog, while
+    /// collecting a [`Diagnostic`] for each release entry which could not be parsed.
+    ///
+    /// Malformed release entries do not abort the build. Instead, the index is built from
+    /// the release entries which could be parsed.
+    pub fn build_index_lenient(&self) -> Result<LenientIndex, RustChangelogError> {
+        let mut releases = StableReleases::default();
+        let mut diagnostics = Vec::new();
+
+        for entry in self.entries()? {
+            match entry {
+                Ok(Some(Entry::Stable(release))) => releases.add(release),
+                Ok(Some(Entry::Alpha(_))) | Ok(None) => {}
+                Err(diagnostic) => diagnostics.push(diagnostic),
+            }
+        }
+
+        Ok(LenientIndex {
+            releases,
+            diagnostics,
+        })
+    }
+
+    /// Build an index of all known stable releases from the official Rust changelog, failing on
+    /// the first release entry which could not be parsed.
+    ///
+    /// The returned [`RustChangelogError::Malformed`] error describes the precise location of
+    /// the malformed release entry.
+    pub fn build_index_strict(&self) -> Result<StableReleases, RustChangelogError> {
         let mut releases = StableReleases::default();
-        for line in content.lines().filter(|s| s.starts_with("Version")) {
-            match create_release(line, &self.today) {
-                Some(Ok(release)) => releases.add(release),
-                Some(Err(e)) => return Err(e),
-                None => {}
+
+        for entry in self.entries()? {
+            if let Some(Entry::Stable(release)) = entry.map_err(RustChangelogError::Malformed)? {
+                releases.add(release);
             }
         }
 
         Ok(releases)
     }
 
+    // Parses each `Version ...` header of the changelog. Releases which are not yet available
+    // are returned as `Ok(None)`.
+    fn entries(
+        &self,
+    ) -> Result<impl Iterator<Item = Result<Option<Entry>, Diagnostic>> + '_, RustChangelogError>
+    {
+        let buffer = self.source.buffer();
+        let content = std::str::from_utf8(buffer).map_err(RustChangelogError::UnrecognizedText)?;
+
+        let entries = content
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| line.starts_with("Version"))
+            .map(move |(index, line)| create_release(index + 1, line, &self.today));
+
+        Ok(entries)
+    }
+
     /// Fetch all known releases from the official rust changelog
+    ///
+    /// Supports the stable and the historic alpha channels.
     pub fn fetch_channel(channel: Channel) -> Result<Self, RustChangelogError> {
-        if let Channel::Stable = channel {
+        if let Channel::Stable | Channel::Alpha = channel {
             // todo: add support for custom cache locations
             let document = fetch(None::<&str>)?;
             Ok(Self::from_document(document))
@@ -74,107 +160,214 @@ impl RustChangelog {
             Err(RustChangelogError::ChannelNotAvailable(channel))
         }
     }
-}
 
-/// Create a release from a `Version ...` header in the Rust changelog file (`RELEASES.md`).
-///
-/// We skip a few older versions which did not use full 3-component semver versions.
-/// While we could parse them as `SemverReq` requirements, adding those would not be worth the hassle
-///   (at least for now).
-///
-/// Versions which we should be able to parse, and are based on their release date available, are
-///   returned as `Some(Result<Release, Error>)`.
-/// If a version is not yet available based on their release date we return `None`.
-/// Versions we currently do not support are also returned as `None`.
-///
-/// The resulting releases can then be filtered on `Option::is_some`, to only keep relevant results.
-fn create_release(
-    line: &str,
-    today: &ReleaseDate,
-) -> Option<RustChangelogResult<RustRelease<Stable>>> {
-    let parsed = parse_release(line.split_ascii_whitespace());
-
-    match parsed {
-        // If the version and date can be parsed, and the version has been released
-        Ok((stable, date)) if date.is_available(today) => {
-            let release_date = rust_release::date::Date::new(
-                date.0.year() as u16,
-                date.0.month() as u8,
-                date.0.day(),
-            );
-            Some(Ok(RustRelease::new(stable, Some(release_date), [])))
+    /// Load the releases from the cached copy of the official Rust changelog, without accessing
+    /// the network, regardless of whether the cached copy is stale.
+    ///
+    /// Supports the stable and the historic alpha channels. Returns
+    /// [`RustChangelogError::NotCached`] if no copy was cached before.
+    pub fn cached_channel(channel: Channel) -> Result<Self, RustChangelogError> {
+        if let Channel::Stable | Channel::Alpha = channel {
+            let document = cached(None::<&str>)?;
+            Ok(Self::from_document(document))
+        } else {
+            Err(RustChangelogError::ChannelNotAvailable(channel))
         }
-        // If the version and date can be parsed, but the version is not yet released
-        Ok(_) => None,
-        // VersionParseError covers pre-release versions (1.0.0-alpha, 1.0.0-beta.1) and
-        // two-component versions (0.10, 0.9, etc.)
-        Err(RustChangelogError::VersionParseError(_)) => None,
-        // In any ony other error case, we forward the error
-        Err(err) => Some(Err(err)),
     }
 }
 
-fn parse_release<'line>(
-    mut parts: impl Iterator<Item = &'line str>,
-) -> Result<(Stable, ReleaseDate), RustChangelogError> {
-    let version_number = parts
-        .nth(1)
-        .ok_or(RustChangelogError::NoVersionInChangelogItem)?;
-    let release_date = parts
-        .next()
-        .ok_or(RustChangelogError::NoDateInChangelogItem)?;
+/// The result of [`RustChangelog::build_index_lenient`]: a (partial) index of the release entries
+/// which could be parsed, and a diagnostic for each release entry which could not.
+#[derive(Debug)]
+pub struct LenientIndex {
+    releases: StableReleases,
+    diagnostics: Vec<Diagnostic>,
+}
 
-    let stable = version_number
-        .parse::<RustVersion>()
-        .map(Stable::from)
-        .map_err(|_| RustChangelogError::VersionParseError(version_number.to_string()))?;
+impl LenientIndex {
+    /// The releases which could be parsed.
+    pub fn releases(&self) -> &StableReleases {
+        &self.releases
+    }
 
-    let date = ReleaseDate::parse(&release_date[1..release_date.len() - 1])?;
+    /// The diagnostics of the release entries which could not be parsed, in order of appearance.
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
 
-    Ok((stable, date))
+    /// Consumes the index, returning the releases and the diagnostics.
+    pub fn into_parts(self) -> (StableReleases, Vec<Diagnostic>) {
+        (self.releases, self.diagnostics)
+    }
 }
 
-#[derive(Debug)]
-struct ReleaseDate(time::Date);
+/// A parsed release entry.
+enum Entry {
+    Stable(RustRelease<Stable>),
+    Alpha(RustRelease<Alpha>),
+}
 
-impl ReleaseDate {
-    fn today() -> Self {
-        let date = time::OffsetDateTime::now_utc().date();
+/// The version found in the heading of a release entry.
+#[derive(Debug, Eq, PartialEq)]
+enum HeadingVersion {
+    Stable(Stable),
+    Alpha(Alpha),
+}
 
-        Self(date)
+/// Drops release entries with a version which is not recognized, and turns the diagnostic of any
+/// other malformed release entry into an error.
+fn skip_unsupported_version(
+    entry: Result<Option<Entry>, Diagnostic>,
+) -> Result<Option<Entry>, RustChangelogError> {
+    match entry {
+        Err(diagnostic)
+            if matches!(diagnostic.reason(), DiagnosticReason::UnsupportedVersion(_)) =>
+        {
+            Ok(None)
+        }
+        entry => entry.map_err(RustChangelogError::Malformed),
     }
+}
 
-    fn parse(from: &str) -> Result<Self, RustChangelogError> {
-        from.parse::<ReleaseDate>()
+/// Create a release from a `Version ...` header in the Rust changelog file (`RELEASES.md`).
+///
+/// Versions which we should be able to parse, and are based on their release date available, are
+///   returned as `Ok(Some(Entry))`.
+/// If a version is not yet available based on their release date we return `Ok(None)`.
+/// Versions we can not parse are returned as an `Err(Diagnostic)`, which points to the offending
+///   token.
+fn create_release(
+    line_number: usize,
+    line: &str,
+    today: &Date,
+) -> Result<Option<Entry>, Diagnostic> {
+    let (version, date) = parse_release(tokens(line))
+        .map_err(|(column, reason)| Diagnostic::new(line_number, column, line, reason))?;
+
+    // If the version and date can be parsed, but the version is not yet released
+    if !is_available(&date, today) {
+        return Ok(None);
     }
 
-    fn is_available(&self, today: &Self) -> bool {
-        today.0 >= self.0
-    }
+    let release_date = Some(date);
+
+    let entry = match version {
+        HeadingVersion::Stable(stable) => Entry::Stable(RustRelease::new(stable, release_date, [])),
+        HeadingVersion::Alpha(alpha) => Entry::Alpha(RustRelease::new(alpha, release_date, [])),
+    };
+
+    Ok(Some(entry))
 }
 
-impl FromStr for ReleaseDate {
-    type Err = crate::RustChangelogError;
+/// Splits a line into whitespace separated tokens, each paired with its 1-based column, counted
+/// in characters.
+fn tokens(line: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut chars = line.char_indices().enumerate().peekable();
+
+    std::iter::from_fn(move || {
+        while chars.next_if(|(_, (_, c))| c.is_whitespace()).is_some() {}
+
+        let (column, (start, first)) = chars.next()?;
+        let mut end = start + first.len_utf8();
 
-    fn from_str(item: &str) -> Result<Self, Self::Err> {
-        let format = format_description!("[year]-[month]-[day]");
+        while let Some((_, (index, c))) = chars.next_if(|(_, (_, c))| !c.is_whitespace()) {
+            end = index + c.len_utf8();
+        }
+
+        Some((column + 1, &line[start..end]))
+    })
+}
 
-        let result = time::Date::parse(item.trim(), &format)
-            .map_err(|err| RustChangelogError::TimeParseError(item.to_string(), err))?;
+/// Parses the version and release date tokens of a release entry.
+///
+/// The release date is usually enclosed in parentheses, e.g. `Version 1.2.3 (2015-08-07)`, but
+/// the parentheses are optional. Any tokens following the release date are ignored.
+///
+/// On failure, returns the column of the offending token together with the reason.
+fn parse_release<'line>(
+    mut parts: impl Iterator<Item = (usize, &'line str)>,
+) -> Result<(HeadingVersion, Date), (usize, DiagnosticReason)> {
+    let (heading_column, _) = parts.next().unwrap_or((1, ""));
+
+    let (version_column, version_number) = parts
+        .next()
+        .ok_or((heading_column, DiagnosticReason::MissingVersion))?;
+    let (date_column, release_date) = parts
+        .next()
+        .ok_or((version_column, DiagnosticReason::MissingDate))?;
+
+    let version = parse_version(version_number).ok_or_else(|| {
+        (
+            version_column,
+            DiagnosticReason::UnsupportedVersion(version_number.to_string()),
+        )
+    })?;
+
+    let (date_column, date_text) = match release_date.strip_prefix('(') {
+        Some(rest) => (date_column + 1, rest),
+        None => (date_column, release_date),
+    };
+    let date_text = date_text.strip_suffix(')').unwrap_or(date_text);
+
+    let date = date_text.parse::<Date>().map_err(|err| {
+        (
+            date_column,
+            DiagnosticReason::InvalidDate(date_text.to_string(), err),
+        )
+    })?;
+
+    Ok((version, date))
+}
 
-        Ok(Self(result))
+/// Parses the version of a release entry.
+///
+/// Recognizes the following variants:
+/// * `X.Y.Z`: a stable release, or, if the major version is `0`, a pre-1.0 release
+/// * `0.Y`: a two component pre-1.0 release
+/// * `X.Y.Z-alpha` and `X.Y.Z-alpha.N`: an alpha pre-release
+fn parse_version(version: &str) -> Option<HeadingVersion> {
+    let (number, label) = match version.split_once('-') {
+        Some((number, label)) => (number, Some(label)),
+        None => (version, None),
+    };
+
+    let rust_version = match number.matches('.').count() {
+        1 if number.starts_with("0.") => format!("{}.0", number).parse::<RustVersion>(),
+        2 => number.parse::<RustVersion>(),
+        _ => return None,
     }
+    .ok()?;
+
+    let prerelease = match label {
+        None => None,
+        Some("alpha") => Some(1),
+        Some(label) => Some(label.strip_prefix("alpha.")?.parse::<u32>().ok()?),
+    };
+
+    if rust_version.major() == 0 || prerelease.is_some() {
+        Some(HeadingVersion::Alpha(Alpha {
+            version: rust_version,
+            prerelease,
+        }))
+    } else {
+        Some(HeadingVersion::Stable(Stable::from(rust_version)))
+    }
+}
+
+/// Whether a release with the given release date is released (i.e. published) as of `today`.
+fn is_available(release_date: &Date, today: &Date) -> bool {
+    release_date <= today
 }
 
 #[cfg(test)]
 mod tests {
-    use super::ReleaseDate;
-    use crate::RustChangelog;
+    use crate::{Diagnostic, DiagnosticReason, RustChangelog, RustChangelogError};
     use rust_releases_core::channel::Channel;
-    use rust_releases_core::Stable;
+    use rust_releases_core::rust_release::date::Date;
+    use rust_releases_core::rust_release::toolchain::RustVersion;
+    use rust_releases_core::{Alpha, Stable};
     use rust_releases_io::Document;
     use std::fs;
-    use time::macros::date;
     use yare::parameterized;
 
     #[test]
@@ -191,18 +384,251 @@ mod tests {
         let source = RustChangelog::from_document(document);
         let releases = source.build_index().unwrap();
 
-        assert_eq!(releases.len(), 72);
+        // 1.0.0 up to and including 1.50.0; 0.12.0 and 0.11.0 are part of the alpha index
+        assert_eq!(releases.len(), 70);
+        assert_eq!(
+            releases.iter().next().unwrap().version,
+            Stable::new(1, 0, 0)
+        );
         assert_eq!(
             releases.iter().last().unwrap().version,
             Stable::new(1, 50, 0)
         );
     }
 
+    #[test]
+    fn source_alpha_index() {
+        let path = [
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../resources/rust_changelog/RELEASES.md",
+        ]
+        .join("");
+
+        let buffer = fs::read(path).unwrap();
+        let document = Document::new(buffer);
+
+        let source = RustChangelog::from_document(document);
+        let releases = source.build_alpha_index().unwrap();
+
+        // 0.1 up to and including 0.12.0, 1.0.0-alpha and 1.0.0-alpha.2
+        assert_eq!(releases.len(), 14);
+
+        let first = releases.iter().next().unwrap();
+        assert_eq!(first.version.to_string(), "0.1.0");
+        assert_eq!(first.release_date(), Some(&Date::new(2012, 1, 20)));
+
+        let last = releases.iter().last().unwrap();
+        assert_eq!(last.version.to_string(), "1.0.0-alpha.2");
+        assert_eq!(last.release_date(), Some(&Date::new(2015, 2, 20)));
+    }
+
+    #[test]
+    fn strict_index_accepts_all_historic_headings() {
+        let path = [
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../resources/rust_changelog/RELEASES.md",
+        ]
+        .join("");
+
+        let buffer = fs::read(path).unwrap();
+        let document = Document::new(buffer);
+
+        let source = RustChangelog::from_document(document);
+        let releases = source.build_index_strict().unwrap();
+
+        assert_eq!(releases.len(), 70);
+    }
+
+    #[test]
+    fn lenient_index_collects_diagnostics() {
+        let document = Document::new(
+            b"Version 1.2.0 (2015-08-07)\nVersion 1.1.0-beta (2015-06-25)\nVersion 1.x (2015-05-15)\n"
+                .to_vec(),
+        );
+
+        let source = RustChangelog::from_document(document);
+        let index = source.build_index_lenient().unwrap();
+
+        assert_eq!(index.releases().len(), 1);
+        assert_eq!(index.diagnostics().len(), 2);
+
+        let first = &index.diagnostics()[0];
+        assert_eq!(first.line(), 2);
+        assert_eq!(first.column(), 9);
+        assert_eq!(first.text(), "Version 1.1.0-beta (2015-06-25)");
+        assert_eq!(
+            first.reason(),
+            &DiagnosticReason::UnsupportedVersion("1.1.0-beta".to_string())
+        );
+
+        let second = &index.diagnostics()[1];
+        assert_eq!(second.line(), 3);
+        assert_eq!(
+            second.reason(),
+            &DiagnosticReason::UnsupportedVersion("1.x".to_string())
+        );
+    }
+
+    #[parameterized(
+        stable = { "Version 1.2.3 (2015-08-07)", Stable::new(1, 2, 3) },
+        point_release = { "Version 1.12.1 (2016-10-20)", Stable::new(1, 12, 1) },
+        double_space = { "Version 1.2.3  (2015-08-07)", Stable::new(1, 2, 3) },
+        without_parentheses = { "Version 1.2.3 2015-08-07", Stable::new(1, 2, 3) },
+        trailing_text = { "Version 1.2.3 (2015-08-07) (yanked)", Stable::new(1, 2, 3) },
+    )]
+    fn stable_heading(line: &str, expected: Stable) {
+        let document = Document::new(line.as_bytes().to_vec());
+
+        let source = RustChangelog::from_document(document);
+        let releases = source.build_index_strict().unwrap();
+
+        assert_eq!(releases.len(), 1);
+        assert_eq!(releases.iter().next().unwrap().version, expected);
+    }
+
+    #[parameterized(
+        three_component = { "Version 0.12.0 (2014-10-09)", RustVersion::new(0, 12, 0), None },
+        two_component = { "Version 0.10 (2014-04-03)", RustVersion::new(0, 10, 0), None },
+        two_component_double_space = { "Version 0.3  (2012-07-12)", RustVersion::new(0, 3, 0), None },
+        alpha = { "Version 1.0.0-alpha (2015-01-09)", RustVersion::new(1, 0, 0), Some(1) },
+        alpha_n = { "Version 1.0.0-alpha.2 (2015-02-20)", RustVersion::new(1, 0, 0), Some(2) },
+    )]
+    fn alpha_heading(line: &str, version: RustVersion, prerelease: Option<u32>) {
+        let document = Document::new(line.as_bytes().to_vec());
+
+        let source = RustChangelog::from_document(document);
+        let releases = source.build_alpha_index().unwrap();
+
+        assert_eq!(releases.len(), 1);
+        assert_eq!(
+            releases.iter().next().unwrap().version,
+            Alpha {
+                version,
+                prerelease
+            }
+        );
+        assert!(source.build_index_strict().unwrap().is_empty());
+    }
+
+    #[parameterized(
+        ascii = { "Version 1.x.0 (2015-08-07)", 9 },
+        non_ascii_heading = { "Versionü 1.x.0 (2015-08-07)", 10 },
+        non_ascii_whitespace = { "Version\u{a0}\u{a0}1.x.0 (2015-08-07)", 10 },
+        single_character_token = { "Version x (2015-08-07)", 9 },
+    )]
+    fn column_in_characters(line: &str, column: usize) {
+        let document = Document::new(line.as_bytes().to_vec());
+
+        let source = RustChangelog::from_document(document);
+        let (_, diagnostics) = source.build_index_lenient().unwrap().into_parts();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].column(), column);
+    }
+
+    #[parameterized(
+        open_parenthesis = { "Version 1.2.3 (", 16 },
+        close_parenthesis = { "Version 1.2.3 )", 15 },
+        empty_parentheses = { "Version 1.2.3 ()", 16 },
+    )]
+    fn short_date_token(line: &str, column: usize) {
+        let document = Document::new(line.as_bytes().to_vec());
+
+        let source = RustChangelog::from_document(document);
+        let (_, diagnostics) = source.build_index_lenient().unwrap().into_parts();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].column(), column);
+        assert!(matches!(
+            diagnostics[0].reason(),
+            DiagnosticReason::InvalidDate(_, _)
+        ));
+    }
+
+    #[test]
+    fn strict_index_fails_with_location() {
+        let document = Document::new(
+            b"Version 1.2.0 (2015-08-07)\n\nVersion 1.1.0 (2015-06-25)\nVersion 1.0.0 (2015-15-15)\n"
+                .to_vec(),
+        );
+
+        let source = RustChangelog::from_document(document);
+        let err = source.build_index_strict().unwrap_err();
+
+        let RustChangelogError::Malformed(diagnostic) = err else {
+            panic!("expected a malformed release entry error, got: {}", err);
+        };
+
+        assert_eq!(diagnostic.line(), 4);
+        assert_eq!(diagnostic.column(), 16);
+        assert!(matches!(
+            diagnostic.reason(),
+            DiagnosticReason::InvalidDate(date, _) if date == "2015-15-15"
+        ));
+    }
+
+    #[test]
+    fn strict_index_fails_on_unsupported_version() {
+        let document =
+            Document::new(b"Version 1.1.0 (2015-06-25)\nVersion 1.0 (2015-05-15)\n".to_vec());
+
+        let source = RustChangelog::from_document(document);
+        let err = source.build_index_strict().unwrap_err();
+
+        let RustChangelogError::Malformed(diagnostic) = err else {
+            panic!("expected a malformed release entry error, got: {}", err);
+        };
+
+        assert_eq!(diagnostic.line(), 2);
+        assert_eq!(diagnostic.column(), 9);
+    }
+
+    #[parameterized(
+        missing_version = { "Version", 1, DiagnosticReason::MissingVersion },
+        missing_date = { "Version 1.0.0", 9, DiagnosticReason::MissingDate },
+    )]
+    fn lenient_index_incomplete_entry(line: &str, column: usize, reason: DiagnosticReason) {
+        let document = Document::new(line.as_bytes().to_vec());
+
+        let source = RustChangelog::from_document(document);
+        let (releases, diagnostics) = source.build_index_lenient().unwrap().into_parts();
+
+        assert!(releases.is_empty());
+        assert_eq!(diagnostics, vec![Diagnostic::new(1, column, line, reason)]);
+    }
+
+    #[test]
+    fn index_aborts_on_invalid_date() {
+        let document =
+            Document::new(b"Version 1.0.0 (2015-05-15)\nVersion 1.1.0 (2015-6-25)\n".to_vec());
+
+        let source = RustChangelog::from_document(document);
+        let err = source.build_index().unwrap_err();
+
+        assert!(
+            matches!(err, RustChangelogError::Malformed(ref diagnostic) if diagnostic.line() == 2),
+            "expected a malformed release entry error at line 2, got: {}",
+            err
+        );
+    }
+
     #[test]
     fn parse_date() {
-        let date = ReleaseDate::parse("2021-09-01").unwrap();
-        let expected = date!(2021 - 09 - 01);
-        assert_eq!(date.0, expected);
+        let (_, date) = crate::parse_release(crate::tokens("Version 1.55.0 (2021-09-09)")).unwrap();
+
+        assert_eq!(date, Date::new(2021, 9, 9));
+    }
+
+    #[parameterized(
+        before = { Date::new(2021, 9, 8), false },
+        same_day = { Date::new(2021, 9, 9), true },
+        after = { Date::new(2021, 9, 10), true },
+    )]
+    fn availability(today: Date, expected: bool) {
+        assert_eq!(
+            crate::is_available(&Date::new(2021, 9, 9), &today),
+            expected
+        );
     }
 
     #[test]
@@ -215,7 +641,7 @@ mod tests {
         let buffer = fs::read(path).unwrap();
         let document = Document::new(buffer);
 
-        let date = ReleaseDate::parse("2021-09-01").unwrap();
+        let date = Date::new(2021, 9, 1);
         let strategy = RustChangelog::from_document_with_date(document, date);
         let index = strategy.build_index().unwrap();
 
@@ -0,0 +1,151 @@
+use crate::{
+    is_available, parse_release, tokens, HeadingVersion, RustChangelog, RustChangelogError,
+};
+use rust_releases_core::ReleaseVersion;
+use std::collections::BTreeMap;
+
+impl RustChangelog {
+    /// Collect the release notes of each release entry in the official Rust changelog.
+    ///
+    /// The notes of a release are the Markdown text between its `Version ...` heading and the
+    /// heading of the next release entry, without the heading and its underline. Release
+    /// entries which could not be parsed, or which are not yet released, are skipped.
+    pub fn release_notes(&self) -> Result<ReleaseNotes, RustChangelogError> {
+        let buffer = self.source.buffer();
+        let content = std::str::from_utf8(buffer).map_err(RustChangelogError::UnrecognizedText)?;
+
+        let mut notes = BTreeMap::new();
+        let mut current: Option<(ReleaseVersion, Vec<&str>)> = None;
+
+        for line in content.lines() {
+            if line.starts_with("Version") {
+                if let Some((version, lines)) = current.take() {
+                    notes.insert(version, join(&lines));
+                }
+
+                current = match parse_release(tokens(line)) {
+                    Ok((version, date)) if is_available(&date, &self.today) => {
+                        Some((release_version(version), Vec::new()))
+                    }
+                    _ => None,
+                };
+            } else if let Some((_, lines)) = current.as_mut() {
+                lines.push(line);
+            }
+        }
+
+        if let Some((version, lines)) = current {
+            notes.insert(version, join(&lines));
+        }
+
+        Ok(ReleaseNotes { notes })
+    }
+}
+
+fn release_version(version: HeadingVersion) -> ReleaseVersion {
+    match version {
+        HeadingVersion::Stable(stable) => ReleaseVersion::Stable(stable),
+        HeadingVersion::Alpha(alpha) => ReleaseVersion::Alpha(alpha),
+    }
+}
+
+// Drops the underline of the heading, and the blank lines which surround the notes
+fn join(lines: &[&str]) -> String {
+    let lines = match lines.first() {
+        Some(first) if !first.is_empty() && first.chars().all(|c| c == '=') => &lines[1..],
+        _ => lines,
+    };
+
+    lines.join("\n").trim_matches('\n').to_string()
+}
+
+/// The release notes of each release entry in the official Rust changelog, as Markdown.
+///
+/// See [`RustChangelog::release_notes`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ReleaseNotes {
+    notes: BTreeMap<ReleaseVersion, String>,
+}
+
+impl ReleaseNotes {
+    /// The release notes of the given version, if the changelog contains them.
+    pub fn get(&self, version: &ReleaseVersion) -> Option<&str> {
+        self.notes.get(version).map(String::as_str)
+    }
+
+    /// Get the number of releases with release notes
+    pub fn len(&self) -> usize {
+        self.notes.len()
+    }
+
+    /// Returns true if there are no release notes, and false otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.notes.is_empty()
+    }
+
+    /// Iterate over the release notes of each version, ordered by version
+    pub fn iter(&self) -> impl Iterator<Item = (&ReleaseVersion, &str)> {
+        self.notes
+            .iter()
+            .map(|(version, notes)| (version, notes.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::RustChangelog;
+    use rust_releases_core::rust_release::date::Date;
+    use rust_releases_core::rust_release::toolchain::RustVersion;
+    use rust_releases_core::{Alpha, ReleaseVersion, Stable};
+    use rust_releases_io::Document;
+    use std::fs;
+
+    #[test]
+    fn release_notes() {
+        let document = Document::new(
+            b"Version 1.2.0 (2015-08-07)\n==========================\n\nLibraries\n---------\n- a\n\nVersion 1.x (2015-06-25)\n- skipped\n\nVersion 1.0.0-alpha (2015-01-09)\n- b\n"
+                .to_vec(),
+        );
+
+        let source = RustChangelog::from_document(document);
+        let notes = source.release_notes().unwrap();
+
+        assert_eq!(notes.len(), 2);
+        assert_eq!(
+            notes.get(&ReleaseVersion::Stable(Stable::new(1, 2, 0))),
+            Some("Libraries\n---------\n- a")
+        );
+        assert_eq!(
+            notes.get(&ReleaseVersion::Alpha(Alpha {
+                version: RustVersion::new(1, 0, 0),
+                prerelease: Some(1),
+            })),
+            Some("- b")
+        );
+    }
+
+    #[test]
+    fn release_notes_of_changelog() {
+        let path = [
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../resources/rust_changelog/RELEASES_with_unreleased.md",
+        ]
+        .join("");
+        let buffer = fs::read(path).unwrap();
+        let document = Document::new(buffer);
+
+        let date = Date::new(2021, 9, 1);
+        let source = RustChangelog::from_document_with_date(document, date);
+        let notes = source.release_notes().unwrap();
+        let index = source.build_index().unwrap();
+
+        // Each released stable release has notes, the unreleased version does not
+        for release in index.iter() {
+            let version = ReleaseVersion::Stable(release.version().clone());
+            assert!(notes.get(&version).is_some(), "{}", version);
+        }
+
+        let (latest, _) = notes.iter().last().unwrap();
+        assert_eq!(latest, &ReleaseVersion::Stable(Stable::new(1, 54, 0)));
+    }
+}
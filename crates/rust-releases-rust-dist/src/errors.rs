@@ -35,6 +35,26 @@ pub enum RustDistError {
     #[error(transparent)]
     Io(#[from] std::io::Error),
 
+    /// Returned in case the distribution listing is not cached, while it may not be fetched.
+    #[error("No cached copy of the Rust distribution listing could be read from '{}'", .0.display())]
+    NotCached(std::path::PathBuf),
+
+    /// Returned in case a channel manifest could not be fetched.
+    #[error(transparent)]
+    CachedClient(#[from] rust_releases_io::HttpCachedClientError),
+
+    /// Returned in case a channel manifest does not describe a release.
+    #[error("Malformed channel manifest: {0}")]
+    MalformedManifest(String),
+
+    /// Returned in place of a release, in case the channel manifest of the given date could
+    /// not be fetched or read.
+    #[error("Unable to read the channel manifest of {0}: {1}")]
+    InvalidManifest(
+        rust_releases_core::rust_release::date::Date,
+        #[source] Box<RustDistError>,
+    ),
+
     /// Returned in case of the base cache folder could not be found.
     #[error(transparent)]
     BaseCacheDir(#[from] rust_releases_io::BaseCacheDirError),
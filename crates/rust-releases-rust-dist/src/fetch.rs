@@ -248,6 +248,14 @@ pub(crate) fn fetch() -> RustDistResult<Document> {
     buffer.try_into()
 }
 
+// Reads the cached listing, regardless of whether it is stale, without accessing the network
+pub(crate) fn cached() -> RustDistResult<Document> {
+    let path = cache_file_path()?;
+    let buffer = fs::read(&path).map_err(|_| RustDistError::NotCached(path))?;
+
+    Ok(Document::new(buffer))
+}
+
 fn write_objects(buffer: &mut impl Write, objects: &[Object]) -> Option<String> {
     for object in objects {
         if let Some(key) = object.key.as_deref() {
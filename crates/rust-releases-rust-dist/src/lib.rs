@@ -8,14 +8,18 @@
 
 use regex::{Captures, Regex};
 use rust_releases_core::channel::Channel;
-use rust_releases_core::releases::StableReleases;
-use rust_releases_core::{RustRelease, Stable};
+use rust_releases_core::releases::{AlphaReleases, BetaReleases, StableReleases};
+use rust_releases_core::rust_release::toolchain::RustVersion;
+use rust_releases_core::{Alpha, Beta, RustRelease, Stable};
 use rust_releases_io::Document;
+use std::str::FromStr;
 
 pub(crate) mod errors;
 pub(crate) mod fetch;
+pub(crate) mod manifests;
 
 pub use crate::errors::{RustDistError, RustDistResult};
+pub use crate::manifests::{parse_beta_manifest, BetaManifests};
 
 /// A source which obtains its input data from the Rust distribution bucket on AWS S3.
 pub struct RustDist {
@@ -23,15 +27,18 @@ pub struct RustDist {
 }
 
 impl RustDist {
-    #[cfg(test)]
-    pub(crate) fn from_document(source: Document) -> Self {
+    /// Create a source from a listing of the Rust distribution bucket, e.g. a snapshot which
+    /// was stored earlier.
+    ///
+    /// The listing contains one object key per line, like `dist/rustc-1.70.0-src.tar.gz`.
+    pub fn from_document(source: Document) -> Self {
         Self { source }
     }
 }
 
 lazy_static::lazy_static! {
     static ref MATCHER: Regex =
-        Regex::new(r"(?m)^dist/rustc-(?P<major>\d+).(?P<minor>\d+).(?P<patch>\d+)(?:\-(alpha|beta|nightly)(\.\d+))?").unwrap();
+        Regex::new(r"(?m)^dist/rustc-(?P<major>\d+).(?P<minor>\d+).(?P<patch>\d+)(?:\-(?P<label>alpha|beta|nightly)(?:\.(?P<prerelease>\d+))?)?").unwrap();
 }
 
 impl RustDist {
@@ -42,43 +49,114 @@ impl RustDist {
 
         let mut releases = StableReleases::default();
         for capture in MATCHER.captures_iter(content) {
-            releases.add(parse_release(capture)?);
+            if capture.name(LABEL).is_none() {
+                let version = parse_version(&capture)?;
+                releases.add(RustRelease::new(Stable::from(version), None, []));
+            }
+        }
+
+        Ok(releases)
+    }
+
+    /// Build an index of all known historic alpha releases from the Rust distribution bucket.
+    pub fn build_alpha_index(&self) -> Result<AlphaReleases, RustDistError> {
+        let buffer = self.source.buffer();
+        let content = std::str::from_utf8(buffer).map_err(RustDistError::UnrecognizedText)?;
+
+        let mut releases = AlphaReleases::default();
+        for capture in MATCHER.captures_iter(content) {
+            if capture.name(LABEL).map(|label| label.as_str()) == Some("alpha") {
+                let version = parse_version(&capture)?;
+                // An unnumbered `alpha` label denotes the first alpha pre-release
+                let prerelease = capture
+                    .name(PRERELEASE)
+                    .map(|n| parse_number(&PRERELEASE, n.as_str()))
+                    .transpose()?
+                    .unwrap_or(1);
+
+                let alpha = Alpha::prerelease(version, prerelease);
+                releases.add(RustRelease::new(alpha, None, []));
+            }
+        }
+
+        Ok(releases)
+    }
+
+    /// Build an index of the beta releases which were published in the root of the Rust
+    /// distribution bucket, i.e. the `1.0.0-beta` pre-releases.
+    ///
+    /// Later beta releases are only published as channel manifests, see [`BetaManifests`].
+    pub fn build_beta_index(&self) -> Result<BetaReleases, RustDistError> {
+        let buffer = self.source.buffer();
+        let content = std::str::from_utf8(buffer).map_err(RustDistError::UnrecognizedText)?;
+
+        let mut releases = BetaReleases::default();
+        for capture in MATCHER.captures_iter(content) {
+            if capture.name(LABEL).map(|label| label.as_str()) == Some("beta") {
+                let version = parse_version(&capture)?;
+                let prerelease = capture
+                    .name(PRERELEASE)
+                    .map(|n| parse_number(&PRERELEASE, n.as_str()))
+                    .transpose()?;
+
+                let beta = Beta {
+                    version,
+                    prerelease,
+                };
+                releases.add(RustRelease::new(beta, None, []));
+            }
         }
 
         Ok(releases)
     }
 }
 
-fn parse_release(capture: Captures) -> RustDistResult<RustRelease<Stable>> {
-    const MAJOR: &str = "major";
-    const MINOR: &str = "minor";
-    const PATCH: &str = "patch";
+const MAJOR: &str = "major";
+const MINOR: &str = "minor";
+const PATCH: &str = "patch";
+const LABEL: &str = "label";
+const PRERELEASE: &str = "prerelease";
 
-    let major = capture[MAJOR].parse::<u64>().map_err(|_| {
-        RustDistError::UnableToParseVersionNumberComponent(&MAJOR, capture[MAJOR].to_string())
-    })?;
-    let minor = capture[MINOR].parse::<u64>().map_err(|_| {
-        RustDistError::UnableToParseVersionNumberComponent(&MINOR, capture[MINOR].to_string())
-    })?;
-    let patch = capture[PATCH].parse::<u64>().map_err(|_| {
-        RustDistError::UnableToParseVersionNumberComponent(&PATCH, capture[PATCH].to_string())
-    })?;
+fn parse_version(capture: &Captures) -> RustDistResult<RustVersion> {
+    let major = parse_number(&MAJOR, &capture[MAJOR])?;
+    let minor = parse_number(&MINOR, &capture[MINOR])?;
+    let patch = parse_number(&PATCH, &capture[PATCH])?;
 
-    let stable = Stable::new(major, minor, patch);
+    Ok(RustVersion::new(major, minor, patch))
+}
 
-    Ok(RustRelease::new(stable, None, []))
+fn parse_number<T: FromStr>(name: &'static &'static str, value: &str) -> RustDistResult<T> {
+    value
+        .parse::<T>()
+        .map_err(|_| RustDistError::UnableToParseVersionNumberComponent(name, value.to_string()))
 }
 
 impl RustDist {
     /// Fetch all known releases from the rust S3 distribution bucket
+    ///
+    /// Supports the stable, beta and the historic alpha channels.
     pub fn fetch_channel(channel: Channel) -> Result<Self, RustDistError> {
-        if let Channel::Stable = channel {
+        if let Channel::Stable | Channel::Beta | Channel::Alpha = channel {
             let source = fetch::fetch()?;
             Ok(Self { source })
         } else {
             Err(RustDistError::ChannelNotAvailable(channel))
         }
     }
+
+    /// Load the releases from the cached listing of the Rust distribution bucket, without
+    /// accessing the network, regardless of whether the cached listing is stale.
+    ///
+    /// Supports the stable, beta and the historic alpha channels. Returns
+    /// [`RustDistError::NotCached`] if no listing was cached before.
+    pub fn cached_channel(channel: Channel) -> Result<Self, RustDistError> {
+        if let Channel::Stable | Channel::Beta | Channel::Alpha = channel {
+            let source = fetch::cached()?;
+            Ok(Self { source })
+        } else {
+            Err(RustDistError::ChannelNotAvailable(channel))
+        }
+    }
 }
 
 #[cfg(test)]
@@ -113,4 +191,57 @@ mod tests {
             Stable::new(1, 0, 0)
         );
     }
+
+    #[test]
+    fn source_rust_dist_alpha() {
+        let path = [
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../resources/rust_dist/dist_static-rust-lang-org.txt",
+        ]
+        .join("");
+
+        let buffer = fs::read(path).unwrap();
+        let document = Document::new(buffer);
+
+        let source = RustDist::from_document(document);
+        let releases = source.build_alpha_index().unwrap();
+
+        let versions = releases
+            .iter()
+            .map(|release| release.version.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(versions, vec!["1.0.0-alpha", "1.0.0-alpha.2"]);
+    }
+
+    #[test]
+    fn source_rust_dist_beta() {
+        let path = [
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../resources/rust_dist/dist_static-rust-lang-org.txt",
+        ]
+        .join("");
+
+        let buffer = fs::read(path).unwrap();
+        let document = Document::new(buffer);
+
+        let source = RustDist::from_document(document);
+        let releases = source.build_beta_index().unwrap();
+
+        let versions = releases
+            .iter()
+            .map(|release| release.version.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            versions,
+            vec![
+                "1.0.0-beta",
+                "1.0.0-beta.2",
+                "1.0.0-beta.3",
+                "1.0.0-beta.4",
+                "1.0.0-beta.5"
+            ]
+        );
+    }
 }
@@ -0,0 +1,395 @@
+use crate::{parse_number, parse_version, RustDistError, RustDistResult, PRERELEASE};
+use regex::Regex;
+use rust_releases_core::releases::BetaReleases;
+use rust_releases_core::rust_release::date::Date;
+use rust_releases_core::{Beta, RustRelease};
+use rust_releases_io::{
+    base_cache_dir, Document, HttpCachedClient, ResourceFile, RustReleasesClient,
+};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use toml_edit::{DocumentMut, Item};
+
+// Lists the channel manifests of all releases ever published
+const LISTING_URL: &str = "https://static.rust-lang.org/manifests.txt";
+const LISTING_NAME: &str = "manifests.txt";
+
+// Directory where cached files reside for this source
+const SOURCE_CACHE_DIR: &str = "source_dist_manifests";
+
+// Use the cached listing for up to 1 day
+const LISTING_TIMEOUT: Duration = Duration::from_secs(86_400);
+
+// A published channel manifest never changes, so we can use a cached copy for a long time
+const MANIFEST_TIMEOUT: Duration = Duration::from_secs(365 * 86_400);
+
+lazy_static::lazy_static! {
+    static ref LISTING: Regex =
+        Regex::new(r"(?m)dist/(?P<date>\d{4}-\d{2}-\d{2})/channel-rust-beta\.toml$").unwrap();
+
+    static ref BETA_VERSION: Regex =
+        Regex::new(r"^(?P<major>\d+)\.(?P<minor>\d+)\.(?P<patch>\d+)-beta(?:\.(?P<prerelease>\d+))?(?: |$)").unwrap();
+}
+
+/// A source which obtains every beta release, i.e. each `1.x.0-beta.N` build, from the
+/// `dist/YYYY-MM-DD/channel-rust-beta.toml` channel manifests in the Rust distribution bucket.
+///
+/// The manifests are found using a listing of the bucket, like the
+/// [manifests index](https://static.rust-lang.org/manifests.txt).
+pub struct BetaManifests {
+    listing: Document,
+    cache_dir: PathBuf,
+}
+
+impl BetaManifests {
+    /// Create a source from a listing of the channel manifests. Each line of the listing which
+    /// ends with `dist/YYYY-MM-DD/channel-rust-beta.toml` refers to a beta channel manifest.
+    ///
+    /// Manifests fetched by [`BetaManifests::build_index`] are cached in the given directory,
+    /// or in the default cache directory if `None`.
+    pub fn from_listing(
+        listing: Document,
+        cache_dir: Option<impl AsRef<Path>>,
+    ) -> RustDistResult<Self> {
+        let cache_dir = if let Some(cache_dir) = cache_dir {
+            cache_dir.as_ref().join(SOURCE_CACHE_DIR)
+        } else {
+            base_cache_dir()?.join(SOURCE_CACHE_DIR)
+        };
+
+        Ok(Self { listing, cache_dir })
+    }
+
+    /// Fetch the listing of the channel manifests from the Rust distribution server.
+    ///
+    /// See [`BetaManifests::from_listing`] for the use of `cache_dir`.
+    pub fn fetch(cache_dir: Option<impl AsRef<Path>>) -> RustDistResult<Self> {
+        let mut source = Self::from_listing(Document::new(Vec::new()), cache_dir)?;
+
+        let client = HttpCachedClient::new(source.cache_dir.clone(), LISTING_TIMEOUT);
+        source.listing = client
+            .fetch(ResourceFile::new(LISTING_URL, LISTING_NAME))?
+            .into_document();
+
+        Ok(source)
+    }
+
+    /// The dates of the beta channel manifests in the listing, in ascending order.
+    pub fn dates(&self) -> RustDistResult<Vec<Date>> {
+        let content =
+            std::str::from_utf8(self.listing.buffer()).map_err(RustDistError::UnrecognizedText)?;
+
+        let mut dates = LISTING
+            .captures_iter(content)
+            .map(|capture| parse_date(&capture["date"]))
+            .collect::<RustDistResult<Vec<_>>>()?;
+
+        dates.sort();
+        dates.dedup();
+
+        Ok(dates)
+    }
+
+    /// Read the beta release of each date in the listing, by fetching its beta channel
+    /// manifest.
+    ///
+    /// See [`BetaManifests::releases_with`] for how failures are reported.
+    pub fn releases(&self) -> RustDistResult<Vec<RustDistResult<RustRelease<Beta>>>> {
+        let client = HttpCachedClient::new(self.cache_dir.clone(), MANIFEST_TIMEOUT);
+
+        self.releases_with(|date| {
+            let url = format!(
+                "https://static.rust-lang.org/dist/{}/channel-rust-beta.toml",
+                date
+            );
+            let name = format!("channel-rust-beta-{}.toml", date);
+
+            let retrieved = client.fetch(ResourceFile::new(&url, &name))?;
+            Ok(retrieved.into_document())
+        })
+    }
+
+    /// Read the beta release of each date in the listing, in ascending order of date, using
+    /// `fetch_manifest` to obtain the beta channel manifest of a date.
+    ///
+    /// A manifest which can not be fetched or read is reported as a
+    /// [`RustDistError::InvalidManifest`], in place of its release, so it does not prevent the
+    /// releases of the other dates from being read. The outer error is only returned when the
+    /// listing itself can not be read.
+    pub fn releases_with<F>(
+        &self,
+        mut fetch_manifest: F,
+    ) -> RustDistResult<Vec<RustDistResult<RustRelease<Beta>>>>
+    where
+        F: FnMut(&Date) -> RustDistResult<Document>,
+    {
+        Ok(self
+            .dates()?
+            .into_iter()
+            .map(|date| {
+                fetch_manifest(&date)
+                    .and_then(|manifest| parse_beta_manifest(&manifest))
+                    .map_err(|err| RustDistError::InvalidManifest(date, Box::new(err)))
+            })
+            .collect())
+    }
+
+    /// Build an index of beta releases, like those read by [`BetaManifests::releases`].
+    ///
+    /// A beta build may be published on more than one date. The release date of a build is the
+    /// date on which it was published first.
+    pub fn build_index(releases: impl IntoIterator<Item = RustRelease<Beta>>) -> BetaReleases {
+        let mut builds = BTreeMap::new();
+
+        for release in releases {
+            if let Some(date) = release.release_date() {
+                let first = builds
+                    .entry(release.version().clone())
+                    .or_insert_with(|| date.clone());
+
+                if date < first {
+                    *first = date.clone();
+                }
+            }
+        }
+
+        builds
+            .into_iter()
+            .map(|(beta, date)| RustRelease::new(beta, Some(date), []))
+            .collect()
+    }
+}
+
+/// Parse the beta release, and its release date, from a `channel-rust-beta.toml` channel
+/// manifest.
+///
+/// The version is read from the `version` key of the `pkg.rust` table, wherever it appears in
+/// the manifest. The pre-release number is taken from this version, e.g. `2` for
+/// `1.8.0-beta.2 (2879d940a 2016-03-22)`. An unnumbered beta, like `1.0.0-beta`, does not
+/// have a pre-release number.
+pub fn parse_beta_manifest(manifest: &Document) -> RustDistResult<RustRelease<Beta>> {
+    let content =
+        std::str::from_utf8(manifest.buffer()).map_err(RustDistError::UnrecognizedText)?;
+
+    let document = content
+        .parse::<DocumentMut>()
+        .map_err(|err| RustDistError::MalformedManifest(err.to_string()))?;
+
+    let date = document
+        .get("date")
+        .and_then(Item::as_str)
+        .ok_or_else(|| RustDistError::MalformedManifest("missing `date`".to_string()))?;
+    let date = parse_date(date)?;
+
+    let capture = document
+        .get("pkg")
+        .and_then(|pkg| pkg.get("rust"))
+        .and_then(|rust| rust.get("version"))
+        .and_then(Item::as_str)
+        .and_then(|version| BETA_VERSION.captures(version))
+        .ok_or_else(|| {
+            RustDistError::MalformedManifest("missing beta version of `pkg.rust`".to_string())
+        })?;
+
+    let version = parse_version(&capture)?;
+    let prerelease = capture
+        .name(PRERELEASE)
+        .map(|n| parse_number(&PRERELEASE, n.as_str()))
+        .transpose()?;
+
+    let beta = Beta {
+        version,
+        prerelease,
+    };
+
+    Ok(RustRelease::new(beta, Some(date), []))
+}
+
+fn parse_date(date: &str) -> RustDistResult<Date> {
+    date.parse::<Date>()
+        .map_err(|_| RustDistError::MalformedManifest(format!("invalid date `{}`", date)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_releases_core::rust_release::toolchain::RustVersion;
+    use std::fs;
+
+    fn resource(name: &str) -> Document {
+        let path = [
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../resources/channel_manifests/",
+            name,
+        ]
+        .join("");
+
+        Document::new(fs::read(path).unwrap())
+    }
+
+    fn manifest(date: &str, version: &str) -> Document {
+        let content = format!(
+            "manifest-version = \"2\"\ndate = \"{}\"\n\n[pkg.rust]\nversion = \"{} (0123456789 {})\"\n",
+            date, version, date
+        );
+
+        Document::new(content.into_bytes())
+    }
+
+    fn source(listing: &str) -> BetaManifests {
+        let listing = Document::new(listing.as_bytes().to_vec());
+        BetaManifests::from_listing(listing, Some(std::env::temp_dir())).unwrap()
+    }
+
+    #[test]
+    fn parse_manifest() {
+        let release = parse_beta_manifest(&resource("beta_2016-03-23.toml")).unwrap();
+
+        assert_eq!(
+            release.version(),
+            &Beta::prerelease(RustVersion::new(1, 8, 0), 2)
+        );
+        assert_eq!(release.release_date(), Some(&Date::new(2016, 3, 23)));
+    }
+
+    #[yare::parameterized(
+        unnumbered = { "1.0.0-beta", None },
+        numbered = { "1.80.0-beta.6", Some(6) },
+    )]
+    fn parse_manifest_prerelease(version: &str, expected: Option<u32>) {
+        let release = parse_beta_manifest(&manifest("2024-06-01", version)).unwrap();
+
+        assert_eq!(release.version().prerelease, expected);
+    }
+
+    #[test]
+    fn parse_manifest_reads_version_by_key() {
+        let manifest = Document::new(
+            b"date = \"2024-06-01\"\n\
+              manifest-version = \"2\"\n\
+              \n\
+              [pkg.rustc]\n\
+              version = \"1.79.0 (0123456789 2024-06-01)\"\n\
+              \n\
+              [pkg.rust]\n\
+              git_commit_hash = \"0123456789\"\n\
+              version = \"1.80.0-beta.6 (0123456789 2024-06-01)\"\n\
+              \n\
+              [pkg.rust.target.x86_64-unknown-linux-gnu]\n\
+              available = true\n"
+                .to_vec(),
+        );
+
+        let release = parse_beta_manifest(&manifest).unwrap();
+
+        assert_eq!(
+            release.version(),
+            &Beta::prerelease(RustVersion::new(1, 80, 0), 6)
+        );
+        assert_eq!(release.release_date(), Some(&Date::new(2024, 6, 1)));
+    }
+
+    #[yare::parameterized(
+        stable = { manifest("2024-06-01", "1.79.0") },
+        invalid_date = { manifest("2024-02-30", "1.80.0-beta.1") },
+        empty = { Document::new(Vec::new()) },
+    )]
+    fn parse_manifest_rejects(manifest: Document) {
+        assert!(parse_beta_manifest(&manifest).is_err());
+    }
+
+    #[test]
+    fn listing_dates() {
+        let source = BetaManifests::from_listing(resource("manifests.txt"), None::<&str>).unwrap();
+
+        let dates = source.dates().unwrap();
+
+        assert_eq!(dates.first(), Some(&Date::new(2016, 3, 23)));
+        assert!(dates.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn build_index() {
+        let source = source(
+            "dist/2024-06-14/channel-rust-beta.toml\n\
+             dist/2024-06-01/channel-rust-nightly.toml\n\
+             dist/2024-06-01/channel-rust-beta.toml\n\
+             dist/2024-06-08/channel-rust-beta.toml\n\
+             dist/2024-06-15/channel-rust-beta.toml\n",
+        );
+
+        let releases = source
+            .releases_with(|date| {
+                let version = match date.to_string().as_str() {
+                    "2024-06-01" => "1.80.0-beta.1",
+                    "2024-06-08" => "1.80.0-beta.2",
+                    // republished build
+                    "2024-06-14" => "1.80.0-beta.2",
+                    _ => "1.80.0-beta.3",
+                };
+
+                Ok(manifest(&date.to_string(), version))
+            })
+            .unwrap();
+        let releases =
+            BetaManifests::build_index(releases.into_iter().map(|release| release.unwrap()));
+
+        let builds = releases
+            .iter()
+            .map(|release| {
+                (
+                    release.version().to_owned(),
+                    release.release_date().unwrap().to_string(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let beta = |n| Beta::prerelease(RustVersion::new(1, 80, 0), n);
+        assert_eq!(
+            builds,
+            vec![
+                (beta(1), "2024-06-01".to_string()),
+                (beta(2), "2024-06-08".to_string()),
+                (beta(3), "2024-06-15".to_string()),
+            ]
+        );
+        assert_eq!(
+            releases
+                .latest_prerelease(RustVersion::new(1, 80, 0))
+                .map(|release| release.version()),
+            Some(&beta(3))
+        );
+    }
+
+    #[test]
+    fn releases_report_failures_per_date() {
+        let source = source(
+            "dist/2024-06-01/channel-rust-beta.toml\n\
+             dist/2024-06-08/channel-rust-beta.toml\n\
+             dist/2024-06-15/channel-rust-beta.toml\n",
+        );
+
+        let releases = source
+            .releases_with(|date| match date.to_string().as_str() {
+                "2024-06-01" => Err(RustDistError::ChunkMetadataMissing),
+                "2024-06-08" => Ok(Document::new(b"date = ".to_vec())),
+                _ => Ok(manifest("2024-06-15", "1.80.0-beta.3")),
+            })
+            .unwrap();
+
+        assert_eq!(releases.len(), 3);
+        assert!(matches!(
+            &releases[0],
+            Err(RustDistError::InvalidManifest(date, _)) if date == &Date::new(2024, 6, 1)
+        ));
+        assert!(matches!(
+            &releases[1],
+            Err(RustDistError::InvalidManifest(date, _)) if date == &Date::new(2024, 6, 8)
+        ));
+        assert_eq!(
+            releases[2].as_ref().unwrap().version(),
+            &Beta::prerelease(RustVersion::new(1, 80, 0), 3)
+        );
+    }
+}
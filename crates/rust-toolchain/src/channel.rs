@@ -1,20 +1,28 @@
+mod alpha;
 mod beta;
+mod custom;
 mod nightly;
 mod stable;
 
+use crate::spec::{parse_channel_spec, ChannelSpec, ParseToolchainError};
 use crate::{Date, RustVersion};
+use std::fmt;
+use std::str::FromStr;
 
+pub use alpha::Alpha;
 pub use beta::Beta;
+pub use custom::Custom;
 pub use nightly::Nightly;
 pub use stable::Stable;
 
 /// A Rust release [`channel`].
 ///
-/// Does not include the once used `Alpha` release channel, which has not been used post `1.0.0`.
+/// Includes the historic [`Alpha`] release channel, which has not been used post `1.0.0`,
+/// and [`Custom`] toolchains, which are not released by the Rust project.
 ///
 /// # Variants
 ///
-/// See also: [`Stable`], [`Beta`] and [`Nightly`].
+/// See also: [`Stable`], [`Beta`], [`Nightly`], [`Alpha`] and [`Custom`].
 ///
 /// # Reading materials
 ///
@@ -37,6 +45,10 @@ pub enum Channel {
     Beta(Beta),
     /// The nightly release channel
     Nightly(Nightly),
+    /// The historic alpha release channel
+    Alpha(Alpha),
+    /// A custom toolchain, like a locally built compiler
+    Custom(Custom),
 }
 
 impl Channel {
@@ -58,6 +70,14 @@ impl Channel {
         Channel::Nightly(Nightly { date })
     }
 
+    /// Create a new [`Alpha`] channel instance.
+    pub fn alpha(version: RustVersion) -> Self {
+        Channel::Alpha(Alpha {
+            version,
+            prerelease: None,
+        })
+    }
+
     /// Whether the given [`Channel`] is of the [`Stable`] variant.
     pub fn is_stable(&self) -> bool {
         matches!(self, Self::Stable(_))
@@ -73,12 +93,29 @@ impl Channel {
         matches!(self, Self::Nightly(_))
     }
 
-    /// Returns the release version, or None, if it's a nightly release.
+    /// Create a new [`Custom`] channel instance.
+    pub fn custom(custom: Custom) -> Self {
+        Channel::Custom(custom)
+    }
+
+    /// Whether the given [`Channel`] is of the [`Custom`] variant.
+    pub fn is_custom(&self) -> bool {
+        matches!(self, Self::Custom(_))
+    }
+
+    /// Whether the given [`Channel`] is of the [`Alpha`] variant.
+    pub fn is_alpha(&self) -> bool {
+        matches!(self, Self::Alpha(_))
+    }
+
+    /// Returns the release version, or None, if it's a nightly release or a custom toolchain.
     pub fn version(&self) -> Option<RustVersion> {
         match self {
             Channel::Stable(v) => Some(v.version),
             Channel::Beta(v) => Some(v.version),
             Channel::Nightly(_) => None,
+            Channel::Alpha(v) => Some(v.version),
+            Channel::Custom(_) => None,
         }
     }
 
@@ -88,6 +125,46 @@ impl Channel {
             Channel::Stable(_) => None,
             Channel::Beta(_) => None,
             Channel::Nightly(v) => Some(v.date.clone()),
+            Channel::Alpha(_) => None,
+            Channel::Custom(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for Channel {
+    /// Formats the channel as it is written in a toolchain name, e.g. `1.70.0`, `1.71.0-beta.2`
+    /// or `nightly-2024-02-01`. A custom toolchain is formatted as its name, or path.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Channel::Stable(v) => write!(f, "{}", v.version),
+            Channel::Beta(v) => write!(f, "{}", v),
+            Channel::Nightly(v) => write!(f, "nightly-{}", v.date.ymd()),
+            Channel::Alpha(v) => write!(f, "{}", v),
+            Channel::Custom(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+impl FromStr for Channel {
+    type Err = ParseToolchainError;
+
+    /// Parses a channel which denotes a single release, e.g. `1.70.0`, `1.71.0-beta.2`
+    /// or `nightly-2024-02-01`.
+    ///
+    /// Symbolic channels, like `stable`, and partial versions, like `1.70`, are rejected
+    /// with [`ParseToolchainError::Unresolved`]. Custom toolchain names are not accepted, since
+    /// any unrecognized name could be one; use [`Channel::custom`] instead.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (spec, date, rest) = parse_channel_spec(s)?;
+
+        if !rest.is_empty() {
+            return Err(ParseToolchainError::Unexpected(rest.to_string()));
+        }
+
+        match (spec, date) {
+            (ChannelSpec::Exact(channel), None) => Ok(channel),
+            (ChannelSpec::Nightly, Some(date)) => Ok(Channel::nightly(date)),
+            _ => Err(ParseToolchainError::Unresolved(s.to_string())),
         }
     }
 }
@@ -123,14 +200,61 @@ mod tests {
         assert!(!stable.is_stable());
         assert!(!stable.is_beta());
         assert!(stable.is_nightly());
+        assert!(!stable.is_alpha());
+    }
+
+    #[test]
+    fn create_channel_alpha() {
+        let alpha = Channel::alpha(RustVersion::new(0, 12, 0));
+
+        assert!(!alpha.is_stable());
+        assert!(!alpha.is_beta());
+        assert!(!alpha.is_nightly());
+        assert!(alpha.is_alpha());
+        assert!(!alpha.is_custom());
+    }
+
+    #[test]
+    fn create_channel_custom() {
+        let custom = Channel::custom(Custom::Named("stage2".to_string()));
+
+        assert!(custom.is_custom());
+        assert!(!custom.is_stable());
+        assert_eq!(custom.version(), None);
+        assert_eq!(custom.date(), None);
+        assert_eq!(custom.to_string(), "stage2");
     }
 
     #[parameterized(
         stable = { Channel::stable("1.2.3".parse().unwrap()), Some(RustVersion::new(1,2,3)) },
         beta = { Channel::beta("1.2.3".parse().unwrap()), Some(RustVersion::new(1,2,3)) },
-        nightly = { Channel::nightly(Date::new(2024, 1, 1)), None }
+        nightly = { Channel::nightly(Date::new(2024, 1, 1)), None },
+        alpha = { Channel::alpha("0.12.0".parse().unwrap()), Some(RustVersion::new(0,12,0)) },
     )]
     fn version(c: Channel, expected: Option<RustVersion>) {
         assert_eq!(c.version(), expected)
     }
+
+    #[parameterized(
+        stable = { Channel::stable(RustVersion::new(1, 70, 0)), "1.70.0" },
+        beta = { Channel::beta(RustVersion::new(1, 71, 0)), "1.71.0-beta" },
+        beta_prerelease = { Channel::Beta(Beta { version: RustVersion::new(1, 71, 0), prerelease: Some(2) }), "1.71.0-beta.2" },
+        nightly = { Channel::nightly(Date::new(2024, 2, 1)), "nightly-2024-02-01" },
+        alpha = { Channel::alpha(RustVersion::new(0, 12, 0)), "0.12.0" },
+        alpha_prerelease = { Channel::Alpha(Alpha::prerelease(RustVersion::new(1, 0, 0), 2)), "1.0.0-alpha.2" },
+    )]
+    fn display_and_parse(channel: Channel, expected: &str) {
+        assert_eq!(channel.to_string(), expected);
+        assert_eq!(expected.parse::<Channel>().unwrap(), channel);
+    }
+
+    #[parameterized(
+        stable = { "stable" },
+        partial = { "1.70" },
+        undated_nightly = { "nightly" },
+        with_host = { "1.70.0-x86_64-unknown-linux-gnu" },
+    )]
+    fn parse_rejects(input: &str) {
+        assert!(input.parse::<Channel>().is_err());
+    }
 }
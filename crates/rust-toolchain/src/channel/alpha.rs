@@ -0,0 +1,144 @@
+use crate::channel::{Beta, Stable};
+use crate::RustVersion;
+use std::cmp::Ordering;
+use std::fmt;
+
+/// The historic `Alpha` release [`channel`]
+///
+/// The alpha channel preceded the `1.0.0` stable release. It covers both the pre-1.0 `0.x`
+/// releases, and the `1.0.0-alpha` pre-releases.
+///
+/// An alpha release always precedes a [`Beta`] or [`Stable`] release of the same version.
+///
+/// [`channel`]: https://rust-lang.github.io/rustup/concepts/channels.html
+#[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct Alpha {
+    /// The three component Rust version
+    ///
+    /// Two component versions, like `0.10`, are completed with a `0` patch component.
+    pub version: RustVersion,
+    /// The nth alpha pre-release, if the version was labelled as such
+    ///
+    /// The `1.0.0-alpha` pre-release is the first, and `1.0.0-alpha.2` the second. The
+    /// pre-1.0 `0.x` releases are not labelled.
+    pub prerelease: Option<u32>,
+}
+
+impl Alpha {
+    /// Instantiate a new, unlabelled, `Alpha` struct, representing a pre-1.0 `0.x` release.
+    pub fn new(major: u64, minor: u64, patch: u64) -> Self {
+        Self {
+            version: RustVersion::new(major, minor, patch),
+            prerelease: None,
+        }
+    }
+
+    /// Instantiate a new `Alpha` struct, representing the nth `alpha` labelled pre-release
+    /// of a version, e.g. `1.0.0-alpha.2`.
+    pub fn prerelease(version: RustVersion, n: u32) -> Self {
+        Self {
+            version,
+            prerelease: Some(n),
+        }
+    }
+}
+
+impl fmt::Display for Alpha {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.prerelease {
+            None => write!(f, "{}", self.version),
+            Some(1) => write!(f, "{}-alpha", self.version),
+            Some(n) => write!(f, "{}-alpha.{}", self.version, n),
+        }
+    }
+}
+
+// An alpha release is never equal to a release of another channel, and precedes a beta or stable
+// release of the same version.
+macro_rules! impl_cmp_channel {
+    ($other:ty) => {
+        impl PartialEq<$other> for Alpha {
+            fn eq(&self, _other: &$other) -> bool {
+                false
+            }
+        }
+
+        impl PartialEq<Alpha> for $other {
+            fn eq(&self, _other: &Alpha) -> bool {
+                false
+            }
+        }
+
+        impl PartialOrd<$other> for Alpha {
+            fn partial_cmp(&self, other: &$other) -> Option<Ordering> {
+                Some(self.version.cmp(&other.version).then(Ordering::Less))
+            }
+        }
+
+        impl PartialOrd<Alpha> for $other {
+            fn partial_cmp(&self, other: &Alpha) -> Option<Ordering> {
+                other.partial_cmp(self).map(Ordering::reverse)
+            }
+        }
+    };
+}
+
+impl_cmp_channel!(Beta);
+impl_cmp_channel!(Stable);
+
+#[cfg(test)]
+mod tests {
+    use crate::channel::{Alpha, Beta, Stable};
+    use crate::RustVersion;
+
+    #[yare::parameterized(
+        patch1 = { Alpha::new(0, 0, 0), Alpha::new(0, 0, 1) },
+        minor1 = { Alpha::new(0, 0, 0), Alpha::new(0, 1, 0) },
+        major1 = { Alpha::new(0, 0, 0), Alpha::new(1, 0, 0) },
+        unlabelled_over_labelled = { Alpha::new(0, 12, 0), Alpha::prerelease(RustVersion::new(1, 0, 0), 1) },
+        prerelease1 = { Alpha::prerelease(RustVersion::new(1, 0, 0), 1), Alpha::prerelease(RustVersion::new(1, 0, 0), 2) },
+    )]
+    fn ord(left: Alpha, right: Alpha) {
+        assert!(left < right);
+    }
+
+    #[yare::parameterized(
+        unlabelled = { Alpha::new(0, 12, 0), "0.12.0" },
+        first = { Alpha::prerelease(RustVersion::new(1, 0, 0), 1), "1.0.0-alpha" },
+        second = { Alpha::prerelease(RustVersion::new(1, 0, 0), 2), "1.0.0-alpha.2" },
+    )]
+    fn display(alpha: Alpha, expected: &str) {
+        assert_eq!(alpha.to_string(), expected);
+    }
+
+    #[test]
+    fn precedes_beta_of_same_version() {
+        let alpha = Alpha::prerelease(RustVersion::new(1, 0, 0), 2);
+        let beta = Beta {
+            version: RustVersion::new(1, 0, 0),
+            prerelease: None,
+        };
+
+        assert!(alpha < beta);
+        assert!(beta > alpha);
+        assert!(alpha != beta);
+    }
+
+    #[test]
+    fn precedes_stable_of_same_version() {
+        let alpha = Alpha::prerelease(RustVersion::new(1, 0, 0), 2);
+        let stable = Stable::new(1, 0, 0);
+
+        assert!(alpha < stable);
+        assert!(stable > alpha);
+    }
+
+    #[test]
+    fn follows_stable_of_older_version() {
+        let alpha = Alpha::new(0, 12, 0);
+        let stable = Stable::new(0, 11, 0);
+
+        assert!(alpha > stable);
+        assert!(stable < alpha);
+    }
+}
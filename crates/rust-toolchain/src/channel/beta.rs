@@ -1,4 +1,5 @@
 use crate::RustVersion;
+use std::fmt;
 
 /// The `Beta` release [`channel`]
 ///
@@ -11,6 +12,34 @@ pub struct Beta {
     pub prerelease: Option<u32>,
 }
 
+impl Beta {
+    /// Instantiate a new, unnumbered, `Beta` struct, e.g. `1.71.0-beta`.
+    pub fn new(major: u64, minor: u64, patch: u64) -> Self {
+        Self {
+            version: RustVersion::new(major, minor, patch),
+            prerelease: None,
+        }
+    }
+
+    /// Instantiate a new `Beta` struct, representing the nth beta pre-release of a version,
+    /// e.g. `1.71.0-beta.2`.
+    pub fn prerelease(version: RustVersion, n: u32) -> Self {
+        Self {
+            version,
+            prerelease: Some(n),
+        }
+    }
+}
+
+impl fmt::Display for Beta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.prerelease {
+            None => write!(f, "{}-beta", self.version),
+            Some(n) => write!(f, "{}-beta.{}", self.version, n),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{channel::Beta, RustVersion};
@@ -35,4 +64,14 @@ mod tests {
 
         assert!(left < right);
     }
+
+    #[test]
+    fn prerelease_follows_unnumbered() {
+        let unnumbered = Beta::new(1, 71, 0);
+        let first = Beta::prerelease(RustVersion::new(1, 71, 0), 1);
+        let second = Beta::prerelease(RustVersion::new(1, 71, 0), 2);
+
+        assert!(unnumbered < first);
+        assert!(first < second);
+    }
 }
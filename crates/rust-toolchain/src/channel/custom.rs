@@ -0,0 +1,90 @@
+use std::fmt;
+use std::path::PathBuf;
+
+/// A [`custom`] toolchain, which is not distributed by the Rust project
+///
+/// Custom toolchains are, for example, locally built compilers linked with
+/// `rustup toolchain link <name> <path>`, or toolchains referred to by a `path` in a
+/// `rust-toolchain.toml` file.
+///
+/// A custom toolchain is never equal to a toolchain of another channel. Two custom toolchains
+/// are equal if they have the same name, or the same path. Since custom toolchains are not
+/// released by the Rust project, they can not be resolved against an index of releases.
+///
+/// [`custom`]: https://rust-lang.github.io/rustup/concepts/toolchains.html#custom-toolchains
+#[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum Custom {
+    /// A custom toolchain known by its name, e.g. `stage2`, as linked with
+    /// `rustup toolchain link`
+    Named(String),
+    /// A custom toolchain located at a path, e.g. as given by the `path` key of a
+    /// `rust-toolchain.toml` file
+    Path(PathBuf),
+}
+
+impl Custom {
+    /// The name of the custom toolchain, if it is known by its name
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            Custom::Named(name) => Some(name),
+            Custom::Path(_) => None,
+        }
+    }
+
+    /// The path of the custom toolchain, if it is located at a path
+    pub fn path(&self) -> Option<&PathBuf> {
+        match self {
+            Custom::Named(_) => None,
+            Custom::Path(path) => Some(path),
+        }
+    }
+}
+
+impl fmt::Display for Custom {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Custom::Named(name) => f.write_str(name),
+            Custom::Path(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::channel::Custom;
+    use std::path::PathBuf;
+
+    #[test]
+    fn named() {
+        let custom = Custom::Named("stage2".to_string());
+
+        assert_eq!(custom.name(), Some("stage2"));
+        assert!(custom.path().is_none());
+        assert_eq!(custom.to_string(), "stage2");
+    }
+
+    #[test]
+    fn path() {
+        let custom = Custom::Path(PathBuf::from("/opt/rust"));
+
+        assert!(custom.name().is_none());
+        assert_eq!(custom.path(), Some(&PathBuf::from("/opt/rust")));
+        assert_eq!(custom.to_string(), "/opt/rust");
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            Custom::Named("stage2".to_string()),
+            Custom::Named("stage2".to_string())
+        );
+        assert_ne!(
+            Custom::Named("stage2".to_string()),
+            Custom::Named("stage1".to_string())
+        );
+        assert_ne!(
+            Custom::Named("/opt/rust".to_string()),
+            Custom::Path(PathBuf::from("/opt/rust"))
+        );
+    }
+}
@@ -1,9 +1,16 @@
+use crate::Target;
 use std::borrow::Cow;
+use std::fmt;
+use std::str::FromStr;
 
 /// A toolchain component
 ///
+/// A component may be specific to a target, like `rust-std`, which is distributed
+/// separately for each target. Such a component carries the target it was built for.
+///
 /// # Reading materials
 ///
+/// - [`rustup concepts: components`]
 /// - [`rustup component history`]
 ///
 /// [`rustup concepts: components`]: https://rust-lang.github.io/rustup/concepts/components.html
@@ -11,27 +18,199 @@ use std::borrow::Cow;
 #[derive(Clone, Debug, Hash, Eq, PartialEq)]
 pub struct Component {
     name: Cow<'static, str>,
+    target: Option<Target>,
 }
 
 impl Component {
     /// Create a new Component instance
     pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
-        Self { name: name.into() }
+        Self {
+            name: name.into(),
+            target: None,
+        }
+    }
+
+    /// Create a new Component instance, which is specific to the given target, like
+    /// `rust-std` for `wasm32-unknown-unknown`.
+    pub fn for_target(name: impl Into<Cow<'static, str>>, target: Target) -> Self {
+        Self {
+            name: name.into(),
+            target: Some(target),
+        }
     }
 
     /// The name of the component
     pub fn name(&self) -> &str {
         self.name.as_ref()
     }
+
+    /// The target of the component, if it is specific to a target
+    pub fn target(&self) -> Option<&Target> {
+        self.target.as_ref()
+    }
+
+    /// The well-known component this component refers to, if any.
+    ///
+    /// Historic names, like `rustfmt-preview`, resolve to their current counterpart.
+    pub fn known(&self) -> Option<KnownComponent> {
+        self.name().parse().ok()
+    }
+
+    /// Whether this component is available on the given target.
+    ///
+    /// Components which are not specific to a target are available on every target.
+    pub fn is_available_on(&self, target: &Target) -> bool {
+        self.target.as_ref().is_none_or(|t| t == target)
+    }
+}
+
+impl From<KnownComponent> for Component {
+    fn from(component: KnownComponent) -> Self {
+        Self::new(component.name())
+    }
 }
 
+/// A well-known toolchain component, distributed by the Rust project.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum KnownComponent {
+    /// The Rust compiler, `rustc`
+    Rustc,
+    /// The Rust package manager, `cargo`
+    Cargo,
+    /// The Rust standard library, `rust-std`, which is distributed for each target separately
+    RustStd,
+    /// The local Rust documentation, `rust-docs`
+    RustDocs,
+    /// The Rust code formatter, `rustfmt`
+    Rustfmt,
+    /// The Rust linter, `clippy`
+    Clippy,
+    /// The source code of the standard library, `rust-src`
+    RustSrc,
+    /// The LLVM tools, `llvm-tools`
+    LlvmTools,
+    /// The Rust interpreter for detecting undefined behaviour, `miri`
+    Miri,
+    /// The Rust language server, `rust-analyzer`
+    RustAnalyzer,
+}
+
+impl KnownComponent {
+    /// All well-known components
+    pub const ALL: &'static [KnownComponent] = &[
+        KnownComponent::Rustc,
+        KnownComponent::Cargo,
+        KnownComponent::RustStd,
+        KnownComponent::RustDocs,
+        KnownComponent::Rustfmt,
+        KnownComponent::Clippy,
+        KnownComponent::RustSrc,
+        KnownComponent::LlvmTools,
+        KnownComponent::Miri,
+        KnownComponent::RustAnalyzer,
+    ];
+
+    /// The current name of the component
+    pub fn name(&self) -> &'static str {
+        match self {
+            KnownComponent::Rustc => "rustc",
+            KnownComponent::Cargo => "cargo",
+            KnownComponent::RustStd => "rust-std",
+            KnownComponent::RustDocs => "rust-docs",
+            KnownComponent::Rustfmt => "rustfmt",
+            KnownComponent::Clippy => "clippy",
+            KnownComponent::RustSrc => "rust-src",
+            KnownComponent::LlvmTools => "llvm-tools",
+            KnownComponent::Miri => "miri",
+            KnownComponent::RustAnalyzer => "rust-analyzer",
+        }
+    }
+
+    /// The names under which the component was previously distributed, e.g. `rustfmt-preview`
+    pub fn previous_names(&self) -> &'static [&'static str] {
+        match self {
+            KnownComponent::Rustfmt => &["rustfmt-preview"],
+            KnownComponent::Clippy => &["clippy-preview"],
+            KnownComponent::LlvmTools => &["llvm-tools-preview"],
+            KnownComponent::Miri => &["miri-preview"],
+            KnownComponent::RustAnalyzer => &["rust-analyzer-preview"],
+            _ => &[],
+        }
+    }
+
+    /// Whether the component is distributed for each target separately
+    pub fn is_target_specific(&self) -> bool {
+        matches!(self, KnownComponent::RustStd)
+    }
+}
+
+impl FromStr for KnownComponent {
+    type Err = UnknownComponent;
+
+    /// Parses the current, or a previous, name of a well-known component.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        KnownComponent::ALL
+            .iter()
+            .copied()
+            .find(|c| c.name() == s || c.previous_names().contains(&s))
+            .ok_or_else(|| UnknownComponent(s.to_string()))
+    }
+}
+
+impl fmt::Display for KnownComponent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Returned when a name does not refer to a [`KnownComponent`]
+#[derive(Debug, thiserror::Error)]
+#[error("Unknown component `{0}`")]
+pub struct UnknownComponent(String);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use yare::parameterized;
 
     #[test]
     fn new_instance() {
         let c = Component::new("sample");
         assert_eq!(c.name(), "sample");
+        assert!(c.target().is_none());
+    }
+
+    #[test]
+    fn for_target() {
+        let wasm = Target::try_from_target_triple("wasm32-unknown-unknown").unwrap();
+        let c = Component::for_target("rust-std", wasm.clone());
+
+        assert_eq!(c.target(), Some(&wasm));
+        assert!(c.is_available_on(&wasm));
+        assert!(!c.is_available_on(&Target::host()));
+        assert!(Component::new("rustc").is_available_on(&wasm));
+    }
+
+    #[parameterized(
+        current = { "rustfmt", Some(KnownComponent::Rustfmt) },
+        renamed_rustfmt = { "rustfmt-preview", Some(KnownComponent::Rustfmt) },
+        renamed_clippy = { "clippy-preview", Some(KnownComponent::Clippy) },
+        renamed_llvm_tools = { "llvm-tools-preview", Some(KnownComponent::LlvmTools) },
+        rust_std = { "rust-std", Some(KnownComponent::RustStd) },
+        unknown = { "rls", None },
+    )]
+    fn known(name: &'static str, expected: Option<KnownComponent>) {
+        assert_eq!(Component::new(name).known(), expected);
+    }
+
+    #[test]
+    fn names_round_trip() {
+        for component in KnownComponent::ALL {
+            assert_eq!(
+                component.name().parse::<KnownComponent>().unwrap(),
+                *component
+            );
+        }
     }
 }
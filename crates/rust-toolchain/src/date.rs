@@ -1,12 +1,19 @@
 use std::fmt;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 /// A release date for a Rust toolchain.
 ///
 /// Nightly toolchains use a date as version instead of a semver number.
 /// This Date should be regarded as a form of a version number, just like semver.
 ///
+/// The date is not validated by [`Date::new`], but [`Date::try_new`] and the `FromStr`
+/// implementation only accept dates which are valid in the proleptic Gregorian calendar.
+/// Day arithmetic, and the day of the week, are only available for valid dates.
+///
 /// For full-featured dates, it is recommended to use a dedicated library
-/// like [`time`], [`chrono`] or [`jiff`].
+/// like [`time`], [`chrono`] or [`jiff`]. Conversions to and from their date types are
+/// available behind the `time`, `chrono` and `jiff` features.
 ///
 /// [`time`]: https://docs.rs/time/latest/time/
 /// [`chrono`]: https://docs.rs/chrono/latest/chrono/
@@ -27,13 +34,116 @@ impl Date {
     /// which parse a Gregorian date.
     ///
     /// It is up to the caller to make sure that the given date is valid.
-    /// This library just takes a date representation "as-is".
+    /// This library just takes a date representation "as-is". Use [`Date::try_new`]
+    /// to create a date which is validated instead.
     pub fn new(year: u16, month: u8, day: u8) -> Self {
         Self {
             date: DateImpl { year, month, day },
         }
     }
 
+    /// Create a new `Date` instance, if the date is valid in the proleptic Gregorian calendar.
+    pub fn try_new(year: u16, month: u8, day: u8) -> Result<Self, DateError> {
+        let date = Self::new(year, month, day);
+
+        if date.is_valid() {
+            Ok(date)
+        } else {
+            Err(DateError::Invalid(date))
+        }
+    }
+
+    /// The current date, in UTC, according to the system clock.
+    ///
+    /// Falls back to the Unix epoch, `1970-01-01`, if the system clock is set to an
+    /// earlier time.
+    pub fn today() -> Self {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs())
+            .unwrap_or_default();
+
+        Self::from_days((seconds / 86_400) as i64).unwrap_or_else(|| Self::new(1970, 1, 1))
+    }
+
+    /// Whether the date is valid in the proleptic Gregorian calendar
+    pub fn is_valid(&self) -> bool {
+        let DateImpl { year, month, day } = self.date;
+
+        (1..=12).contains(&month) && (1..=days_in_month(year, month)).contains(&day)
+    }
+
+    /// The day of the week, or `None` if the date is not valid.
+    pub fn weekday(&self) -> Option<Weekday> {
+        // The Unix epoch, 1970-01-01, was a Thursday
+        let days = self.days_since_epoch()?;
+
+        Some(Weekday::ALL[(days + 3).rem_euclid(7) as usize])
+    }
+
+    /// Add the given number of days, which may be negative.
+    ///
+    /// Returns `None` if the date is not valid, or if the result would be out of range.
+    pub fn checked_add_days(&self, days: i64) -> Option<Self> {
+        self.days_since_epoch()?
+            .checked_add(days)
+            .and_then(Self::from_days)
+    }
+
+    /// Subtract the given number of days, which may be negative.
+    ///
+    /// Returns `None` if the date is not valid, or if the result would be out of range.
+    pub fn checked_sub_days(&self, days: i64) -> Option<Self> {
+        self.checked_add_days(days.checked_neg()?)
+    }
+
+    /// The number of days from this date to the other date, which is negative if the other
+    /// date is earlier, e.g. `1` from `2024-02-29` to `2024-03-01`.
+    ///
+    /// Returns `None` if either date is not valid.
+    pub fn days_between(&self, other: &Date) -> Option<i64> {
+        Some(other.days_since_epoch()? - self.days_since_epoch()?)
+    }
+
+    /// The number of days since the Unix epoch, using the `days_from_civil` algorithm by
+    /// Howard Hinnant.
+    fn days_since_epoch(&self) -> Option<i64> {
+        if !self.is_valid() {
+            return None;
+        }
+
+        let month = i64::from(self.date.month);
+        let day = i64::from(self.date.day);
+        let year = i64::from(self.date.year) - i64::from(month <= 2);
+
+        let era = year.div_euclid(400);
+        let yoe = year - era * 400;
+        let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+        Some(era * 146_097 + doe - 719_468)
+    }
+
+    /// Converts the number of days since the Unix epoch to a date in the proleptic Gregorian
+    /// calendar, using the `civil_from_days` algorithm by Howard Hinnant.
+    ///
+    /// Returns `None` if the year does not fit in a `u16`.
+    fn from_days(days: i64) -> Option<Self> {
+        let z = days.checked_add(719_468)?;
+        let era = z.div_euclid(146_097);
+        let doe = z - era * 146_097;
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + i64::from(month <= 2);
+
+        let year = u16::try_from(year).ok()?;
+
+        Some(Self::new(year, month as u8, day as u8))
+    }
+
     /// The year
     pub fn year(&self) -> u16 {
         self.date.year
@@ -67,6 +177,212 @@ impl Date {
 
         format!("{y:04}-{m:02}-{d:02}")
     }
+
+    /// Parses a strict `YYYY-MM-DD` representation, as used by rustup toolchain specifiers,
+    /// e.g. `nightly-2024-02-01`.
+    ///
+    /// Like [`Date::new`], the date is not validated against the Gregorian calendar.
+    pub(crate) fn parse_ymd(input: &str) -> Option<Self> {
+        let bytes = input.as_bytes();
+
+        let is_ymd = bytes.len() == 10
+            && bytes.iter().enumerate().all(|(i, b)| match i {
+                4 | 7 => *b == b'-',
+                _ => b.is_ascii_digit(),
+            });
+
+        if !is_ymd {
+            return None;
+        }
+
+        let year = input[0..4].parse().ok()?;
+        let month = input[5..7].parse().ok()?;
+        let day = input[8..10].parse().ok()?;
+
+        Some(Self::new(year, month, day))
+    }
+}
+
+impl FromStr for Date {
+    type Err = DateError;
+
+    /// Parses a `YYYY-MM-DD` date, which must be valid in the proleptic Gregorian calendar.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let date = Self::parse_ymd(s).ok_or_else(|| DateError::Format(s.to_string()))?;
+
+        if date.is_valid() {
+            Ok(date)
+        } else {
+            Err(DateError::Invalid(date))
+        }
+    }
+}
+
+impl fmt::Display for Date {
+    /// Formats the date as `YYYY-MM-DD`, like [`Date::ymd`].
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.ymd())
+    }
+}
+
+/// A day of the week
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Weekday {
+    /// Monday
+    Monday,
+    /// Tuesday
+    Tuesday,
+    /// Wednesday
+    Wednesday,
+    /// Thursday
+    Thursday,
+    /// Friday
+    Friday,
+    /// Saturday
+    Saturday,
+    /// Sunday
+    Sunday,
+}
+
+impl Weekday {
+    const ALL: [Weekday; 7] = [
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+        Weekday::Sunday,
+    ];
+}
+
+impl fmt::Display for Weekday {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Weekday::Monday => "Monday",
+            Weekday::Tuesday => "Tuesday",
+            Weekday::Wednesday => "Wednesday",
+            Weekday::Thursday => "Thursday",
+            Weekday::Friday => "Friday",
+            Weekday::Saturday => "Saturday",
+            Weekday::Sunday => "Sunday",
+        })
+    }
+}
+
+/// Errors which may occur while creating, parsing or converting a [`Date`].
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+#[non_exhaustive]
+pub enum DateError {
+    /// Returned when the input is not of the form `YYYY-MM-DD`
+    #[error("Expected a date of the form `YYYY-MM-DD`, but got `{0}`")]
+    Format(String),
+
+    /// Returned when the date is not valid in the proleptic Gregorian calendar
+    #[error("Date `{0}` is not valid in the Gregorian calendar")]
+    Invalid(Date),
+
+    /// Returned when a date can not be represented by the target type of a conversion
+    #[error("Date is out of the supported range")]
+    OutOfRange,
+}
+
+fn is_leap_year(year: u16) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<Date> for time::Date {
+    type Error = DateError;
+
+    fn try_from(date: Date) -> Result<Self, Self::Error> {
+        if !date.is_valid() {
+            return Err(DateError::Invalid(date));
+        }
+
+        let month = time::Month::try_from(date.month()).map_err(|_| DateError::OutOfRange)?;
+
+        time::Date::from_calendar_date(i32::from(date.year()), month, date.day())
+            .map_err(|_| DateError::OutOfRange)
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<time::Date> for Date {
+    type Error = DateError;
+
+    fn try_from(date: time::Date) -> Result<Self, Self::Error> {
+        let year = u16::try_from(date.year()).map_err(|_| DateError::OutOfRange)?;
+
+        Ok(Date::new(year, u8::from(date.month()), date.day()))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<Date> for chrono::NaiveDate {
+    type Error = DateError;
+
+    fn try_from(date: Date) -> Result<Self, Self::Error> {
+        if !date.is_valid() {
+            return Err(DateError::Invalid(date));
+        }
+
+        chrono::NaiveDate::from_ymd_opt(
+            i32::from(date.year()),
+            u32::from(date.month()),
+            u32::from(date.day()),
+        )
+        .ok_or(DateError::OutOfRange)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<chrono::NaiveDate> for Date {
+    type Error = DateError;
+
+    fn try_from(date: chrono::NaiveDate) -> Result<Self, Self::Error> {
+        use chrono::Datelike;
+
+        let year = u16::try_from(date.year()).map_err(|_| DateError::OutOfRange)?;
+
+        Ok(Date::new(year, date.month() as u8, date.day() as u8))
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl TryFrom<Date> for jiff::civil::Date {
+    type Error = DateError;
+
+    fn try_from(date: Date) -> Result<Self, Self::Error> {
+        if !date.is_valid() {
+            return Err(DateError::Invalid(date));
+        }
+
+        let year = i16::try_from(date.year()).map_err(|_| DateError::OutOfRange)?;
+
+        jiff::civil::Date::new(year, date.month() as i8, date.day() as i8)
+            .map_err(|_| DateError::OutOfRange)
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl TryFrom<jiff::civil::Date> for Date {
+    type Error = DateError;
+
+    fn try_from(date: jiff::civil::Date) -> Result<Self, Self::Error> {
+        let year = u16::try_from(date.year()).map_err(|_| DateError::OutOfRange)?;
+
+        Ok(Date::new(year, date.month() as u8, date.day() as u8))
+    }
 }
 
 /// A compact date consisting of a four number year, and a two number month and day.
@@ -137,6 +453,167 @@ mod tests {
         assert_eq!(date.ymd().to_string(), expected.to_string());
     }
 
+    #[yare::parameterized(
+        regular = { "2024-02-01", Some(Date::new(2024, 2, 1)) },
+        zeroes = { "0000-00-00", Some(Date::new(0, 0, 0)) },
+        short_month = { "2024-2-01", None },
+        no_separator = { "20240201", None },
+        trailing = { "2024-02-01-x86_64", None },
+        non_digit = { "2024-0a-01", None },
+    )]
+    fn parse_ymd(input: &str, expected: Option<Date>) {
+        assert_eq!(Date::parse_ymd(input), expected);
+    }
+
+    #[yare::parameterized(
+        epoch = { 0, Date::new(1970, 1, 1) },
+        leap_day = { 11_016, Date::new(2000, 2, 29) },
+        rust_1_0 = { 16_570, Date::new(2015, 5, 15) },
+        new_year = { 19_723, Date::new(2024, 1, 1) },
+        before_epoch = { -1, Date::new(1969, 12, 31) },
+        year_zero = { -719_528, Date::new(0, 1, 1) },
+    )]
+    fn from_days(days: i64, expected: Date) {
+        assert_eq!(Date::from_days(days), Some(expected.clone()));
+        assert_eq!(expected.days_since_epoch(), Some(days));
+    }
+
+    #[yare::parameterized(
+        before_year_zero = { -719_529 },
+        after_max_year = { 23_217_004 },
+    )]
+    fn from_days_out_of_range(days: i64) {
+        assert_eq!(Date::from_days(days), None);
+    }
+
+    #[yare::parameterized(
+        regular = { 2024, 2, 1, true },
+        leap_day = { 2024, 2, 29, true },
+        leap_century = { 2000, 2, 29, true },
+        not_a_leap_year = { 2023, 2, 29, false },
+        not_a_leap_century = { 1900, 2, 29, false },
+        short_month = { 2024, 4, 31, false },
+        zero_month = { 2024, 0, 1, false },
+        zero_day = { 2024, 1, 0, false },
+        large_month = { 9999, 200, 200, false },
+    )]
+    fn try_new(year: u16, month: u8, day: u8, valid: bool) {
+        assert_eq!(Date::new(year, month, day).is_valid(), valid);
+        assert_eq!(Date::try_new(year, month, day).is_ok(), valid);
+    }
+
+    #[yare::parameterized(
+        regular = { "2024-02-01", Ok(Date::new(2024, 2, 1)) },
+        leap_day = { "2024-02-29", Ok(Date::new(2024, 2, 29)) },
+        invalid = { "2023-02-29", Err(DateError::Invalid(Date::new(2023, 2, 29))) },
+        zeroes = { "0000-00-00", Err(DateError::Invalid(Date::new(0, 0, 0))) },
+        format = { "2024-2-1", Err(DateError::Format("2024-2-1".to_string())) },
+        empty = { "", Err(DateError::Format(String::new())) },
+    )]
+    fn from_str(input: &str, expected: Result<Date, DateError>) {
+        assert_eq!(input.parse::<Date>(), expected);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Date::new(2024, 2, 1).to_string(), "2024-02-01");
+    }
+
+    #[yare::parameterized(
+        epoch = { Date::new(1970, 1, 1), Some(Weekday::Thursday) },
+        rust_1_0 = { Date::new(2015, 5, 15), Some(Weekday::Friday) },
+        leap_day = { Date::new(2024, 2, 29), Some(Weekday::Thursday) },
+        sunday = { Date::new(2024, 3, 3), Some(Weekday::Sunday) },
+        monday = { Date::new(1900, 1, 1), Some(Weekday::Monday) },
+        invalid = { Date::new(2024, 2, 30), None },
+    )]
+    fn weekday(date: Date, expected: Option<Weekday>) {
+        assert_eq!(date.weekday(), expected);
+    }
+
+    #[yare::parameterized(
+        next_day = { Date::new(2024, 2, 1), 1, Some(Date::new(2024, 2, 2)) },
+        leap_day = { Date::new(2024, 2, 28), 1, Some(Date::new(2024, 2, 29)) },
+        next_month = { Date::new(2023, 2, 28), 1, Some(Date::new(2023, 3, 1)) },
+        next_year = { Date::new(2023, 12, 31), 1, Some(Date::new(2024, 1, 1)) },
+        release_train = { Date::new(2023, 6, 1), 42, Some(Date::new(2023, 7, 13)) },
+        negative = { Date::new(2024, 1, 1), -1, Some(Date::new(2023, 12, 31)) },
+        out_of_range = { Date::new(0, 1, 1), -1, None },
+        invalid = { Date::new(2024, 2, 30), 1, None },
+    )]
+    fn checked_add_days(date: Date, days: i64, expected: Option<Date>) {
+        assert_eq!(date.checked_add_days(days), expected);
+    }
+
+    #[test]
+    fn checked_sub_days() {
+        let date = Date::new(2024, 3, 1);
+
+        assert_eq!(date.checked_sub_days(1), Some(Date::new(2024, 2, 29)));
+        assert_eq!(date.checked_sub_days(-1), Some(Date::new(2024, 3, 2)));
+        assert_eq!(date.checked_sub_days(i64::MIN), None);
+    }
+
+    #[yare::parameterized(
+        same = { Date::new(2024, 2, 1), Date::new(2024, 2, 1), Some(0) },
+        leap_day = { Date::new(2024, 2, 29), Date::new(2024, 3, 1), Some(1) },
+        release_train = { Date::new(2023, 6, 1), Date::new(2023, 7, 13), Some(42) },
+        earlier = { Date::new(2024, 1, 1), Date::new(2023, 1, 1), Some(-365) },
+        invalid = { Date::new(2024, 1, 1), Date::new(2024, 13, 1), None },
+    )]
+    fn days_between(from: Date, to: Date, expected: Option<i64>) {
+        assert_eq!(from.days_between(&to), expected);
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn convert_time() {
+        let date = Date::new(2024, 2, 29);
+
+        let converted = time::Date::try_from(date.clone()).unwrap();
+
+        assert_eq!(converted.to_string(), "2024-02-29");
+        assert_eq!(Date::try_from(converted), Ok(date));
+        assert!(time::Date::try_from(Date::new(2023, 2, 29)).is_err());
+        assert_eq!(
+            time::Date::try_from(Date::new(10_000, 1, 1)),
+            Err(DateError::OutOfRange)
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn convert_chrono() {
+        let date = Date::new(2024, 2, 29);
+
+        let converted = chrono::NaiveDate::try_from(date.clone()).unwrap();
+
+        assert_eq!(converted.to_string(), "2024-02-29");
+        assert_eq!(Date::try_from(converted), Ok(date));
+        assert!(chrono::NaiveDate::try_from(Date::new(2023, 2, 29)).is_err());
+    }
+
+    #[cfg(feature = "jiff")]
+    #[test]
+    fn convert_jiff() {
+        let date = Date::new(2024, 2, 29);
+
+        let converted = jiff::civil::Date::try_from(date.clone()).unwrap();
+
+        assert_eq!(converted.to_string(), "2024-02-29");
+        assert_eq!(Date::try_from(converted), Ok(date));
+        assert!(jiff::civil::Date::try_from(Date::new(2023, 2, 29)).is_err());
+        assert_eq!(
+            jiff::civil::Date::try_from(Date::new(10_000, 1, 1)),
+            Err(DateError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn today_is_after_epoch() {
+        assert!(Date::today() > Date::new(2024, 1, 1));
+    }
+
     #[test]
     fn newer_date() {
         let newer = Date::new(2000, 1, 1);
@@ -6,13 +6,21 @@
 //! it tries to follow official Rust sources as closely as possible.
 //!
 //! The project is currently primarily modelled around the rough [`toolchain`]
-//! specification written by the [`rustup`] developers. For now, we have disregarded
-//! custom toolchains altogether though, both in the name of simplicity, and because
-//! the current users didn't really need it yet. If you would like to see it added,
-//! please open an issue (preferably including your use case :)).
+//! specification written by the [`rustup`] developers. This includes custom toolchains,
+//! like locally built compilers linked with `rustup toolchain link`, which are modelled
+//! by [`channel::Custom`].
 //!
 //! This project is part of the [`rust-releases`] and [`cargo-msrv`] projects.
 //!
+//! # Features
+//!
+//! The following features are opt-in, since they depend on `toml_edit`:
+//!
+//! * `toolchain-file`: read and write `rust-toolchain.toml` and legacy `rust-toolchain` files,
+//!   with `ToolchainFile`
+//! * `rustup-home`: discover the toolchains installed by rustup, and its settings, with
+//!   `RustupHome`
+//!
 //! In case you have a feature request, question, bug, or have another reason to
 //! contact the developers, please create a new issue at the `rust-releases` [`repository`].
 //!
@@ -31,13 +39,31 @@
 pub mod channel;
 mod component;
 mod date;
+mod profile;
+#[cfg(feature = "rustup-home")]
+mod rustup_home;
+mod spec;
 mod target;
 mod toolchain;
+#[cfg(feature = "toolchain-file")]
+mod toolchain_file;
 mod version;
 
 pub use channel::Channel;
-pub use component::Component;
-pub use date::Date;
-pub use target::Target;
+pub use component::{Component, KnownComponent, UnknownComponent};
+pub use date::{Date, DateError, Weekday};
+pub use profile::{Profile, UnknownProfile};
+#[cfg(feature = "rustup-home")]
+pub use rustup_home::{InstalledToolchain, RustupHome, RustupHomeError};
+pub use spec::{ChannelSpec, ParseToolchainError, ToolchainSpec};
+pub use target::{
+    Architecture, Environment, OperatingSystem, PlatformSupport, PointerWidth, Target, Tier, Vendor,
+};
 pub use toolchain::Toolchain;
-pub use version::RustVersion;
+#[cfg(feature = "toolchain-file")]
+pub use toolchain_file::{ToolchainFile, ToolchainFileError};
+#[cfg(feature = "semver")]
+pub use version::SemverError;
+pub use version::{
+    Comparator, Op, ParseVersionReqError, PartialRustVersion, RustVersion, RustVersionReq,
+};
@@ -0,0 +1,175 @@
+use crate::{Component, KnownComponent, Target};
+use std::fmt;
+use std::str::FromStr;
+
+/// A rustup [`profile`], which is a named group of components installed with a toolchain.
+///
+/// # Reading materials
+///
+/// - [`rustup concepts: profiles`]
+///
+/// [`profile`]: https://rust-lang.github.io/rustup/concepts/profiles.html
+/// [`rustup concepts: profiles`]: https://rust-lang.github.io/rustup/concepts/profiles.html
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Profile {
+    /// The `minimal` profile: `rustc`, `rust-std` and `cargo`
+    Minimal,
+    /// The `default` profile: the `minimal` profile, plus `rust-docs`, `rustfmt` and `clippy`
+    #[default]
+    Default,
+    /// The `complete` profile: every component available for a toolchain
+    Complete,
+}
+
+impl Profile {
+    /// The well-known components which are part of the profile.
+    ///
+    /// The `complete` profile contains every component available for a toolchain,
+    /// so it is not limited to the well-known components.
+    pub fn known_components(&self) -> &'static [KnownComponent] {
+        const MINIMAL: &[KnownComponent] = &[
+            KnownComponent::Rustc,
+            KnownComponent::RustStd,
+            KnownComponent::Cargo,
+        ];
+
+        const DEFAULT: &[KnownComponent] = &[
+            KnownComponent::Rustc,
+            KnownComponent::RustStd,
+            KnownComponent::Cargo,
+            KnownComponent::RustDocs,
+            KnownComponent::Rustfmt,
+            KnownComponent::Clippy,
+        ];
+
+        match self {
+            Profile::Minimal => MINIMAL,
+            Profile::Default => DEFAULT,
+            Profile::Complete => KnownComponent::ALL,
+        }
+    }
+
+    /// Whether the component is part of the profile
+    pub fn contains(&self, component: &Component) -> bool {
+        match (self, component.known()) {
+            (Profile::Complete, _) => true,
+            (_, Some(known)) => self.known_components().contains(&known),
+            (_, None) => false,
+        }
+    }
+
+    /// Selects the components of the profile which are available on the given target, from
+    /// the components available for a toolchain, e.g. as described by a release manifest.
+    pub fn select<'c>(
+        &self,
+        available: impl IntoIterator<Item = &'c Component>,
+        target: &Target,
+    ) -> Vec<&'c Component> {
+        available
+            .into_iter()
+            .filter(|component| component.is_available_on(target) && self.contains(component))
+            .collect()
+    }
+
+    /// The name of the profile
+    pub fn name(&self) -> &'static str {
+        match self {
+            Profile::Minimal => "minimal",
+            Profile::Default => "default",
+            Profile::Complete => "complete",
+        }
+    }
+}
+
+impl FromStr for Profile {
+    type Err = UnknownProfile;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "minimal" => Ok(Profile::Minimal),
+            "default" => Ok(Profile::Default),
+            "complete" => Ok(Profile::Complete),
+            unknown => Err(UnknownProfile(unknown.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Returned when a name does not refer to a [`Profile`]
+#[derive(Debug, thiserror::Error)]
+#[error("Unknown profile `{0}`, expected `minimal`, `default` or `complete`")]
+pub struct UnknownProfile(String);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use yare::parameterized;
+
+    fn available() -> Vec<Component> {
+        let wasm = Target::try_from_target_triple("wasm32-unknown-unknown").unwrap();
+
+        vec![
+            Component::new("rustc"),
+            Component::new("cargo"),
+            Component::for_target("rust-std", Target::host()),
+            Component::for_target("rust-std", wasm),
+            Component::new("rust-docs"),
+            Component::new("rustfmt-preview"),
+            Component::new("clippy-preview"),
+            Component::new("rust-src"),
+            Component::new("rls-preview"),
+        ]
+    }
+
+    #[parameterized(
+        minimal = { Profile::Minimal, &["rustc", "cargo", "rust-std"] },
+        default = { Profile::Default, &["rustc", "cargo", "rust-std", "rust-docs", "rustfmt-preview", "clippy-preview"] },
+        complete = { Profile::Complete, &["rustc", "cargo", "rust-std", "rust-docs", "rustfmt-preview", "clippy-preview", "rust-src", "rls-preview"] },
+    )]
+    fn select(profile: Profile, expected: &[&str]) {
+        let available = available();
+
+        let selected = profile
+            .select(&available, &Target::host())
+            .into_iter()
+            .map(Component::name)
+            .collect::<Vec<_>>();
+
+        assert_eq!(selected, expected);
+    }
+
+    #[test]
+    fn select_target_specific() {
+        let available = available();
+        let wasm = Target::try_from_target_triple("wasm32-unknown-unknown").unwrap();
+
+        let rust_std = Profile::Minimal
+            .select(&available, &wasm)
+            .into_iter()
+            .filter(|component| component.name() == "rust-std")
+            .collect::<Vec<_>>();
+
+        assert_eq!(rust_std.len(), 1);
+        assert_eq!(rust_std[0].target(), Some(&wasm));
+    }
+
+    #[parameterized(
+        minimal = { "minimal", Profile::Minimal },
+        default = { "default", Profile::Default },
+        complete = { "complete", Profile::Complete },
+    )]
+    fn parse_and_display(name: &str, expected: Profile) {
+        assert_eq!(name.parse::<Profile>().unwrap(), expected);
+        assert_eq!(expected.to_string(), name);
+    }
+
+    #[test]
+    fn parse_unknown() {
+        assert!("everything".parse::<Profile>().is_err());
+    }
+}
@@ -0,0 +1,583 @@
+use crate::{
+    Channel, Component, Date, KnownComponent, ParseToolchainError, Profile, Target, Toolchain,
+    ToolchainSpec,
+};
+use std::collections::{BTreeMap, HashSet};
+use std::convert::TryFrom;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::{env, fs, io};
+use toml_edit::{DocumentMut, Item, TableLike};
+
+const SETTINGS: &str = "settings.toml";
+const TOOLCHAINS: &str = "toolchains";
+const COMPONENTS: &str = "lib/rustlib/components";
+const MANIFEST: &str = "lib/rustlib/multirust-channel-manifest.toml";
+
+/// A [`rustup home`] directory, which contains the toolchains installed by rustup, and its
+/// settings, like the default toolchain and the directory overrides.
+///
+/// The settings are read when the rustup home is opened, while the installed toolchains are
+/// read on request, by [`RustupHome::toolchains`].
+///
+/// # Reading materials
+///
+/// - [`rustup installation: choosing where to install`]
+/// - [`rustup overrides: directory overrides`]
+///
+/// [`rustup home`]: https://rust-lang.github.io/rustup/installation/index.html#choosing-where-to-install
+/// [`rustup installation: choosing where to install`]: https://rust-lang.github.io/rustup/installation/index.html#choosing-where-to-install
+/// [`rustup overrides: directory overrides`]: https://rust-lang.github.io/rustup/overrides.html#directory-overrides
+#[derive(Clone, Debug)]
+pub struct RustupHome {
+    path: PathBuf,
+    default_toolchain: Option<ToolchainSpec>,
+    profile: Option<Profile>,
+    overrides: BTreeMap<PathBuf, ToolchainSpec>,
+}
+
+impl RustupHome {
+    /// Open the rustup home given by the `RUSTUP_HOME` environment variable, or the `.rustup`
+    /// directory within the home directory of the user otherwise, like rustup does.
+    pub fn from_env() -> Result<Self, RustupHomeError> {
+        let path = env::var_os("RUSTUP_HOME")
+            .filter(|path| !path.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| {
+                env::var_os("HOME")
+                    .or_else(|| env::var_os("USERPROFILE"))
+                    .filter(|path| !path.is_empty())
+                    .map(|home| Path::new(&home).join(".rustup"))
+            })
+            .ok_or(RustupHomeError::NotFound)?;
+
+        Self::open(path)
+    }
+
+    /// Open the rustup home at the given path, and read its `settings.toml`, if present.
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, RustupHomeError> {
+        let path = path.into();
+        let settings = path.join(SETTINGS);
+
+        let mut home = Self {
+            path,
+            default_toolchain: None,
+            profile: None,
+            overrides: BTreeMap::new(),
+        };
+
+        match fs::read_to_string(&settings) {
+            Ok(contents) => home.read_settings(&contents)?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => return Err(RustupHomeError::Io(settings, err)),
+        }
+
+        Ok(home)
+    }
+
+    fn read_settings(&mut self, contents: &str) -> Result<(), RustupHomeError> {
+        let document = contents.parse::<DocumentMut>()?;
+
+        self.default_toolchain = get_str(document.as_table(), "default_toolchain")?
+            .map(ToolchainSpec::parse_or_custom)
+            .transpose()?;
+
+        self.profile = get_str(document.as_table(), "profile")?
+            .map(Profile::from_str)
+            .transpose()?;
+
+        if let Some(item) = document.get("overrides") {
+            let overrides = item
+                .as_table_like()
+                .ok_or(RustupHomeError::InvalidType("overrides", "a table"))?;
+
+            for (dir, toolchain) in overrides.iter() {
+                let toolchain = toolchain.as_str().ok_or(RustupHomeError::InvalidType(
+                    "overrides",
+                    "a table of strings",
+                ))?;
+
+                self.overrides.insert(
+                    PathBuf::from(dir),
+                    ToolchainSpec::parse_or_custom(toolchain)?,
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The path of the rustup home
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The default toolchain, as set by `rustup default`
+    pub fn default_toolchain(&self) -> Option<&ToolchainSpec> {
+        self.default_toolchain.as_ref()
+    }
+
+    /// The profile used to install new toolchains, as set by `rustup set profile`
+    pub fn profile(&self) -> Option<Profile> {
+        self.profile
+    }
+
+    /// The directory overrides, as set by `rustup override set`, by directory
+    pub fn overrides(&self) -> &BTreeMap<PathBuf, ToolchainSpec> {
+        &self.overrides
+    }
+
+    /// The directory override which applies to the given directory, i.e. the override of the
+    /// directory itself, or of its closest ancestor.
+    ///
+    /// Toolchain files are not taken into account.
+    pub fn override_for(&self, dir: impl AsRef<Path>) -> Option<&ToolchainSpec> {
+        dir.as_ref()
+            .ancestors()
+            .find_map(|ancestor| self.overrides.get(ancestor))
+    }
+
+    /// Read the toolchains installed in the rustup home, sorted by name.
+    ///
+    /// The channel and date of a toolchain are read from the release manifest which rustup
+    /// keeps within the toolchain, so toolchains installed by a symbolic name, like `stable`,
+    /// are reported as the release which is installed. The components and targets are read
+    /// from the list of installed components.
+    ///
+    /// Directories which are not recognized as a toolchain name, like those of toolchains
+    /// linked with `rustup toolchain link`, are reported as custom toolchains.
+    ///
+    /// A toolchain which can not be read, e.g. because its name contains an invalid version,
+    /// or because it is installed by a symbolic name, but lacks a release manifest, is
+    /// reported as a [`RustupHomeError::InvalidToolchain`], in place of the toolchain, so it
+    /// does not prevent the other toolchains from being read. The outer error is only returned
+    /// when the toolchains directory itself can not be read.
+    pub fn toolchains(
+        &self,
+    ) -> Result<Vec<Result<InstalledToolchain, RustupHomeError>>, RustupHomeError> {
+        let dir = self.path.join(TOOLCHAINS);
+
+        let entries = match fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(RustupHomeError::Io(dir, err)),
+        };
+
+        let mut toolchains = Vec::new();
+
+        for entry in entries {
+            let entry = entry.map_err(|err| RustupHomeError::Io(dir.clone(), err))?;
+            let path = entry.path();
+
+            if !path.is_dir() {
+                continue;
+            }
+
+            // Toolchain names are always valid UTF-8, so other directories are not toolchains
+            if let Some(name) = entry.file_name().to_str() {
+                let toolchain = InstalledToolchain::read(name, path).map_err(|err| {
+                    RustupHomeError::InvalidToolchain(name.to_string(), Box::new(err))
+                });
+
+                toolchains.push((name.to_string(), toolchain));
+            }
+        }
+
+        toolchains.sort_by(|(lhs, _), (rhs, _)| lhs.cmp(rhs));
+
+        Ok(toolchains
+            .into_iter()
+            .map(|(_, toolchain)| toolchain)
+            .collect())
+    }
+}
+
+/// A toolchain installed in a [`RustupHome`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InstalledToolchain {
+    name: String,
+    path: PathBuf,
+    spec: ToolchainSpec,
+    toolchain: Toolchain,
+}
+
+impl InstalledToolchain {
+    fn read(name: &str, path: PathBuf) -> Result<Self, RustupHomeError> {
+        let spec = ToolchainSpec::parse_or_custom(name)?;
+        let manifest = read_optional(&path.join(MANIFEST))?
+            .map(|contents| Manifest::parse(&contents))
+            .transpose()?;
+
+        let mut toolchain = match manifest.as_ref().and_then(Manifest::channel) {
+            Some(channel) => Toolchain::new(
+                channel,
+                None,
+                spec.host().cloned().unwrap_or_else(Target::host),
+                HashSet::new(),
+                HashSet::new(),
+            ),
+            None => Toolchain::try_from(spec.clone())?,
+        };
+
+        if let Some(date) = manifest.and_then(|manifest| manifest.date) {
+            toolchain.set_date(Some(date));
+        }
+
+        if let Some(contents) = read_optional(&path.join(COMPONENTS))? {
+            let components = contents
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(parse_component)
+                .collect::<HashSet<_>>();
+
+            let targets = components
+                .iter()
+                .filter(|component| component.known() == Some(KnownComponent::RustStd))
+                .filter_map(|component| component.target().cloned())
+                .collect();
+
+            toolchain.set_components(components);
+            toolchain.set_targets(targets);
+        }
+
+        Ok(Self {
+            name: name.to_string(),
+            path,
+            spec,
+            toolchain,
+        })
+    }
+
+    /// The name of the toolchain, i.e. the name of its directory
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The path of the toolchain directory
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The specifier by which the toolchain was installed, e.g. `stable-x86_64-unknown-linux-gnu`
+    pub fn spec(&self) -> &ToolchainSpec {
+        &self.spec
+    }
+
+    /// The installed toolchain, including its installed components and targets
+    pub fn toolchain(&self) -> &Toolchain {
+        &self.toolchain
+    }
+
+    /// Take the installed toolchain
+    pub fn into_toolchain(self) -> Toolchain {
+        self.toolchain
+    }
+}
+
+/// The parts of the release manifest which rustup keeps within an installed toolchain.
+struct Manifest {
+    date: Option<Date>,
+    // E.g. `1.70.0 (90c541806 2023-05-31)` or `1.72.0-nightly (d59363ad0 2023-06-01)`
+    version: Option<String>,
+}
+
+impl Manifest {
+    fn parse(contents: &str) -> Result<Self, RustupHomeError> {
+        let document = contents.parse::<DocumentMut>()?;
+
+        let date = get_str(document.as_table(), "date")?.and_then(Date::parse_ymd);
+        let version = document
+            .get("pkg")
+            .and_then(|pkg| pkg.get("rust"))
+            .and_then(Item::as_table_like)
+            .map(|rust| get_str(rust, "version"))
+            .transpose()?
+            .flatten()
+            .map(str::to_string);
+
+        Ok(Self { date, version })
+    }
+
+    fn channel(&self) -> Option<Channel> {
+        let version = self.version.as_deref()?.split_whitespace().next()?;
+
+        match version.strip_suffix("-nightly") {
+            Some(_) => self.date.clone().map(Channel::nightly),
+            None => version.parse().ok(),
+        }
+    }
+}
+
+/// Parses an entry of the list of installed components, like `rust-std-wasm32-unknown-unknown`,
+/// which consists of the name of the component, optionally followed by the target for which
+/// the component was installed.
+fn parse_component(entry: &str) -> Component {
+    let split = entry.match_indices('-').find_map(|(i, _)| {
+        let (name, target) = (&entry[..i], &entry[i + 1..]);
+
+        Target::try_from_target_triple(target)
+            .ok()
+            .map(|target| (name, target))
+    });
+
+    match split {
+        Some((name, target)) => {
+            let is_target_specific = Component::new(name.to_string())
+                .known()
+                .is_some_and(|known| known.is_target_specific());
+
+            if is_target_specific {
+                Component::for_target(name.to_string(), target)
+            } else {
+                Component::new(name.to_string())
+            }
+        }
+        None => Component::new(entry.to_string()),
+    }
+}
+
+fn read_optional(path: &Path) -> Result<Option<String>, RustupHomeError> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(RustupHomeError::Io(path.to_path_buf(), err)),
+    }
+}
+
+fn get_str<'t>(
+    table: &'t dyn TableLike,
+    key: &'static str,
+) -> Result<Option<&'t str>, RustupHomeError> {
+    table
+        .get(key)
+        .map(|item| {
+            item.as_str()
+                .ok_or(RustupHomeError::InvalidType(key, "a string"))
+        })
+        .transpose()
+}
+
+/// Errors which may occur while reading a [`RustupHome`].
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum RustupHomeError {
+    /// Returned when neither `RUSTUP_HOME`, nor the home directory of the user is set
+    #[error("Unable to locate the rustup home: RUSTUP_HOME and HOME are not set")]
+    NotFound,
+
+    /// Returned when a file or directory within the rustup home could not be read
+    #[error("Unable to read '{path}': {err}", path = .0.display(), err = .1)]
+    Io(PathBuf, #[source] io::Error),
+
+    /// Returned when the settings, or a release manifest, is not a valid `toml` document
+    #[error("Unable to parse rustup file: {0}")]
+    Toml(#[from] toml_edit::TomlError),
+
+    /// Returned when a key does not have the expected type
+    #[error("Expected `{0}` to be {1}")]
+    InvalidType(&'static str, &'static str),
+
+    /// Returned when a toolchain can not be parsed, or does not denote a single toolchain
+    #[error(transparent)]
+    Toolchain(#[from] ParseToolchainError),
+
+    /// Returned when the profile is not recognized
+    #[error(transparent)]
+    Profile(#[from] crate::UnknownProfile),
+
+    /// Returned in place of an installed toolchain which could not be read
+    #[error("Unable to read installed toolchain '{0}': {1}")]
+    InvalidToolchain(String, #[source] Box<RustupHomeError>),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ChannelSpec, RustVersion};
+    use yare::parameterized;
+
+    fn home() -> RustupHome {
+        let path = [env!("CARGO_MANIFEST_DIR"), "/../../resources/rustup_home"].join("");
+
+        RustupHome::open(path).unwrap()
+    }
+
+    // The toolchains of the fixture which could be read
+    fn valid_toolchains(home: &RustupHome) -> Vec<InstalledToolchain> {
+        home.toolchains()
+            .unwrap()
+            .into_iter()
+            .filter_map(Result::ok)
+            .collect()
+    }
+
+    fn x86_64() -> Target {
+        Target::try_from_target_triple("x86_64-unknown-linux-gnu").unwrap()
+    }
+
+    #[test]
+    fn settings() {
+        let home = home();
+
+        assert_eq!(
+            home.default_toolchain().unwrap().to_string(),
+            "stable-x86_64-unknown-linux-gnu"
+        );
+        assert_eq!(home.profile(), Some(Profile::Minimal));
+        assert_eq!(home.overrides().len(), 2);
+    }
+
+    #[parameterized(
+        exact = { "/home/ferris/projects/msrv", Some("1.70.0-x86_64-unknown-linux-gnu") },
+        ancestor = { "/home/ferris/projects/msrv/crates/core", Some("1.70.0-x86_64-unknown-linux-gnu") },
+        custom = { "/home/ferris/projects/compiler", Some("stage2") },
+        none = { "/home/ferris/projects", None },
+    )]
+    fn override_for(dir: &str, expected: Option<&str>) {
+        let home = home();
+
+        let spec = home.override_for(dir);
+
+        assert_eq!(spec.map(ToString::to_string).as_deref(), expected);
+    }
+
+    #[test]
+    fn toolchains() {
+        let home = home();
+
+        let toolchains = valid_toolchains(&home);
+
+        let names = toolchains
+            .iter()
+            .map(|toolchain| (toolchain.name(), toolchain.toolchain().to_string()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            names,
+            vec![
+                (
+                    "1.70.0-x86_64-unknown-linux-gnu",
+                    "1.70.0-x86_64-unknown-linux-gnu".to_string()
+                ),
+                (
+                    "beta-x86_64-unknown-linux-gnu",
+                    "1.71.0-beta.1-x86_64-unknown-linux-gnu".to_string()
+                ),
+                (
+                    "nightly-2023-06-02-x86_64-unknown-linux-gnu",
+                    "nightly-2023-06-02-x86_64-unknown-linux-gnu".to_string()
+                ),
+                (
+                    "stable-x86_64-unknown-linux-gnu",
+                    "1.71.0-x86_64-unknown-linux-gnu".to_string()
+                ),
+                ("stage2", "stage2".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn symbolic_toolchain_is_read_from_manifest() {
+        let home = home();
+        let toolchains = valid_toolchains(&home);
+
+        let stable = &toolchains[3];
+
+        assert_eq!(stable.spec().channel(), &ChannelSpec::Stable);
+        assert_eq!(
+            stable.toolchain().channel(),
+            &Channel::stable(RustVersion::new(1, 71, 0))
+        );
+        assert_eq!(stable.toolchain().date(), Some(&Date::new(2023, 7, 13)));
+        assert_eq!(stable.toolchain().host(), &x86_64());
+    }
+
+    #[test]
+    fn components_and_targets() {
+        let home = home();
+        let toolchains = valid_toolchains(&home);
+        let toolchain = toolchains[3].toolchain();
+
+        let wasm = Target::try_from_target_triple("wasm32-unknown-unknown").unwrap();
+
+        let mut names = toolchain
+            .components()
+            .iter()
+            .map(|component| component.name())
+            .collect::<Vec<_>>();
+        names.sort_unstable();
+
+        assert_eq!(
+            names,
+            vec![
+                "cargo",
+                "clippy-preview",
+                "rust-src",
+                "rust-std",
+                "rust-std",
+                "rustc",
+                "rustfmt-preview"
+            ]
+        );
+        assert!(toolchain
+            .components()
+            .contains(&Component::for_target("rust-std", wasm.clone())));
+        assert_eq!(toolchain.targets(), &[x86_64(), wasm].into());
+    }
+
+    #[test]
+    fn invalid_toolchains_do_not_abort_listing() {
+        let home = home();
+        let toolchains = home.toolchains().unwrap();
+
+        let invalid = toolchains
+            .iter()
+            .filter_map(|toolchain| match toolchain {
+                Err(RustupHomeError::InvalidToolchain(name, err)) => Some((name.as_str(), &**err)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(toolchains.len(), 7);
+        assert!(matches!(
+            invalid.as_slice(),
+            [
+                ("1.x.0", RustupHomeError::Toolchain(_)),
+                (
+                    "nightly-x86_64-unknown-linux-gnu",
+                    RustupHomeError::Toolchain(ParseToolchainError::Unresolved(_))
+                ),
+            ]
+        ));
+    }
+
+    #[test]
+    fn custom_toolchain() {
+        let home = home();
+        let toolchains = valid_toolchains(&home);
+
+        let stage2 = toolchains[4].toolchain();
+
+        assert!(stage2.channel().is_custom());
+        assert!(stage2.components().is_empty());
+    }
+
+    #[parameterized(
+        cargo = { "cargo-x86_64-unknown-linux-gnu", Component::new("cargo") },
+        renamed = { "clippy-preview-x86_64-unknown-linux-gnu", Component::new("clippy-preview") },
+        rust_std = { "rust-std-wasm32-unknown-unknown", Component::for_target("rust-std", Target::try_from_target_triple("wasm32-unknown-unknown").unwrap()) },
+        without_target = { "rust-src", Component::new("rust-src") },
+    )]
+    fn parse_components(entry: &str, expected: Component) {
+        assert_eq!(parse_component(entry), expected);
+    }
+
+    #[test]
+    fn missing_home_is_empty() {
+        let home = RustupHome::open("/definitely/not/a/rustup/home").unwrap();
+
+        assert!(home.default_toolchain().is_none());
+        assert!(valid_toolchains(&home).is_empty());
+    }
+}
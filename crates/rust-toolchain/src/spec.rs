@@ -0,0 +1,468 @@
+use crate::channel::{Alpha, Beta, Custom};
+use crate::{Channel, Date, PartialRustVersion, RustVersion, Target, Toolchain};
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+/// A rustup [`toolchain`] specifier, such as `stable`, `1.70`, `beta-2024-01-05` or
+/// `nightly-2024-02-01-x86_64-unknown-linux-gnu`.
+///
+/// Unlike a [`Toolchain`], a specifier may be symbolic (e.g. `stable`), or refer to a partial
+/// version (e.g. `1.70`). Such a specifier only denotes a concrete toolchain once it is resolved
+/// against an index of releases.
+///
+/// The specifier has the form `<channel>[-<date>][-<host>]`, where:
+/// * `<channel>` is one of `stable`, `beta` or `nightly`, a two component `major.minor`
+///   version, or a three component `major.minor.patch` version, optionally labelled as a beta
+///   or alpha pre-release, e.g. `1.71.0-beta.2`
+/// * `<date>` is a `YYYY-MM-DD` date, which may only follow a `stable`, `beta` or `nightly` channel
+/// * `<host>` is a target triple
+///
+/// # Reading materials
+///
+/// - [`rustup concepts: toolchains`]
+///
+/// [`toolchain`]: https://rust-lang.github.io/rustup/concepts/toolchains.html#toolchain-specification
+/// [`rustup concepts: toolchains`]: https://rust-lang.github.io/rustup/concepts/toolchains.html
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ToolchainSpec {
+    channel: ChannelSpec,
+    date: Option<Date>,
+    host: Option<Target>,
+}
+
+impl ToolchainSpec {
+    /// Create a new toolchain specifier
+    pub fn new(channel: ChannelSpec, date: Option<Date>, host: Option<Target>) -> Self {
+        Self {
+            channel,
+            date,
+            host,
+        }
+    }
+
+    /// Create a new specifier for a custom toolchain
+    pub fn custom(custom: Custom) -> Self {
+        Self {
+            channel: ChannelSpec::Custom(custom),
+            date: None,
+            host: None,
+        }
+    }
+
+    /// Parse a toolchain specifier, like [`ToolchainSpec::from_str`], but treat a specifier of
+    /// which the channel is not recognized as the name of a custom toolchain, like rustup does.
+    pub fn parse_or_custom(input: &str) -> Result<Self, ParseToolchainError> {
+        match input.parse() {
+            Err(ParseToolchainError::Channel(_)) => {
+                Ok(Self::custom(Custom::Named(input.to_string())))
+            }
+            result => result,
+        }
+    }
+
+    /// The channel of the specifier
+    pub fn channel(&self) -> &ChannelSpec {
+        &self.channel
+    }
+
+    /// The date of the specifier, e.g. `2024-01-05` for `beta-2024-01-05`
+    pub fn date(&self) -> Option<&Date> {
+        self.date.as_ref()
+    }
+
+    /// The host target of the specifier, if any
+    pub fn host(&self) -> Option<&Target> {
+        self.host.as_ref()
+    }
+}
+
+impl FromStr for ToolchainSpec {
+    type Err = ParseToolchainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (channel, date, rest) = parse_channel_spec(s)?;
+        let host = parse_host(rest)?;
+
+        Ok(Self {
+            channel,
+            date,
+            host,
+        })
+    }
+}
+
+impl fmt::Display for ToolchainSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.channel)?;
+
+        if let Some(date) = &self.date {
+            write!(f, "-{}", date.ymd())?;
+        }
+
+        if let Some(host) = &self.host {
+            write!(f, "-{}", host)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl From<&Toolchain> for ToolchainSpec {
+    fn from(toolchain: &Toolchain) -> Self {
+        let (channel, date) = match toolchain.channel() {
+            Channel::Nightly(nightly) => (ChannelSpec::Nightly, Some(nightly.date.clone())),
+            // The name of a custom toolchain does not include a host
+            Channel::Custom(custom) => return Self::custom(custom.clone()),
+            channel => (ChannelSpec::Exact(channel.clone()), None),
+        };
+
+        Self {
+            channel,
+            date,
+            host: Some(toolchain.host().clone()),
+        }
+    }
+}
+
+impl TryFrom<ToolchainSpec> for Toolchain {
+    type Error = ParseToolchainError;
+
+    /// Convert a specifier which denotes a single toolchain, i.e. a specifier with a three
+    /// component version, a dated nightly, or a custom toolchain, into a [`Toolchain`].
+    ///
+    /// If the specifier does not have a host, the [`Target::host`] is used, like rustup does.
+    fn try_from(spec: ToolchainSpec) -> Result<Self, Self::Error> {
+        let channel = match (&spec.channel, &spec.date) {
+            (ChannelSpec::Nightly, Some(date)) => Channel::nightly(date.clone()),
+            (ChannelSpec::Exact(channel), _) => channel.clone(),
+            (ChannelSpec::Custom(custom), _) => Channel::Custom(custom.clone()),
+            _ => return Err(ParseToolchainError::Unresolved(spec.to_string())),
+        };
+
+        let date = match &channel {
+            Channel::Nightly(nightly) => Some(nightly.date.clone()),
+            _ => spec.date,
+        };
+
+        Ok(Toolchain::new(
+            channel,
+            date,
+            spec.host.unwrap_or_else(Target::host),
+            HashSet::new(),
+            HashSet::new(),
+        ))
+    }
+}
+
+/// The channel of a [`ToolchainSpec`]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ChannelSpec {
+    /// The `stable` channel, i.e. the latest stable release
+    Stable,
+    /// The `beta` channel, i.e. the latest beta release
+    Beta,
+    /// The `nightly` channel, i.e. the latest nightly release
+    Nightly,
+    /// A two component `major.minor` version, e.g. `1.70`, which refers to the latest
+    /// `1.70.x` patch release
+    Partial(PartialRustVersion),
+    /// A channel pinned to a three component version, e.g. `1.70.0` or `1.71.0-beta.2`
+    Exact(Channel),
+    /// A custom toolchain, which is not released by the Rust project, and thus can not be
+    /// resolved against an index of releases
+    Custom(Custom),
+}
+
+impl fmt::Display for ChannelSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChannelSpec::Stable => f.write_str("stable"),
+            ChannelSpec::Beta => f.write_str("beta"),
+            ChannelSpec::Nightly => f.write_str("nightly"),
+            ChannelSpec::Partial(version) => write!(f, "{}", version),
+            ChannelSpec::Exact(channel) => write!(f, "{}", channel),
+            ChannelSpec::Custom(custom) => write!(f, "{}", custom),
+        }
+    }
+}
+
+/// Errors which may occur while parsing a [`ToolchainSpec`], [`Toolchain`] or [`Channel`].
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum ParseToolchainError {
+    /// Returned when the input is empty
+    #[error("Toolchain specifier is empty")]
+    Empty,
+
+    /// Returned when the channel is not recognized
+    #[error("Unrecognized channel `{0}`")]
+    Channel(String),
+
+    /// Returned when the version of the channel can not be parsed
+    #[error("Unable to parse version `{0}`: {1}")]
+    Version(String, #[source] crate::version::ParseError),
+
+    /// Returned when the pre-release label of a version is not recognized
+    #[error(
+        "Unrecognized pre-release label `{0}`, expected `beta`, `beta.N`, `alpha` or `alpha.N`"
+    )]
+    Label(String),
+
+    /// Returned when the host target triple can not be parsed
+    #[error("Unable to parse host `{0}`: {1}")]
+    Host(String, #[source] crate::target::ParseError),
+
+    /// Returned when the input contains more than was expected, e.g. a host for a [`Channel`]
+    #[error("Unexpected input `{0}`")]
+    Unexpected(String),
+
+    /// Returned when a specifier is symbolic or partial, and thus does not denote a single toolchain
+    #[error("Toolchain specifier `{0}` does not denote a single toolchain")]
+    Unresolved(String),
+}
+
+/// Parses the channel, and the optional date, of a toolchain specifier, and returns the remaining
+/// input.
+pub(crate) fn parse_channel_spec(
+    input: &str,
+) -> Result<(ChannelSpec, Option<Date>, &str), ParseToolchainError> {
+    if input.is_empty() {
+        return Err(ParseToolchainError::Empty);
+    }
+
+    let symbolic = [
+        ("stable", ChannelSpec::Stable),
+        ("beta", ChannelSpec::Beta),
+        ("nightly", ChannelSpec::Nightly),
+    ];
+
+    for (name, channel) in symbolic {
+        if let Some(rest) = strip_segment(input, name) {
+            let (date, rest) = parse_date(rest);
+            return Ok((channel, date, rest));
+        }
+    }
+
+    let (version, rest) = input.split_at(input.find('-').unwrap_or(input.len()));
+
+    if !version.starts_with(|c: char| c.is_ascii_digit()) {
+        return Err(ParseToolchainError::Channel(version.to_string()));
+    }
+
+    if version.matches('.').count() == 1 {
+        let partial = version
+            .parse::<PartialRustVersion>()
+            .map_err(|err| ParseToolchainError::Version(version.to_string(), err))?;
+
+        return Ok((ChannelSpec::Partial(partial), None, rest));
+    }
+
+    let version = version
+        .parse::<RustVersion>()
+        .map_err(|err| ParseToolchainError::Version(version.to_string(), err))?;
+
+    let (channel, rest) = parse_label(version, rest)?;
+
+    Ok((ChannelSpec::Exact(channel), None, rest))
+}
+
+/// Parses the optional `-beta[.N]` or `-alpha[.N]` label which may follow a three component
+/// version. A version with major version `0` is a historic alpha release.
+fn parse_label(version: RustVersion, rest: &str) -> Result<(Channel, &str), ParseToolchainError> {
+    let unlabelled = || {
+        if version.major() == 0 {
+            Channel::alpha(version)
+        } else {
+            Channel::stable(version)
+        }
+    };
+
+    let segment = match rest.strip_prefix('-') {
+        Some(segment) if segment.starts_with("beta") || segment.starts_with("alpha") => segment,
+        _ => return Ok((unlabelled(), rest)),
+    };
+
+    let (label, rest) = segment.split_at(segment.find('-').unwrap_or(segment.len()));
+
+    let (name, n) = match label.split_once('.') {
+        Some((name, n)) => {
+            let n = n
+                .parse::<u32>()
+                .map_err(|_| ParseToolchainError::Label(label.to_string()))?;
+            (name, Some(n))
+        }
+        None => (label, None),
+    };
+
+    let channel = match name {
+        "beta" => Channel::Beta(Beta {
+            version,
+            prerelease: n,
+        }),
+        // An unnumbered `alpha` label denotes the first alpha pre-release
+        "alpha" => Channel::Alpha(Alpha::prerelease(version, n.unwrap_or(1))),
+        _ => return Err(ParseToolchainError::Label(label.to_string())),
+    };
+
+    Ok((channel, rest))
+}
+
+/// Parses an optional `-YYYY-MM-DD` date at the start of the input.
+fn parse_date(input: &str) -> (Option<Date>, &str) {
+    let date = input
+        .strip_prefix('-')
+        .and_then(|rest| rest.get(..10).map(|ymd| (ymd, &rest[10..])))
+        .filter(|(_, rest)| rest.is_empty() || rest.starts_with('-'))
+        .and_then(|(ymd, rest)| Date::parse_ymd(ymd).map(|date| (date, rest)));
+
+    match date {
+        Some((date, rest)) => (Some(date), rest),
+        None => (None, input),
+    }
+}
+
+/// Parses the optional `-<host>` target triple which may complete a toolchain specifier.
+pub(crate) fn parse_host(input: &str) -> Result<Option<Target>, ParseToolchainError> {
+    if input.is_empty() {
+        return Ok(None);
+    }
+
+    match input.strip_prefix('-') {
+        Some(triple) if !triple.is_empty() => Target::try_from_target_triple(triple)
+            .map(Some)
+            .map_err(|err| ParseToolchainError::Host(triple.to_string(), err)),
+        _ => Err(ParseToolchainError::Unexpected(input.to_string())),
+    }
+}
+
+/// Strips `segment` from the start of the input, if it is followed by the end of the input, or
+/// by a `-` separator.
+fn strip_segment<'a>(input: &'a str, segment: &str) -> Option<&'a str> {
+    input
+        .strip_prefix(segment)
+        .filter(|rest| rest.is_empty() || rest.starts_with('-'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use yare::parameterized;
+
+    fn target(triple: &str) -> Option<Target> {
+        Some(Target::try_from_target_triple(triple).unwrap())
+    }
+
+    #[parameterized(
+        stable = { "stable", ChannelSpec::Stable, None, None },
+        beta = { "beta", ChannelSpec::Beta, None, None },
+        nightly = { "nightly", ChannelSpec::Nightly, None, None },
+        partial = { "1.70", ChannelSpec::Partial(PartialRustVersion::new(1, 70)), None, None },
+        full = { "1.70.0", ChannelSpec::Exact(Channel::stable(RustVersion::new(1, 70, 0))), None, None },
+        dated_stable = { "stable-2023-06-01", ChannelSpec::Stable, Some(Date::new(2023, 6, 1)), None },
+        dated_beta = { "beta-2024-01-05", ChannelSpec::Beta, Some(Date::new(2024, 1, 5)), None },
+        dated_nightly_with_host = { "nightly-2024-02-01-x86_64-unknown-linux-gnu", ChannelSpec::Nightly, Some(Date::new(2024, 2, 1)), target("x86_64-unknown-linux-gnu") },
+        full_with_host = { "1.82.0-aarch64-apple-darwin", ChannelSpec::Exact(Channel::stable(RustVersion::new(1, 82, 0))), None, target("aarch64-apple-darwin") },
+        partial_with_host = { "1.70-x86_64-pc-windows-msvc", ChannelSpec::Partial(PartialRustVersion::new(1, 70)), None, target("x86_64-pc-windows-msvc") },
+        symbolic_with_host = { "stable-x86_64-pc-windows-gnu", ChannelSpec::Stable, None, target("x86_64-pc-windows-gnu") },
+        beta_prerelease = { "1.71.0-beta.2", ChannelSpec::Exact(Channel::Beta(Beta { version: RustVersion::new(1, 71, 0), prerelease: Some(2) })), None, None },
+        beta_unnumbered_with_host = { "1.71.0-beta-x86_64-unknown-linux-gnu", ChannelSpec::Exact(Channel::beta(RustVersion::new(1, 71, 0))), None, target("x86_64-unknown-linux-gnu") },
+        alpha = { "1.0.0-alpha", ChannelSpec::Exact(Channel::Alpha(Alpha::prerelease(RustVersion::new(1, 0, 0), 1))), None, None },
+        alpha_n = { "1.0.0-alpha.2", ChannelSpec::Exact(Channel::Alpha(Alpha::prerelease(RustVersion::new(1, 0, 0), 2))), None, None },
+        pre_one = { "0.12.0", ChannelSpec::Exact(Channel::alpha(RustVersion::new(0, 12, 0))), None, None },
+    )]
+    fn parse(input: &str, channel: ChannelSpec, date: Option<Date>, host: Option<Target>) {
+        let spec = input.parse::<ToolchainSpec>().unwrap();
+
+        assert_eq!(spec, ToolchainSpec::new(channel, date, host));
+        assert_eq!(spec.to_string(), input);
+    }
+
+    #[parameterized(
+        empty = { "" },
+        unknown_channel = { "unstable" },
+        symbolic_prefix = { "stablex" },
+        invalid_version = { "1.x.0" },
+        leading_zero = { "1.07.0" },
+        unknown_label = { "1.70.0-rc.1" },
+        invalid_prerelease = { "1.70.0-beta.x" },
+        dangling_separator = { "stable-" },
+        invalid_host = { "stable-not-a-target" },
+    )]
+    fn parse_rejects(input: &str) {
+        assert!(input.parse::<ToolchainSpec>().is_err());
+    }
+
+    #[test]
+    fn into_toolchain() {
+        let spec = "nightly-2024-02-01-x86_64-unknown-linux-gnu"
+            .parse::<ToolchainSpec>()
+            .unwrap();
+
+        let toolchain = Toolchain::try_from(spec).unwrap();
+
+        assert_eq!(
+            toolchain.channel(),
+            &Channel::nightly(Date::new(2024, 2, 1))
+        );
+        assert_eq!(toolchain.date(), Some(&Date::new(2024, 2, 1)));
+        assert_eq!(
+            toolchain.host(),
+            &Target::try_from_target_triple("x86_64-unknown-linux-gnu").unwrap()
+        );
+    }
+
+    #[parameterized(
+        official = { "1.70.0", ChannelSpec::Exact(Channel::stable(RustVersion::new(1, 70, 0))) },
+        custom = { "stage2", ChannelSpec::Custom(Custom::Named("stage2".to_string())) },
+        custom_with_separator = { "my-patched-toolchain", ChannelSpec::Custom(Custom::Named("my-patched-toolchain".to_string())) },
+    )]
+    fn parse_or_custom(input: &str, expected: ChannelSpec) {
+        let spec = ToolchainSpec::parse_or_custom(input).unwrap();
+
+        assert_eq!(spec.channel(), &expected);
+        assert_eq!(spec.to_string(), input);
+    }
+
+    #[test]
+    fn parse_or_custom_rejects_invalid_version() {
+        assert!(ToolchainSpec::parse_or_custom("1.x.0").is_err());
+    }
+
+    #[test]
+    fn custom_into_toolchain() {
+        let custom = Custom::Named("stage2".to_string());
+        let spec = ToolchainSpec::custom(custom.clone());
+
+        let toolchain = Toolchain::try_from(spec.clone()).unwrap();
+
+        assert_eq!(toolchain.channel(), &Channel::Custom(custom));
+        assert_eq!(toolchain.host(), &Target::host());
+        assert_eq!(ToolchainSpec::from(&toolchain), spec);
+    }
+
+    #[test]
+    fn into_toolchain_defaults_to_host() {
+        let spec = "1.70.0".parse::<ToolchainSpec>().unwrap();
+
+        let toolchain = Toolchain::try_from(spec).unwrap();
+
+        assert_eq!(toolchain.host(), &Target::host());
+    }
+
+    #[parameterized(
+        stable = { "stable" },
+        dated_beta = { "beta-2024-01-05" },
+        nightly = { "nightly" },
+        partial = { "1.70" },
+    )]
+    fn into_toolchain_unresolved(input: &str) {
+        let spec = input.parse::<ToolchainSpec>().unwrap();
+
+        assert!(matches!(
+            Toolchain::try_from(spec),
+            Err(ParseToolchainError::Unresolved(_))
+        ));
+    }
+}
@@ -1,6 +1,12 @@
+use std::cmp::Ordering;
 use std::fmt;
 use std::str::FromStr;
 
+mod platform_support;
+
+pub use platform_support::{PlatformSupport, Tier};
+pub use target_lexicon::{Architecture, Environment, OperatingSystem, PointerWidth, Vendor};
+
 /// A target platform
 ///
 /// Commonly represented as a [`target triple`]. A target triple consists of three (or four) components: the
@@ -22,6 +28,9 @@ use std::str::FromStr;
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct Target {
     target: target_lexicon::Triple,
+    // The triple as given, if it is not recognized by `target_lexicon`, or if `target_lexicon`
+    // would not print it in the same way.
+    name: Option<String>,
 }
 
 impl Target {
@@ -31,6 +40,7 @@ impl Target {
     pub const fn host() -> Self {
         Self {
             target: target_lexicon::HOST,
+            name: None,
         }
     }
 
@@ -42,11 +52,12 @@ impl Target {
     pub fn try_from_target_triple(triple: &str) -> Result<Self, ParseError> {
         let platform = target_lexicon::Triple::from_str(triple).map_err(ParseError::from)?;
 
-        Ok(Self { target: platform })
+        Ok(Self::with_name(platform, triple))
     }
 
-    /// Create a new `Target` instance from a [`target triple`], defaults to
-    /// `unknown-unknown-unknown` if the give triple is not recognized.
+    /// Create a new `Target` instance from a [`target triple`]. If the triple is not recognized,
+    /// its components are `unknown`, i.e. the accessors return the `unknown` variants, while the
+    /// triple is retained as-is, and used by the `Display` implementation.
     ///
     /// * See also: [Rustc platform support](https://doc.rust-lang.org/rustc/platform-support.html)
     ///
@@ -55,13 +66,229 @@ impl Target {
         let platform = target_lexicon::Triple::from_str(triple)
             .unwrap_or_else(|_| target_lexicon::Triple::unknown());
 
-        Self { target: platform }
+        Self::with_name(platform, triple)
+    }
+
+    fn with_name(target: target_lexicon::Triple, triple: &str) -> Self {
+        let name = (target.to_string() != triple).then(|| triple.to_string());
+
+        Self { target, name }
+    }
+
+    /// Whether all components of the target triple were recognized
+    pub fn is_recognized(&self) -> bool {
+        self.target != target_lexicon::Triple::unknown()
+    }
+
+    /// The architecture of the target, e.g. `x86_64` or `aarch64`
+    pub fn arch(&self) -> Architecture {
+        self.target.architecture
+    }
+
+    /// The vendor of the target, e.g. `pc`, `apple` or `unknown`
+    pub fn vendor(&self) -> Vendor {
+        self.target.vendor.clone()
+    }
+
+    /// The operating system of the target, e.g. `linux`, `windows` or `darwin`
+    pub fn os(&self) -> OperatingSystem {
+        self.target.operating_system
+    }
+
+    /// The environment of the target, e.g. `gnu`, `msvc` or `musl`
+    pub fn env(&self) -> Environment {
+        self.target.environment
+    }
+
+    /// The pointer width of the target, if it is known
+    pub fn pointer_width(&self) -> Option<PointerWidth> {
+        self.target.pointer_width().ok()
+    }
+
+    /// Whether the target runs on Windows
+    pub fn is_windows(&self) -> bool {
+        self.os() == OperatingSystem::Windows
+    }
+
+    /// Whether the target runs on Linux
+    pub fn is_linux(&self) -> bool {
+        self.os() == OperatingSystem::Linux
+    }
+
+    /// Whether the target runs on an Apple operating system, like macOS or iOS
+    pub fn is_apple(&self) -> bool {
+        self.os().is_like_darwin()
+    }
+
+    /// Whether the target uses the `musl` C library
+    pub fn is_musl(&self) -> bool {
+        matches!(
+            self.env(),
+            Environment::Musl
+                | Environment::Musleabi
+                | Environment::Musleabihf
+                | Environment::Muslabi64
+        )
+    }
+
+    /// Whether the target is a WebAssembly target
+    pub fn is_wasm(&self) -> bool {
+        matches!(self.arch(), Architecture::Wasm32 | Architecture::Wasm64)
+    }
+
+    /// Whether the target has a pointer width of 32 bits
+    pub fn is_32bit(&self) -> bool {
+        self.pointer_width() == Some(PointerWidth::U32)
+    }
+
+    /// Whether the target has a pointer width of 64 bits
+    pub fn is_64bit(&self) -> bool {
+        self.pointer_width() == Some(PointerWidth::U64)
+    }
+
+    /// The platform support metadata of the target, if the target is part of the built-in
+    /// table. The table covers all tier 1 targets, but only a selection of the tier 2 and
+    /// tier 3 targets, so `None` does not imply that the target is unsupported.
+    ///
+    /// * See also: [Rustc platform support](https://doc.rust-lang.org/rustc/platform-support.html)
+    pub fn platform_support(&self) -> Option<&'static PlatformSupport> {
+        match &self.name {
+            Some(name) => PlatformSupport::find(name),
+            None => PlatformSupport::find_triple(&self.target),
+        }
+    }
+
+    /// The tier of the target, if the target is part of the built-in table.
+    ///
+    /// See [`Target::platform_support`].
+    pub fn tier(&self) -> Option<Tier> {
+        self.platform_support().and_then(PlatformSupport::tier)
+    }
+}
+
+impl FromStr for Target {
+    type Err = ParseError;
+
+    /// Parses a target triple.
+    ///
+    /// Unlike [`Target::try_from_target_triple`], triples which are not recognized, like
+    /// non-canonical rustup triples, are accepted, and round-trip through the `Display`
+    /// implementation. Only triples with empty components, or which contain whitespace,
+    /// are rejected.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let is_malformed =
+            s.is_empty() || s.contains(char::is_whitespace) || s.split('-').any(str::is_empty);
+
+        if is_malformed {
+            return Err(ParseError::Malformed(s.to_string()));
+        }
+
+        Ok(Self::from_target_triple_or_unknown(s))
+    }
+}
+
+impl PartialOrd for Target {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Target {
+    /// Targets are ordered by their triple, as printed by the `Display` implementation.
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (&self.name, &other.name) {
+            (Some(lhs), Some(rhs)) => lhs.cmp(rhs),
+            (None, Some(rhs)) => cmp_printed(self, rhs.as_bytes()),
+            (Some(lhs), None) => cmp_printed(other, lhs.as_bytes()).reverse(),
+            (None, None) => {
+                // Canonical triples are short, so one of them is printed on the stack
+                let mut printed = Printed::default();
+
+                match fmt::write(&mut printed, format_args!("{}", other.target)) {
+                    Ok(()) => cmp_printed(self, printed.as_bytes()),
+                    Err(_) => self.to_string().cmp(&other.to_string()),
+                }
+            }
+        }
+    }
+}
+
+// Compares the target, as printed by the `Display` implementation, with the given triple,
+// while it is being printed
+fn cmp_printed(target: &Target, triple: &[u8]) -> Ordering {
+    struct Compare<'a> {
+        rest: &'a [u8],
+        ordering: Ordering,
+    }
+
+    impl fmt::Write for Compare<'_> {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            if self.ordering == Ordering::Equal {
+                let len = s.len().min(self.rest.len());
+
+                self.ordering = s.as_bytes()[..len]
+                    .cmp(&self.rest[..len])
+                    .then(s.len().cmp(&len));
+                self.rest = &self.rest[len..];
+            }
+
+            Ok(())
+        }
+    }
+
+    let mut compare = Compare {
+        rest: triple,
+        ordering: Ordering::Equal,
+    };
+    let _ = fmt::write(&mut compare, format_args!("{}", target));
+
+    // If the target is a prefix of the triple, it precedes it
+    compare.ordering.then(if compare.rest.is_empty() {
+        Ordering::Equal
+    } else {
+        Ordering::Less
+    })
+}
+
+// A fixed size buffer for a printed triple
+struct Printed {
+    bytes: [u8; 64],
+    len: usize,
+}
+
+impl Default for Printed {
+    fn default() -> Self {
+        Self {
+            bytes: [0; 64],
+            len: 0,
+        }
+    }
+}
+
+impl Printed {
+    fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+impl fmt::Write for Printed {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        let buffer = self.bytes.get_mut(self.len..end).ok_or(fmt::Error)?;
+
+        buffer.copy_from_slice(s.as_bytes());
+        self.len = end;
+
+        Ok(())
     }
 }
 
 impl fmt::Display for Target {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.target)
+        match &self.name {
+            Some(name) => f.write_str(name),
+            None => write!(f, "{}", self.target),
+        }
     }
 }
 
@@ -81,6 +308,8 @@ pub enum ParseError {
     BinaryFormat(String),
     #[error("Unknown field `{0}`")]
     Field(String),
+    #[error("Malformed target triple `{0}`")]
+    Malformed(String),
 }
 
 impl From<target_lexicon::ParseError> for ParseError {
@@ -108,15 +337,142 @@ mod tests {
 
         let expected = Target {
             target: target_lexicon::HOST,
+            name: None,
         };
 
         assert_eq!(this_platform, expected);
     }
 
+    #[test]
+    fn tier() {
+        let target = Target::try_from_target_triple("aarch64-apple-darwin").unwrap();
+        let unknown = Target::from_target_triple_or_unknown("make it unknown");
+
+        assert_eq!(target.tier(), Some(Tier::One));
+        assert!(unknown.platform_support().is_none());
+    }
+
     #[test]
     fn to_string() {
         let target = Target::try_from_target_triple("x86_64-unknown-linux-gnu").unwrap();
 
         assert_eq!(target.to_string(), "x86_64-unknown-linux-gnu");
     }
+
+    #[yare::parameterized(
+        canonical = { "x86_64-unknown-linux-gnu" },
+        non_canonical = { "x86_64-linux" },
+        unrecognized = { "x86_64-pc-nto-qnx710" },
+    )]
+    fn parse_round_trips(triple: &str) {
+        let target = triple.parse::<Target>().unwrap();
+
+        assert_eq!(target.to_string(), triple);
+        assert_eq!(target.to_string().parse::<Target>().unwrap(), target);
+    }
+
+    #[test]
+    fn unrecognized_is_not_flattened() {
+        let qnx = Target::from_target_triple_or_unknown("x86_64-pc-nto-qnx710");
+        let unknown = Target::from_target_triple_or_unknown("make it unknown");
+
+        assert!(!qnx.is_recognized());
+        assert_eq!(qnx.arch(), Architecture::Unknown);
+        assert_ne!(qnx, unknown);
+    }
+
+    #[yare::parameterized(
+        empty = { "" },
+        whitespace = { "x86_64 unknown-linux-gnu" },
+        empty_component = { "x86_64--linux-gnu" },
+    )]
+    fn parse_rejects(triple: &str) {
+        assert!(triple.parse::<Target>().is_err());
+    }
+
+    #[test]
+    fn accessors() {
+        let target = Target::try_from_target_triple("i686-pc-windows-msvc").unwrap();
+
+        assert_eq!(
+            target.arch(),
+            Architecture::X86_32(target_lexicon::X86_32Architecture::I686)
+        );
+        assert_eq!(target.vendor(), Vendor::Pc);
+        assert_eq!(target.os(), OperatingSystem::Windows);
+        assert_eq!(target.env(), Environment::Msvc);
+        assert_eq!(target.pointer_width(), Some(PointerWidth::U32));
+        assert!(target.is_recognized());
+    }
+
+    #[yare::parameterized(
+        windows = { "x86_64-pc-windows-msvc", Target::is_windows },
+        linux = { "aarch64-unknown-linux-gnu", Target::is_linux },
+        macos = { "aarch64-apple-darwin", Target::is_apple },
+        ios = { "aarch64-apple-ios", Target::is_apple },
+        musl = { "x86_64-unknown-linux-musl", Target::is_musl },
+        musleabihf = { "armv7-unknown-linux-musleabihf", Target::is_musl },
+        wasm = { "wasm32-unknown-unknown", Target::is_wasm },
+        bit32 = { "armv7-unknown-linux-gnueabihf", Target::is_32bit },
+        bit64 = { "x86_64-unknown-linux-gnu", Target::is_64bit },
+    )]
+    fn predicates(triple: &str, predicate: fn(&Target) -> bool) {
+        let target = Target::try_from_target_triple(triple).unwrap();
+
+        assert!(predicate(&target));
+    }
+
+    #[test]
+    fn predicates_do_not_match_other_targets() {
+        let target = Target::try_from_target_triple("x86_64-unknown-linux-gnu").unwrap();
+
+        assert!(!target.is_windows());
+        assert!(!target.is_apple());
+        assert!(!target.is_musl());
+        assert!(!target.is_wasm());
+        assert!(!target.is_32bit());
+    }
+
+    #[test]
+    fn ord() {
+        let mut targets = [
+            "x86_64-unknown-linux-gnu",
+            "aarch64-apple-darwin",
+            "x86_64-linux",
+        ]
+        .iter()
+        .map(|triple| triple.parse::<Target>().unwrap())
+        .collect::<Vec<_>>();
+
+        targets.sort();
+
+        let sorted = targets.iter().map(ToString::to_string).collect::<Vec<_>>();
+
+        assert_eq!(
+            sorted,
+            [
+                "aarch64-apple-darwin",
+                "x86_64-linux",
+                "x86_64-unknown-linux-gnu"
+            ]
+        );
+    }
+
+    #[yare::parameterized(
+        canonical = { "x86_64-unknown-linux-gnu", "x86_64-unknown-linux-musl" },
+        canonical_prefix = { "x86_64-unknown-linux-gnu", "x86_64-unknown-linux-gnux32" },
+        named = { "x86_64-linux", "x86_64-linux-gnu" },
+        named_and_canonical = { "x86_64-linux", "x86_64-unknown-linux-gnu" },
+        canonical_and_named = { "aarch64-apple-darwin", "x86_64-linux" },
+        named_prefix_of_canonical = { "wasm32", "wasm32-unknown-unknown" },
+        equal = { "x86_64-unknown-linux-gnu", "x86_64-unknown-linux-gnu" },
+    )]
+    fn ord_matches_printed_triples(lhs: &str, rhs: &str) {
+        let lhs = lhs.parse::<Target>().unwrap();
+        let rhs = rhs.parse::<Target>().unwrap();
+        let printed = lhs.to_string().cmp(&rhs.to_string());
+
+        assert_eq!(lhs.cmp(&rhs), printed);
+        assert_eq!(rhs.cmp(&lhs), printed.reverse());
+    }
 }
@@ -0,0 +1,195 @@
+use crate::RustVersion;
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::OnceLock;
+use target_lexicon::Triple;
+
+mod table;
+
+use table::PLATFORM_SUPPORT;
+
+/// The [`tier`] of a target, which describes the guarantees the Rust project makes about it.
+///
+/// [`tier`]: https://doc.rust-lang.org/rustc/platform-support.html
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Tier {
+    /// Tier 1 targets are guaranteed to build and pass tests, and ship with host tools
+    One,
+    /// Tier 2 targets are guaranteed to build, and ship with host tools like `rustc` and `cargo`
+    TwoWithHostTools,
+    /// Tier 2 targets are guaranteed to build; their standard library is shipped, if available
+    Two,
+    /// Tier 3 targets are supported by the codebase, but not built or shipped
+    Three,
+}
+
+impl fmt::Display for Tier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Tier::One => "tier 1",
+            Tier::TwoWithHostTools => "tier 2 with host tools",
+            Tier::Two => "tier 2",
+            Tier::Three => "tier 3",
+        })
+    }
+}
+
+/// Platform support metadata of a target, as documented by [`rustc platform support`].
+///
+/// The built-in table is generated by `resources/platform_support/generate.py`, from the target
+/// specifications of a nightly `rustc` and the `rust-std` components of the release manifests.
+/// Regenerate it with `just generate-platform-support`.
+///
+/// [`rustc platform support`]: https://doc.rust-lang.org/rustc/platform-support.html
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlatformSupport {
+    triple: &'static str,
+    tier: Option<Tier>,
+    std: bool,
+    first_shipped: Option<(u64, u64, u64)>,
+    last_shipped: Option<(u64, u64, u64)>,
+}
+
+impl PlatformSupport {
+    /// The platform support metadata of all known targets, sorted by triple.
+    ///
+    /// The built-in table covers all targets supported by `rustc`, and the targets of which the
+    /// standard library was shipped, but which are no longer supported.
+    pub fn all() -> &'static [PlatformSupport] {
+        PLATFORM_SUPPORT
+    }
+
+    /// Find the platform support metadata of a target triple, or `None` if the target is not
+    /// part of the built-in table.
+    pub fn find(triple: &str) -> Option<&'static PlatformSupport> {
+        PLATFORM_SUPPORT
+            .binary_search_by(|entry| entry.triple.cmp(triple))
+            .ok()
+            .map(|index| &PLATFORM_SUPPORT[index])
+    }
+
+    // Find the platform support metadata of a recognized target triple, without formatting it
+    pub(crate) fn find_triple(triple: &Triple) -> Option<&'static PlatformSupport> {
+        static BY_TRIPLE: OnceLock<HashMap<Triple, &'static PlatformSupport>> = OnceLock::new();
+
+        BY_TRIPLE
+            .get_or_init(|| {
+                PLATFORM_SUPPORT
+                    .iter()
+                    .filter_map(|entry| {
+                        let triple = Triple::from_str(entry.triple).ok()?;
+                        (triple.to_string() == entry.triple).then_some((triple, entry))
+                    })
+                    .collect()
+            })
+            .get(triple)
+            .copied()
+    }
+
+    /// The target triple
+    pub fn triple(&self) -> &'static str {
+        self.triple
+    }
+
+    /// The current tier of the target, or `None` if the target is no longer supported by `rustc`
+    pub fn tier(&self) -> Option<Tier> {
+        self.tier
+    }
+
+    /// Whether the standard library is available for the target, as opposed to
+    /// only `core` (and `alloc`)
+    pub fn has_std(&self) -> bool {
+        self.std
+    }
+
+    /// The first release which shipped the standard library of this target, or `None` if it
+    /// was never shipped
+    ///
+    /// Before Rust 1.8.0, which is the first release with a release manifest, only the hosts for
+    /// which a `rustc` was shipped are known.
+    pub fn first_shipped(&self) -> Option<RustVersion> {
+        self.first_shipped
+            .map(|(major, minor, patch)| RustVersion::new(major, minor, patch))
+    }
+
+    /// The last release which shipped the standard library of this target, or `None` if it
+    /// is still shipped, or was never shipped
+    pub fn last_shipped(&self) -> Option<RustVersion> {
+        self.last_shipped
+            .map(|(major, minor, patch)| RustVersion::new(major, minor, patch))
+    }
+
+    /// Whether the standard library of this target was shipped with the given release, i.e.
+    /// whether the release lies between the first and last release which shipped it
+    pub fn is_shipped_in(&self, version: RustVersion) -> bool {
+        let after_first = self.first_shipped().is_some_and(|first| version >= first);
+        let before_last = self.last_shipped().is_none_or(|last| version <= last);
+
+        after_first && before_last
+    }
+}
+
+const fn entry(
+    triple: &'static str,
+    tier: Option<Tier>,
+    std: bool,
+    first_shipped: Option<(u64, u64, u64)>,
+    last_shipped: Option<(u64, u64, u64)>,
+) -> PlatformSupport {
+    PlatformSupport {
+        triple,
+        tier,
+        std,
+        first_shipped,
+        last_shipped,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Target;
+    use yare::parameterized;
+
+    #[test]
+    fn triples_are_sorted_and_parse() {
+        for entry in PLATFORM_SUPPORT {
+            let target = entry.triple().parse::<Target>().unwrap();
+
+            assert_eq!(target.to_string(), entry.triple());
+            assert_eq!(target.platform_support(), Some(entry));
+        }
+
+        assert!(PLATFORM_SUPPORT
+            .windows(2)
+            .all(|pair| pair[0].triple() < pair[1].triple()));
+    }
+
+    #[parameterized(
+        tier1 = { "x86_64-unknown-linux-gnu", Some(Tier::One) },
+        tier2_host = { "x86_64-apple-darwin", Some(Tier::TwoWithHostTools) },
+        tier2_host_demoted = { "i686-pc-windows-gnu", Some(Tier::TwoWithHostTools) },
+        tier2 = { "wasm32-unknown-unknown", Some(Tier::Two) },
+        tier3 = { "x86_64-unknown-haiku", Some(Tier::Three) },
+        non_canonical = { "thumbv8m.main-none-eabi", Some(Tier::Two) },
+        removed = { "wasm32-wasi", None },
+    )]
+    fn tier(triple: &str, expected: Option<Tier>) {
+        assert_eq!(PlatformSupport::find(triple).unwrap().tier(), expected);
+    }
+
+    #[parameterized(
+        before_first = { "wasm32-wasip1", RustVersion::new(1, 78, 0), false },
+        first = { "wasm32-wasip1", RustVersion::new(1, 79, 0), true },
+        last = { "wasm32-wasi", RustVersion::new(1, 83, 0), true },
+        after_last = { "wasm32-wasi", RustVersion::new(1, 84, 0), false },
+        never = { "x86_64-unknown-haiku", RustVersion::new(1, 80, 0), false },
+        removed = { "i686-apple-darwin", RustVersion::new(1, 42, 0), false },
+    )]
+    fn is_shipped_in(triple: &str, version: RustVersion, expected: bool) {
+        let entry = PlatformSupport::find(triple).unwrap();
+
+        assert_eq!(entry.is_shipped_in(version), expected);
+    }
+}
@@ -0,0 +1,334 @@
+// @generated by resources/platform_support/generate.py; do not edit.
+//
+// Tiers from the target specifications of rustc 1.97.0-nightly (e50aa6fba 2026-05-19).
+// Shipped releases from the release manifests up to Rust 1.99.0.
+
+use super::{entry, PlatformSupport, Tier};
+
+#[rustfmt::skip]
+pub(super) const PLATFORM_SUPPORT: &[PlatformSupport] = &[
+    entry("aarch64-apple-darwin", Some(Tier::One), true, Some((1, 56, 0)), None),
+    entry("aarch64-apple-ios", Some(Tier::Two), true, Some((1, 47, 0)), Some((1, 98, 0))),
+    entry("aarch64-apple-ios-macabi", Some(Tier::Two), true, Some((1, 87, 0)), Some((1, 90, 0))),
+    entry("aarch64-apple-ios-sim", Some(Tier::Two), true, Some((1, 60, 0)), Some((1, 98, 0))),
+    entry("aarch64-apple-tvos", Some(Tier::Two), true, None, None),
+    entry("aarch64-apple-tvos-sim", Some(Tier::Two), true, None, None),
+    entry("aarch64-apple-visionos", Some(Tier::Two), true, None, None),
+    entry("aarch64-apple-visionos-sim", Some(Tier::Two), true, None, None),
+    entry("aarch64-apple-watchos", Some(Tier::Two), true, None, None),
+    entry("aarch64-apple-watchos-sim", Some(Tier::Two), true, None, None),
+    entry("aarch64-kmc-solid_asp3", Some(Tier::Three), true, None, None),
+    entry("aarch64-linux-android", Some(Tier::Two), true, Some((1, 47, 0)), None),
+    entry("aarch64-nintendo-switch-freestanding", Some(Tier::Three), false, None, None),
+    entry("aarch64-pc-windows-gnullvm", Some(Tier::TwoWithHostTools), true, Some((1, 85, 0)), Some((1, 95, 0))),
+    entry("aarch64-pc-windows-msvc", Some(Tier::One), true, Some((1, 69, 0)), Some((1, 98, 0))),
+    entry("aarch64-unknown-freebsd", Some(Tier::Three), true, None, None),
+    entry("aarch64-unknown-fuchsia", Some(Tier::Two), true, Some((1, 87, 0)), Some((1, 95, 0))),
+    entry("aarch64-unknown-helenos", Some(Tier::Three), true, None, None),
+    entry("aarch64-unknown-hermit", Some(Tier::Three), true, None, None),
+    entry("aarch64-unknown-illumos", Some(Tier::Three), true, None, None),
+    entry("aarch64-unknown-linux-gnu", Some(Tier::One), true, Some((1, 41, 0)), None),
+    entry("aarch64-unknown-linux-gnu_ilp32", Some(Tier::Three), true, None, None),
+    entry("aarch64-unknown-linux-musl", Some(Tier::TwoWithHostTools), true, Some((1, 60, 0)), None),
+    entry("aarch64-unknown-linux-ohos", Some(Tier::TwoWithHostTools), true, Some((1, 82, 0)), Some((1, 93, 0))),
+    entry("aarch64-unknown-managarm-mlibc", Some(Tier::Three), false, None, None),
+    entry("aarch64-unknown-netbsd", Some(Tier::Three), true, None, None),
+    entry("aarch64-unknown-none", Some(Tier::Two), false, Some((1, 60, 0)), Some((1, 98, 0))),
+    entry("aarch64-unknown-none-softfloat", Some(Tier::Two), false, Some((1, 85, 0)), Some((1, 98, 0))),
+    entry("aarch64-unknown-nto-qnx700", Some(Tier::Three), true, None, None),
+    entry("aarch64-unknown-nto-qnx710", Some(Tier::Three), true, None, None),
+    entry("aarch64-unknown-nto-qnx710_iosock", Some(Tier::Three), true, None, None),
+    entry("aarch64-unknown-nto-qnx800", Some(Tier::Three), true, None, None),
+    entry("aarch64-unknown-nuttx", Some(Tier::Three), true, None, None),
+    entry("aarch64-unknown-openbsd", Some(Tier::Three), true, None, None),
+    entry("aarch64-unknown-redox", Some(Tier::Three), false, None, None),
+    entry("aarch64-unknown-teeos", Some(Tier::Three), false, None, None),
+    entry("aarch64-unknown-trusty", Some(Tier::Three), true, None, None),
+    entry("aarch64-unknown-uefi", Some(Tier::Two), false, Some((1, 80, 0)), Some((1, 98, 0))),
+    entry("aarch64-uwp-windows-msvc", Some(Tier::Three), false, None, None),
+    entry("aarch64-wrs-vxworks", Some(Tier::Three), true, None, None),
+    entry("aarch64_be-unknown-hermit", Some(Tier::Three), true, None, None),
+    entry("aarch64_be-unknown-linux-gnu", Some(Tier::Three), true, None, None),
+    entry("aarch64_be-unknown-linux-gnu_ilp32", Some(Tier::Three), true, None, None),
+    entry("aarch64_be-unknown-linux-musl", Some(Tier::Three), true, None, None),
+    entry("aarch64_be-unknown-netbsd", Some(Tier::Three), true, None, None),
+    entry("aarch64_be-unknown-none-softfloat", Some(Tier::Three), false, None, None),
+    entry("aarch64v8r-unknown-none", Some(Tier::Three), false, None, None),
+    entry("aarch64v8r-unknown-none-softfloat", Some(Tier::Three), false, None, None),
+    entry("amdgcn-amd-amdhsa", Some(Tier::Three), false, None, None),
+    entry("arm-linux-androideabi", Some(Tier::Two), true, Some((1, 64, 0)), Some((1, 90, 0))),
+    entry("arm-unknown-linux-gnueabi", Some(Tier::TwoWithHostTools), true, Some((1, 66, 0)), Some((1, 93, 0))),
+    entry("arm-unknown-linux-gnueabihf", Some(Tier::TwoWithHostTools), true, Some((1, 80, 0)), Some((1, 96, 0))),
+    entry("arm-unknown-linux-musleabi", Some(Tier::Two), true, Some((1, 85, 0)), Some((1, 87, 0))),
+    entry("arm-unknown-linux-musleabihf", Some(Tier::Two), true, Some((1, 72, 0)), Some((1, 90, 0))),
+    entry("arm64_32-apple-watchos", Some(Tier::Three), true, None, None),
+    entry("arm64e-apple-darwin", Some(Tier::Three), true, None, None),
+    entry("arm64e-apple-ios", Some(Tier::Three), true, None, None),
+    entry("arm64e-apple-tvos", Some(Tier::Three), true, None, None),
+    entry("arm64ec-pc-windows-msvc", Some(Tier::Two), true, Some((1, 85, 0)), Some((1, 95, 0))),
+    entry("armeb-unknown-linux-gnueabi", Some(Tier::Three), true, None, None),
+    entry("armebv7r-none-eabi", Some(Tier::Three), false, Some((1, 87, 0)), Some((1, 87, 0))),
+    entry("armebv7r-none-eabihf", Some(Tier::Three), false, Some((1, 87, 0)), Some((1, 87, 0))),
+    entry("armv4t-none-eabi", Some(Tier::Three), false, None, None),
+    entry("armv4t-unknown-linux-gnueabi", Some(Tier::Three), true, None, None),
+    entry("armv5te-none-eabi", Some(Tier::Three), false, None, None),
+    entry("armv5te-unknown-linux-gnueabi", Some(Tier::Two), true, Some((1, 85, 0)), Some((1, 93, 0))),
+    entry("armv5te-unknown-linux-musleabi", Some(Tier::Two), true, Some((1, 85, 0)), Some((1, 87, 0))),
+    entry("armv5te-unknown-linux-uclibceabi", Some(Tier::Three), true, None, None),
+    entry("armv6-none-eabi", Some(Tier::Three), false, None, None),
+    entry("armv6-none-eabihf", Some(Tier::Three), false, None, None),
+    entry("armv6-unknown-freebsd", Some(Tier::Three), true, None, None),
+    entry("armv6-unknown-netbsd-eabihf", Some(Tier::Three), true, None, None),
+    entry("armv6k-nintendo-3ds", Some(Tier::Three), false, None, None),
+    entry("armv7-linux-androideabi", Some(Tier::Two), true, Some((1, 47, 0)), Some((1, 97, 0))),
+    entry("armv7-rtems-eabihf", Some(Tier::Three), true, None, None),
+    entry("armv7-sony-vita-newlibeabihf", Some(Tier::Three), true, None, None),
+    entry("armv7-unknown-freebsd", Some(Tier::Three), true, None, None),
+    entry("armv7-unknown-linux-gnueabi", Some(Tier::Two), true, Some((1, 75, 0)), Some((1, 90, 0))),
+    entry("armv7-unknown-linux-gnueabihf", Some(Tier::TwoWithHostTools), true, Some((1, 53, 0)), Some((1, 95, 0))),
+    entry("armv7-unknown-linux-musleabi", Some(Tier::Two), true, Some((1, 85, 0)), Some((1, 90, 0))),
+    entry("armv7-unknown-linux-musleabihf", Some(Tier::Two), true, Some((1, 85, 0)), Some((1, 93, 0))),
+    entry("armv7-unknown-linux-ohos", Some(Tier::Two), true, Some((1, 82, 0)), Some((1, 87, 0))),
+    entry("armv7-unknown-linux-uclibceabi", Some(Tier::Three), true, None, None),
+    entry("armv7-unknown-linux-uclibceabihf", Some(Tier::Three), true, None, None),
+    entry("armv7-unknown-netbsd-eabihf", Some(Tier::Three), true, None, None),
+    entry("armv7-unknown-trusty", Some(Tier::Three), true, None, None),
+    entry("armv7-wrs-vxworks-eabihf", Some(Tier::Three), false, None, None),
+    entry("armv7a-kmc-solid_asp3-eabi", Some(Tier::Three), true, None, None),
+    entry("armv7a-kmc-solid_asp3-eabihf", Some(Tier::Three), true, None, None),
+    entry("armv7a-none-eabi", Some(Tier::Two), false, Some((1, 87, 0)), Some((1, 98, 0))),
+    entry("armv7a-none-eabihf", Some(Tier::Two), false, None, None),
+    entry("armv7a-nuttx-eabi", Some(Tier::Three), true, None, None),
+    entry("armv7a-nuttx-eabihf", Some(Tier::Three), true, None, None),
+    entry("armv7a-vex-v5", Some(Tier::Three), true, None, None),
+    entry("armv7k-apple-watchos", Some(Tier::Three), true, None, None),
+    entry("armv7r-none-eabi", Some(Tier::Two), false, Some((1, 87, 0)), Some((1, 92, 0))),
+    entry("armv7r-none-eabihf", Some(Tier::Two), false, Some((1, 87, 0)), Some((1, 92, 0))),
+    entry("armv7s-apple-ios", Some(Tier::Three), true, None, None),
+    entry("armv8r-none-eabihf", Some(Tier::Two), false, Some((1, 92, 0)), Some((1, 92, 0))),
+    entry("asmjs-unknown-emscripten", None, true, Some((1, 72, 0)), Some((1, 72, 0))),
+    entry("avr-none", Some(Tier::Three), false, None, None),
+    entry("bpfeb-unknown-none", Some(Tier::Three), false, None, None),
+    entry("bpfel-unknown-none", Some(Tier::Three), false, None, None),
+    entry("csky-unknown-linux-gnuabiv2", Some(Tier::Three), true, None, None),
+    entry("csky-unknown-linux-gnuabiv2hf", Some(Tier::Three), true, None, None),
+    entry("hexagon-unknown-linux-musl", Some(Tier::Three), true, None, None),
+    entry("hexagon-unknown-none-elf", Some(Tier::Three), false, None, None),
+    entry("hexagon-unknown-qurt", Some(Tier::Three), false, None, None),
+    entry("i386-apple-ios", Some(Tier::Three), true, None, None),
+    entry("i586-unknown-linux-gnu", Some(Tier::Two), true, Some((1, 76, 0)), Some((1, 95, 0))),
+    entry("i586-unknown-linux-musl", Some(Tier::Two), true, Some((1, 85, 0)), Some((1, 87, 0))),
+    entry("i586-unknown-netbsd", Some(Tier::Three), true, None, None),
+    entry("i586-unknown-redox", Some(Tier::Three), false, None, None),
+    entry("i686-apple-darwin", Some(Tier::Three), true, Some((1, 0, 0)), Some((1, 7, 0))),
+    entry("i686-linux-android", Some(Tier::Two), true, Some((1, 47, 0)), Some((1, 96, 0))),
+    entry("i686-pc-nto-qnx700", Some(Tier::Three), false, None, None),
+    entry("i686-pc-windows-gnu", Some(Tier::TwoWithHostTools), true, Some((1, 0, 0)), Some((1, 95, 0))),
+    entry("i686-pc-windows-gnullvm", Some(Tier::Two), true, Some((1, 87, 0)), Some((1, 90, 0))),
+    entry("i686-pc-windows-msvc", Some(Tier::One), true, Some((1, 3, 0)), None),
+    entry("i686-unknown-freebsd", Some(Tier::Two), true, Some((1, 65, 0)), Some((1, 93, 0))),
+    entry("i686-unknown-haiku", Some(Tier::Three), true, None, None),
+    entry("i686-unknown-helenos", Some(Tier::Three), true, None, None),
+    entry("i686-unknown-hurd-gnu", Some(Tier::Three), true, None, None),
+    entry("i686-unknown-linux-gnu", Some(Tier::One), true, Some((1, 0, 0)), None),
+    entry("i686-unknown-linux-musl", Some(Tier::Two), true, Some((1, 72, 0)), Some((1, 98, 0))),
+    entry("i686-unknown-netbsd", Some(Tier::Three), true, None, None),
+    entry("i686-unknown-openbsd", Some(Tier::Three), true, None, None),
+    entry("i686-unknown-uefi", Some(Tier::Two), false, Some((1, 87, 0)), Some((1, 89, 0))),
+    entry("i686-uwp-windows-gnu", Some(Tier::Three), false, None, None),
+    entry("i686-uwp-windows-msvc", Some(Tier::Three), false, None, None),
+    entry("i686-win7-windows-gnu", Some(Tier::Three), true, None, None),
+    entry("i686-win7-windows-msvc", Some(Tier::Three), true, None, None),
+    entry("i686-wrs-vxworks", Some(Tier::Three), true, None, None),
+    entry("loongarch32-unknown-none", Some(Tier::Three), false, None, None),
+    entry("loongarch32-unknown-none-softfloat", Some(Tier::Three), false, None, None),
+    entry("loongarch64-unknown-linux-gnu", Some(Tier::TwoWithHostTools), true, Some((1, 85, 0)), Some((1, 93, 0))),
+    entry("loongarch64-unknown-linux-musl", Some(Tier::TwoWithHostTools), true, Some((1, 85, 0)), Some((1, 93, 0))),
+    entry("loongarch64-unknown-linux-ohos", Some(Tier::Three), true, None, None),
+    entry("loongarch64-unknown-none", Some(Tier::Two), false, Some((1, 85, 0)), Some((1, 87, 0))),
+    entry("loongarch64-unknown-none-softfloat", Some(Tier::Two), false, Some((1, 87, 0)), Some((1, 90, 0))),
+    entry("m68k-unknown-linux-gnu", Some(Tier::Three), true, None, None),
+    entry("m68k-unknown-none-elf", Some(Tier::Three), false, None, None),
+    entry("mips-mti-none-elf", Some(Tier::Three), false, None, None),
+    entry("mips-unknown-linux-gnu", Some(Tier::Three), true, Some((1, 66, 0)), Some((1, 71, 0))),
+    entry("mips-unknown-linux-musl", Some(Tier::Three), true, Some((1, 71, 0)), Some((1, 72, 0))),
+    entry("mips-unknown-linux-uclibc", Some(Tier::Three), true, None, None),
+    entry("mips64-openwrt-linux-musl", Some(Tier::Three), true, None, None),
+    entry("mips64-unknown-linux-gnuabi64", Some(Tier::Three), true, Some((1, 41, 0)), Some((1, 41, 0))),
+    entry("mips64-unknown-linux-muslabi64", Some(Tier::Three), true, Some((1, 72, 0)), Some((1, 72, 0))),
+    entry("mips64el-unknown-linux-gnuabi64", Some(Tier::Three), true, None, None),
+    entry("mips64el-unknown-linux-muslabi64", Some(Tier::Three), true, None, None),
+    entry("mipsel-mti-none-elf", Some(Tier::Three), false, None, None),
+    entry("mipsel-sony-psp", Some(Tier::Three), false, None, None),
+    entry("mipsel-sony-psx", Some(Tier::Three), false, None, None),
+    entry("mipsel-unknown-linux-gnu", Some(Tier::Three), true, Some((1, 66, 0)), Some((1, 66, 0))),
+    entry("mipsel-unknown-linux-musl", Some(Tier::Three), true, Some((1, 72, 0)), Some((1, 72, 0))),
+    entry("mipsel-unknown-linux-uclibc", Some(Tier::Three), true, None, None),
+    entry("mipsel-unknown-netbsd", Some(Tier::Three), true, None, None),
+    entry("mipsel-unknown-none", Some(Tier::Three), false, None, None),
+    entry("mipsisa32r6-unknown-linux-gnu", Some(Tier::Three), true, None, None),
+    entry("mipsisa32r6el-unknown-linux-gnu", Some(Tier::Three), true, None, None),
+    entry("mipsisa64r6-unknown-linux-gnuabi64", Some(Tier::Three), true, None, None),
+    entry("mipsisa64r6el-unknown-linux-gnuabi64", Some(Tier::Three), true, None, None),
+    entry("msp430-none-elf", Some(Tier::Three), false, None, None),
+    entry("nvptx64-nvidia-cuda", Some(Tier::Two), false, Some((1, 87, 0)), Some((1, 98, 0))),
+    entry("powerpc-unknown-freebsd", Some(Tier::Three), true, None, None),
+    entry("powerpc-unknown-helenos", Some(Tier::Three), true, None, None),
+    entry("powerpc-unknown-linux-gnu", Some(Tier::TwoWithHostTools), true, Some((1, 70, 0)), Some((1, 95, 0))),
+    entry("powerpc-unknown-linux-gnuspe", Some(Tier::Three), true, None, None),
+    entry("powerpc-unknown-linux-musl", Some(Tier::Three), true, None, None),
+    entry("powerpc-unknown-linux-muslspe", Some(Tier::Three), true, None, None),
+    entry("powerpc-unknown-netbsd", Some(Tier::Three), true, None, None),
+    entry("powerpc-unknown-openbsd", Some(Tier::Three), true, None, None),
+    entry("powerpc-wrs-vxworks", Some(Tier::Three), true, None, None),
+    entry("powerpc-wrs-vxworks-spe", Some(Tier::Three), false, None, None),
+    entry("powerpc64-ibm-aix", Some(Tier::Three), false, None, None),
+    entry("powerpc64-unknown-freebsd", Some(Tier::Three), true, None, None),
+    entry("powerpc64-unknown-linux-gnu", Some(Tier::TwoWithHostTools), true, Some((1, 60, 0)), Some((1, 95, 0))),
+    entry("powerpc64-unknown-linux-musl", Some(Tier::TwoWithHostTools), true, Some((1, 95, 0)), Some((1, 95, 0))),
+    entry("powerpc64-unknown-openbsd", Some(Tier::Three), true, None, None),
+    entry("powerpc64-wrs-vxworks", Some(Tier::Three), true, None, None),
+    entry("powerpc64le-unknown-freebsd", Some(Tier::Three), true, None, None),
+    entry("powerpc64le-unknown-linux-gnu", Some(Tier::TwoWithHostTools), true, Some((1, 75, 0)), Some((1, 95, 0))),
+    entry("powerpc64le-unknown-linux-musl", Some(Tier::TwoWithHostTools), true, Some((1, 85, 0)), Some((1, 93, 0))),
+    entry("riscv32-wrs-vxworks", Some(Tier::Three), true, None, None),
+    entry("riscv32e-unknown-none-elf", Some(Tier::Three), false, None, None),
+    entry("riscv32em-unknown-none-elf", Some(Tier::Three), false, None, None),
+    entry("riscv32emc-unknown-none-elf", Some(Tier::Three), false, None, None),
+    entry("riscv32gc-unknown-linux-gnu", Some(Tier::Three), true, None, None),
+    entry("riscv32gc-unknown-linux-musl", Some(Tier::Three), true, None, None),
+    entry("riscv32i-unknown-none-elf", Some(Tier::Two), false, Some((1, 85, 0)), Some((1, 95, 0))),
+    entry("riscv32im-risc0-zkvm-elf", Some(Tier::Three), false, None, None),
+    entry("riscv32im-unknown-none-elf", Some(Tier::Two), false, Some((1, 85, 0)), Some((1, 95, 0))),
+    entry("riscv32ima-unknown-none-elf", Some(Tier::Three), false, None, None),
+    entry("riscv32imac-esp-espidf", Some(Tier::Three), true, None, None),
+    entry("riscv32imac-unknown-none-elf", Some(Tier::Two), false, Some((1, 70, 0)), Some((1, 98, 0))),
+    entry("riscv32imac-unknown-nuttx-elf", Some(Tier::Three), true, None, None),
+    entry("riscv32imac-unknown-xous-elf", Some(Tier::Three), false, None, None),
+    entry("riscv32imafc-esp-espidf", Some(Tier::Three), true, None, None),
+    entry("riscv32imafc-unknown-none-elf", Some(Tier::Two), false, Some((1, 85, 0)), Some((1, 95, 0))),
+    entry("riscv32imafc-unknown-nuttx-elf", Some(Tier::Three), true, None, None),
+    entry("riscv32imc-esp-espidf", Some(Tier::Three), true, None, None),
+    entry("riscv32imc-unknown-none-elf", Some(Tier::Two), false, Some((1, 70, 0)), Some((1, 98, 0))),
+    entry("riscv32imc-unknown-nuttx-elf", Some(Tier::Three), true, None, None),
+    entry("riscv64-linux-android", Some(Tier::Three), true, None, None),
+    entry("riscv64-wrs-vxworks", Some(Tier::Three), true, None, None),
+    entry("riscv64a23-unknown-linux-gnu", Some(Tier::Two), true, None, None),
+    entry("riscv64gc-unknown-freebsd", Some(Tier::Three), true, None, None),
+    entry("riscv64gc-unknown-fuchsia", Some(Tier::Three), true, None, None),
+    entry("riscv64gc-unknown-hermit", Some(Tier::Three), true, None, None),
+    entry("riscv64gc-unknown-linux-gnu", Some(Tier::TwoWithHostTools), true, Some((1, 75, 0)), Some((1, 98, 0))),
+    entry("riscv64gc-unknown-linux-musl", Some(Tier::Two), true, Some((1, 85, 0)), Some((1, 98, 0))),
+    entry("riscv64gc-unknown-managarm-mlibc", Some(Tier::Three), false, None, None),
+    entry("riscv64gc-unknown-netbsd", Some(Tier::Three), true, None, None),
+    entry("riscv64gc-unknown-none-elf", Some(Tier::Two), false, Some((1, 85, 0)), Some((1, 98, 0))),
+    entry("riscv64gc-unknown-nuttx-elf", Some(Tier::Three), true, None, None),
+    entry("riscv64gc-unknown-openbsd", Some(Tier::Three), true, None, None),
+    entry("riscv64gc-unknown-redox", Some(Tier::Three), true, None, None),
+    entry("riscv64im-unknown-none-elf", Some(Tier::Three), false, None, None),
+    entry("riscv64imac-unknown-none-elf", Some(Tier::Two), false, Some((1, 81, 0)), Some((1, 98, 0))),
+    entry("riscv64imac-unknown-nuttx-elf", Some(Tier::Three), true, None, None),
+    entry("s390x-unknown-linux-gnu", Some(Tier::TwoWithHostTools), true, Some((1, 75, 0)), Some((1, 95, 0))),
+    entry("s390x-unknown-linux-musl", Some(Tier::Three), true, None, None),
+    entry("s390x-unknown-none-softfloat", Some(Tier::Two), false, None, None),
+    entry("sparc-unknown-linux-gnu", Some(Tier::Three), true, None, None),
+    entry("sparc-unknown-none-elf", Some(Tier::Three), false, None, None),
+    entry("sparc64-unknown-helenos", Some(Tier::Three), true, None, None),
+    entry("sparc64-unknown-linux-gnu", Some(Tier::Two), true, Some((1, 85, 0)), Some((1, 93, 0))),
+    entry("sparc64-unknown-netbsd", Some(Tier::Three), true, None, None),
+    entry("sparc64-unknown-openbsd", Some(Tier::Three), true, None, None),
+    entry("sparcv9-sun-solaris", Some(Tier::TwoWithHostTools), true, Some((1, 85, 0)), Some((1, 93, 0))),
+    entry("thumbv4t-none-eabi", Some(Tier::Three), false, None, None),
+    entry("thumbv5te-none-eabi", Some(Tier::Three), false, None, None),
+    entry("thumbv6-none-eabi", Some(Tier::Three), false, None, None),
+    entry("thumbv6m-none-eabi", Some(Tier::Two), false, Some((1, 48, 0)), Some((1, 98, 0))),
+    entry("thumbv6m-nuttx-eabi", Some(Tier::Three), true, None, None),
+    entry("thumbv7a-none-eabi", Some(Tier::Three), false, None, None),
+    entry("thumbv7a-none-eabihf", Some(Tier::Three), false, None, None),
+    entry("thumbv7a-nuttx-eabi", Some(Tier::Three), true, None, None),
+    entry("thumbv7a-nuttx-eabihf", Some(Tier::Three), true, None, None),
+    entry("thumbv7a-pc-windows-msvc", Some(Tier::Three), false, None, None),
+    entry("thumbv7a-uwp-windows-msvc", Some(Tier::Three), true, None, None),
+    entry("thumbv7em-none-eabi", Some(Tier::Two), false, Some((1, 41, 0)), Some((1, 98, 0))),
+    entry("thumbv7em-none-eabihf", Some(Tier::Two), false, Some((1, 51, 0)), Some((1, 98, 0))),
+    entry("thumbv7em-nuttx-eabi", Some(Tier::Three), true, None, None),
+    entry("thumbv7em-nuttx-eabihf", Some(Tier::Three), true, None, None),
+    entry("thumbv7m-none-eabi", Some(Tier::Two), false, Some((1, 56, 0)), Some((1, 95, 0))),
+    entry("thumbv7m-nuttx-eabi", Some(Tier::Three), true, None, None),
+    entry("thumbv7neon-linux-androideabi", Some(Tier::Two), true, Some((1, 90, 0)), Some((1, 90, 0))),
+    entry("thumbv7neon-unknown-linux-gnueabihf", Some(Tier::Two), true, Some((1, 77, 0)), Some((1, 95, 0))),
+    entry("thumbv7neon-unknown-linux-musleabihf", Some(Tier::Three), true, None, None),
+    entry("thumbv7r-none-eabi", Some(Tier::Three), false, None, None),
+    entry("thumbv7r-none-eabihf", Some(Tier::Three), false, None, None),
+    entry("thumbv8m.base-none-eabi", Some(Tier::Two), false, Some((1, 87, 0)), Some((1, 92, 0))),
+    entry("thumbv8m.base-nuttx-eabi", Some(Tier::Three), true, None, None),
+    entry("thumbv8m.main-none-eabi", Some(Tier::Two), false, Some((1, 65, 0)), Some((1, 95, 0))),
+    entry("thumbv8m.main-none-eabihf", Some(Tier::Two), false, Some((1, 75, 0)), Some((1, 95, 0))),
+    entry("thumbv8m.main-nuttx-eabi", Some(Tier::Three), true, None, None),
+    entry("thumbv8m.main-nuttx-eabihf", Some(Tier::Three), true, None, None),
+    entry("thumbv8r-none-eabihf", Some(Tier::Three), false, None, None),
+    entry("wasm32-unknown-emscripten", Some(Tier::Two), true, Some((1, 63, 0)), Some((1, 97, 0))),
+    entry("wasm32-unknown-unknown", Some(Tier::Two), true, Some((1, 31, 0)), None),
+    entry("wasm32-wali-linux-musl", Some(Tier::Three), false, None, None),
+    entry("wasm32-wasi", None, true, Some((1, 49, 0)), Some((1, 83, 0))),
+    entry("wasm32-wasip1", Some(Tier::Two), true, Some((1, 79, 0)), None),
+    entry("wasm32-wasip1-threads", Some(Tier::Two), true, Some((1, 82, 0)), Some((1, 98, 0))),
+    entry("wasm32-wasip2", Some(Tier::Two), true, Some((1, 82, 0)), None),
+    entry("wasm32-wasip3", Some(Tier::Three), true, None, None),
+    entry("wasm32v1-none", Some(Tier::Two), false, Some((1, 84, 0)), Some((1, 95, 0))),
+    entry("wasm64-unknown-unknown", Some(Tier::Three), false, None, None),
+    entry("x86_64-apple-darwin", Some(Tier::TwoWithHostTools), true, Some((1, 0, 0)), None),
+    entry("x86_64-apple-ios", Some(Tier::Two), true, Some((1, 47, 0)), Some((1, 96, 0))),
+    entry("x86_64-apple-ios-macabi", Some(Tier::Two), true, None, None),
+    entry("x86_64-apple-tvos", Some(Tier::Three), true, None, None),
+    entry("x86_64-apple-watchos-sim", Some(Tier::Three), true, None, None),
+    entry("x86_64-fortanix-unknown-sgx", Some(Tier::Two), true, Some((1, 82, 0)), Some((1, 90, 0))),
+    entry("x86_64-linux-android", Some(Tier::Two), true, Some((1, 47, 0)), Some((1, 97, 0))),
+    entry("x86_64-lynx-lynxos178", Some(Tier::Three), false, None, None),
+    entry("x86_64-pc-cygwin", Some(Tier::Three), true, None, None),
+    entry("x86_64-pc-nto-qnx710", Some(Tier::Three), true, None, None),
+    entry("x86_64-pc-nto-qnx710_iosock", Some(Tier::Three), true, None, None),
+    entry("x86_64-pc-nto-qnx800", Some(Tier::Three), true, None, None),
+    entry("x86_64-pc-solaris", Some(Tier::TwoWithHostTools), true, Some((1, 63, 0)), Some((1, 93, 0))),
+    entry("x86_64-pc-windows-gnu", Some(Tier::One), true, Some((1, 0, 0)), None),
+    entry("x86_64-pc-windows-gnullvm", Some(Tier::TwoWithHostTools), true, Some((1, 85, 0)), Some((1, 97, 0))),
+    entry("x86_64-pc-windows-msvc", Some(Tier::One), true, Some((1, 2, 0)), None),
+    entry("x86_64-unikraft-linux-musl", Some(Tier::Three), true, None, None),
+    entry("x86_64-unknown-dragonfly", Some(Tier::Three), true, None, None),
+    entry("x86_64-unknown-freebsd", Some(Tier::TwoWithHostTools), true, Some((1, 41, 0)), Some((1, 96, 0))),
+    entry("x86_64-unknown-fuchsia", Some(Tier::Two), true, Some((1, 81, 0)), Some((1, 95, 0))),
+    entry("x86_64-unknown-haiku", Some(Tier::Three), true, None, None),
+    entry("x86_64-unknown-helenos", Some(Tier::Three), true, None, None),
+    entry("x86_64-unknown-hermit", Some(Tier::Three), true, None, None),
+    entry("x86_64-unknown-hurd-gnu", Some(Tier::Three), true, None, None),
+    entry("x86_64-unknown-illumos", Some(Tier::TwoWithHostTools), true, Some((1, 65, 0)), Some((1, 95, 0))),
+    entry("x86_64-unknown-l4re-uclibc", Some(Tier::Three), false, None, None),
+    entry("x86_64-unknown-linux-gnu", Some(Tier::One), true, Some((1, 0, 0)), None),
+    entry("x86_64-unknown-linux-gnuasan", Some(Tier::Two), true, Some((1, 97, 0)), Some((1, 97, 0))),
+    entry("x86_64-unknown-linux-gnumsan", Some(Tier::Two), true, Some((1, 97, 0)), Some((1, 97, 0))),
+    entry("x86_64-unknown-linux-gnutsan", Some(Tier::Two), true, Some((1, 97, 0)), Some((1, 97, 0))),
+    entry("x86_64-unknown-linux-gnux32", Some(Tier::Two), true, Some((1, 56, 0)), Some((1, 97, 0))),
+    entry("x86_64-unknown-linux-musl", Some(Tier::TwoWithHostTools), true, Some((1, 36, 0)), None),
+    entry("x86_64-unknown-linux-none", Some(Tier::Three), false, None, None),
+    entry("x86_64-unknown-linux-ohos", Some(Tier::Two), true, Some((1, 82, 0)), Some((1, 82, 0))),
+    entry("x86_64-unknown-managarm-mlibc", Some(Tier::Three), false, None, None),
+    entry("x86_64-unknown-motor", Some(Tier::Three), false, None, None),
+    entry("x86_64-unknown-netbsd", Some(Tier::TwoWithHostTools), true, Some((1, 13, 0)), Some((1, 95, 0))),
+    entry("x86_64-unknown-none", Some(Tier::Two), false, Some((1, 80, 0)), None),
+    entry("x86_64-unknown-openbsd", Some(Tier::Three), true, None, None),
+    entry("x86_64-unknown-redox", Some(Tier::Two), true, Some((1, 65, 0)), Some((1, 93, 0))),
+    entry("x86_64-unknown-trusty", Some(Tier::Three), true, None, None),
+    entry("x86_64-unknown-uefi", Some(Tier::Two), false, Some((1, 80, 0)), Some((1, 98, 0))),
+    entry("x86_64-uwp-windows-gnu", Some(Tier::Three), true, None, None),
+    entry("x86_64-uwp-windows-msvc", Some(Tier::Three), true, None, None),
+    entry("x86_64-win7-windows-gnu", Some(Tier::Three), true, None, None),
+    entry("x86_64-win7-windows-msvc", Some(Tier::Three), true, None, None),
+    entry("x86_64-wrs-vxworks", Some(Tier::Three), true, None, None),
+    entry("x86_64h-apple-darwin", Some(Tier::Three), true, None, None),
+    entry("xtensa-esp32-espidf", Some(Tier::Three), false, None, None),
+    entry("xtensa-esp32-none-elf", Some(Tier::Three), false, None, None),
+    entry("xtensa-esp32s2-espidf", Some(Tier::Three), false, None, None),
+    entry("xtensa-esp32s2-none-elf", Some(Tier::Three), false, None, None),
+    entry("xtensa-esp32s3-espidf", Some(Tier::Three), false, None, None),
+    entry("xtensa-esp32s3-none-elf", Some(Tier::Three), false, None, None),
+];
@@ -1,5 +1,8 @@
-use crate::{Channel, Component, Date, Target};
+use crate::{Channel, Component, Date, ParseToolchainError, Profile, Target, ToolchainSpec};
 use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
 
 /// A Rust toolchain
 ///
@@ -57,6 +60,12 @@ impl Toolchain {
         &self.components
     }
 
+    /// The components associated with the toolchain which are part of the given profile,
+    /// and which are available on the host of the toolchain.
+    pub fn profile_components(&self, profile: Profile) -> Vec<&Component> {
+        profile.select(&self.components, &self.host)
+    }
+
     /// The targets associated with the toolchain
     pub fn targets(&self) -> &HashSet<Target> {
         &self.targets
@@ -88,6 +97,29 @@ impl Toolchain {
     }
 }
 
+impl FromStr for Toolchain {
+    type Err = ParseToolchainError;
+
+    /// Parses a toolchain name, e.g. `1.82.0-aarch64-apple-darwin` or
+    /// `nightly-2024-02-01-x86_64-unknown-linux-gnu`.
+    ///
+    /// If the name does not contain a host, the [`Target::host`] is used. Names which do not
+    /// denote a single toolchain, like `stable` or `1.70`, must be parsed as a [`ToolchainSpec`]
+    /// instead.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<ToolchainSpec>().and_then(Toolchain::try_from)
+    }
+}
+
+impl fmt::Display for Toolchain {
+    /// Formats the name of the toolchain, i.e. `<channel>-<host>`.
+    ///
+    /// The release date of a stable or beta toolchain is not part of its name.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", ToolchainSpec::from(self))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -214,4 +246,38 @@ mod tests {
 
         assert_eq!(toolchain.targets(), &expected);
     }
+
+    #[yare::parameterized(
+        stable = { "1.82.0-aarch64-apple-darwin", Channel::stable(RustVersion::new(1, 82, 0)) },
+        nightly = { "nightly-2024-02-01-x86_64-unknown-linux-gnu", Channel::nightly(Date::new(2024, 2, 1)) },
+        beta = { "1.71.0-beta.2-x86_64-pc-windows-msvc", "1.71.0-beta.2".parse().unwrap() },
+    )]
+    fn parse_and_display(name: &str, channel: Channel) {
+        let toolchain = name.parse::<Toolchain>().unwrap();
+
+        assert_eq!(toolchain.channel(), &channel);
+        assert_eq!(toolchain.to_string(), name);
+    }
+
+    #[test]
+    fn profile_components() {
+        let mut toolchain = "1.70.0".parse::<Toolchain>().unwrap();
+
+        let mut set = HashSet::new();
+        set.insert(Component::new("rustc"));
+        set.insert(Component::new("rust-src"));
+        toolchain.set_components(set);
+
+        let components = toolchain.profile_components(Profile::Minimal);
+
+        assert_eq!(components, vec![&Component::new("rustc")]);
+    }
+
+    #[test]
+    fn parse_symbolic_is_unresolved() {
+        assert!(matches!(
+            "stable".parse::<Toolchain>(),
+            Err(ParseToolchainError::Unresolved(_))
+        ));
+    }
 }
@@ -0,0 +1,591 @@
+use crate::channel::Custom;
+use crate::{Component, ParseToolchainError, Profile, Target, Toolchain, ToolchainSpec};
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::{fmt, fs, io};
+use toml_edit::{Array, DocumentMut, Item, TableLike, Value};
+
+const TOOLCHAIN: &str = "toolchain";
+const CHANNEL: &str = "channel";
+const COMPONENTS: &str = "components";
+const TARGETS: &str = "targets";
+const PROFILE: &str = "profile";
+const PATH: &str = "path";
+
+/// A [`toolchain file`], which overrides the toolchain used within a directory.
+///
+/// Both the `rust-toolchain.toml` format, and the legacy single line `rust-toolchain` format
+/// are supported. A toolchain file in the `toml` format may contain the following keys in its
+/// `[toolchain]` table: `channel`, `components`, `targets`, `profile` and `path`.
+///
+/// When a parsed toolchain file is written again, keys, tables and comments which are not
+/// modelled by this type are preserved as-is. The `Display` implementation produces the
+/// contents of the file.
+///
+/// # Reading materials
+///
+/// - [`rustup overrides: the toolchain file`]
+///
+/// [`toolchain file`]: https://rust-lang.github.io/rustup/overrides.html#the-toolchain-file
+/// [`rustup overrides: the toolchain file`]: https://rust-lang.github.io/rustup/overrides.html#the-toolchain-file
+#[derive(Clone, Debug)]
+pub struct ToolchainFile {
+    format: Format,
+    channel: Option<ToolchainSpec>,
+    path: Option<PathBuf>,
+    profile: Option<Profile>,
+    components: Vec<Component>,
+    targets: Vec<Target>,
+}
+
+#[derive(Clone, Debug)]
+enum Format {
+    Legacy,
+    Toml(DocumentMut),
+}
+
+impl ToolchainFile {
+    /// Create a new toolchain file in the `rust-toolchain.toml` format, which pins the
+    /// given channel.
+    pub fn new(channel: ToolchainSpec) -> Self {
+        Self {
+            format: Format::Toml(DocumentMut::new()),
+            channel: Some(channel),
+            path: None,
+            profile: None,
+            components: Vec::new(),
+            targets: Vec::new(),
+        }
+    }
+
+    /// Create a new toolchain file in the legacy single line `rust-toolchain` format, which
+    /// pins the given channel.
+    pub fn legacy(channel: ToolchainSpec) -> Self {
+        Self {
+            format: Format::Legacy,
+            ..Self::new(channel)
+        }
+    }
+
+    /// Parse a toolchain file in the `rust-toolchain.toml` format.
+    ///
+    /// A `channel` which is not recognized is treated as the name of a custom toolchain, e.g.
+    /// one linked with `rustup toolchain link`, like rustup does.
+    pub fn parse_toml(contents: &str) -> Result<Self, ToolchainFileError> {
+        let document = contents.parse::<DocumentMut>()?;
+
+        let table = document
+            .get(TOOLCHAIN)
+            .ok_or(ToolchainFileError::MissingToolchainTable)?
+            .as_table_like()
+            .ok_or(ToolchainFileError::InvalidType(TOOLCHAIN, "a table"))?;
+
+        let channel = get_str(table, CHANNEL)?
+            .map(ToolchainSpec::parse_or_custom)
+            .transpose()?;
+        let path = get_str(table, PATH)?.map(PathBuf::from);
+
+        if channel.is_some() && path.is_some() {
+            return Err(ToolchainFileError::ChannelAndPath);
+        }
+
+        let profile = get_str(table, PROFILE)?
+            .map(Profile::from_str)
+            .transpose()?;
+
+        let components = get_str_array(table, COMPONENTS)?
+            .into_iter()
+            .map(|name| Component::new(name.to_string()))
+            .collect();
+
+        let targets = get_str_array(table, TARGETS)?
+            .into_iter()
+            .map(|triple| {
+                Target::try_from_target_triple(triple)
+                    .map_err(|err| ToolchainFileError::Target(triple.to_string(), err))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            format: Format::Toml(document),
+            channel,
+            path,
+            profile,
+            components,
+            targets,
+        })
+    }
+
+    /// Parse a toolchain file in the legacy `rust-toolchain` format, which consists of a
+    /// single line with a toolchain specifier.
+    ///
+    /// Like the `channel` of the `toml` format, a specifier of which the channel is not
+    /// recognized, is treated as the name of a custom toolchain.
+    pub fn parse_legacy(contents: &str) -> Result<Self, ToolchainFileError> {
+        let channel = ToolchainSpec::parse_or_custom(contents.trim())?;
+
+        Ok(Self::legacy(channel))
+    }
+
+    /// Read a toolchain file from the given path.
+    ///
+    /// A `rust-toolchain.toml` file is always parsed in the `toml` format, while the
+    /// format of a `rust-toolchain` file is detected from its contents, like rustup does.
+    pub fn read(path: impl AsRef<Path>) -> Result<Self, ToolchainFileError> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path)?;
+
+        if path.extension().is_some_and(|ext| ext == "toml") {
+            Self::parse_toml(&contents)
+        } else {
+            contents.parse()
+        }
+    }
+
+    /// Write the toolchain file to the given path.
+    pub fn write(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    /// Whether the toolchain file will be written in the legacy single line format.
+    ///
+    /// A toolchain file which was parsed from, or created in, the legacy format is written in
+    /// the `toml` format instead, once it specifies more than just a channel.
+    pub fn is_legacy(&self) -> bool {
+        matches!(self.format, Format::Legacy) && self.is_channel_only()
+    }
+
+    /// The channel of the toolchain file, if any
+    pub fn channel(&self) -> Option<&ToolchainSpec> {
+        self.channel.as_ref()
+    }
+
+    /// The path to a custom toolchain, if any
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// The rustup profile, if any
+    pub fn profile(&self) -> Option<Profile> {
+        self.profile
+    }
+
+    /// The additional components which should be installed
+    pub fn components(&self) -> &[Component] {
+        &self.components
+    }
+
+    /// The additional targets which should be installed
+    pub fn targets(&self) -> &[Target] {
+        &self.targets
+    }
+
+    /// Update the channel, e.g. to bump a pinned toolchain
+    pub fn set_channel(&mut self, channel: Option<ToolchainSpec>) {
+        self.channel = channel;
+    }
+
+    /// Update the path to a custom toolchain
+    pub fn set_path(&mut self, path: Option<PathBuf>) {
+        self.path = path;
+    }
+
+    /// Update the rustup profile
+    pub fn set_profile(&mut self, profile: Option<Profile>) {
+        self.profile = profile;
+    }
+
+    /// Update the additional components
+    pub fn set_components(&mut self, components: Vec<Component>) {
+        self.components = components;
+    }
+
+    /// Update the additional targets
+    pub fn set_targets(&mut self, targets: Vec<Target>) {
+        self.targets = targets;
+    }
+
+    /// Convert the toolchain file into a [`Toolchain`], including its components and targets.
+    ///
+    /// A `path` results in a [`Custom::Path`] toolchain.
+    ///
+    /// Fails if the toolchain file specifies neither a channel, nor a path, or if the channel
+    /// does not denote a single toolchain, like `stable` or `1.70`.
+    pub fn to_toolchain(&self) -> Result<Toolchain, ToolchainFileError> {
+        let spec = match (&self.channel, &self.path) {
+            (Some(channel), _) => channel.clone(),
+            (None, Some(path)) => ToolchainSpec::custom(Custom::Path(path.clone())),
+            (None, None) => return Err(ToolchainFileError::MissingChannel),
+        };
+
+        let mut toolchain = Toolchain::try_from(spec)?;
+        toolchain.set_components(self.components.iter().cloned().collect::<HashSet<_>>());
+        toolchain.set_targets(self.targets.iter().cloned().collect::<HashSet<_>>());
+
+        Ok(toolchain)
+    }
+
+    fn is_channel_only(&self) -> bool {
+        self.channel.is_some()
+            && self.path.is_none()
+            && self.profile.is_none()
+            && self.components.is_empty()
+            && self.targets.is_empty()
+    }
+
+    /// Produces a `toml` document with the modelled keys updated, starting from the parsed
+    /// document, if any. Keys whose value did not change are left untouched, so their
+    /// formatting is retained.
+    fn to_document(&self) -> DocumentMut {
+        let mut document = match &self.format {
+            Format::Toml(document) => document.clone(),
+            Format::Legacy => DocumentMut::new(),
+        };
+
+        let table = document
+            .entry(TOOLCHAIN)
+            .or_insert_with(toml_edit::table)
+            .as_table_like_mut()
+            .expect("[toolchain] is verified to be a table when parsed");
+
+        let channel = self.channel.as_ref().map(ToString::to_string);
+        let path = self.path.as_ref().map(|path| path.display().to_string());
+        let components = self.components.iter().map(|c| c.name().to_string());
+        let targets = self.targets.iter().map(ToString::to_string);
+
+        set_str(table, CHANNEL, channel);
+        set_str_array(table, COMPONENTS, components.collect());
+        set_str_array(table, TARGETS, targets.collect());
+        set_str(table, PROFILE, self.profile.map(|p| p.to_string()));
+        set_str(table, PATH, path);
+
+        document
+    }
+}
+
+impl FromStr for ToolchainFile {
+    type Err = ToolchainFileError;
+
+    /// Parse a toolchain file, detecting whether it is in the `toml`, or in the legacy
+    /// single line format.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+
+        if trimmed.contains(['\n', '=', '[']) {
+            Self::parse_toml(s)
+        } else {
+            Self::parse_legacy(trimmed)
+        }
+    }
+}
+
+impl fmt::Display for ToolchainFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.channel, self.is_legacy()) {
+            (Some(channel), true) => writeln!(f, "{}", channel),
+            _ => write!(f, "{}", self.to_document()),
+        }
+    }
+}
+
+/// Errors which may occur while reading a [`ToolchainFile`].
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum ToolchainFileError {
+    /// Returned when the toolchain file could not be read
+    #[error("Unable to read toolchain file: {0}")]
+    Io(#[from] io::Error),
+
+    /// Returned when the toolchain file is not a valid `toml` document
+    #[error("Unable to parse toolchain file: {0}")]
+    Toml(#[from] toml_edit::TomlError),
+
+    /// Returned when the `toml` document does not contain a `[toolchain]` table
+    #[error("Toolchain file does not contain a [toolchain] table")]
+    MissingToolchainTable,
+
+    /// Returned when a key does not have the expected type
+    #[error("Expected `{0}` to be {1}")]
+    InvalidType(&'static str, &'static str),
+
+    /// Returned when both a `channel` and a `path` are specified
+    #[error("Toolchain file must not specify both a `channel` and a `path`")]
+    ChannelAndPath,
+
+    /// Returned when the toolchain file specifies neither a channel, nor a path, while one is
+    /// required
+    #[error("Toolchain file does not specify a channel or a path")]
+    MissingChannel,
+
+    /// Returned when the channel can not be parsed, or does not denote a single toolchain
+    #[error(transparent)]
+    Channel(#[from] ParseToolchainError),
+
+    /// Returned when the profile is not recognized
+    #[error(transparent)]
+    Profile(#[from] crate::UnknownProfile),
+
+    /// Returned when a target can not be parsed
+    #[error("Unable to parse target `{0}`: {1}")]
+    Target(String, #[source] crate::target::ParseError),
+}
+
+fn get_str<'t>(
+    table: &'t dyn TableLike,
+    key: &'static str,
+) -> Result<Option<&'t str>, ToolchainFileError> {
+    table
+        .get(key)
+        .map(|item| {
+            item.as_str()
+                .ok_or(ToolchainFileError::InvalidType(key, "a string"))
+        })
+        .transpose()
+}
+
+fn get_str_array<'t>(
+    table: &'t dyn TableLike,
+    key: &'static str,
+) -> Result<Vec<&'t str>, ToolchainFileError> {
+    let invalid = || ToolchainFileError::InvalidType(key, "an array of strings");
+
+    let Some(item) = table.get(key) else {
+        return Ok(Vec::new());
+    };
+
+    item.as_array()
+        .ok_or_else(invalid)?
+        .iter()
+        .map(|value| value.as_str().ok_or_else(invalid))
+        .collect()
+}
+
+fn set_str(table: &mut dyn TableLike, key: &str, value: Option<String>) {
+    match value {
+        Some(value) if table.get(key).and_then(Item::as_str) != Some(value.as_str()) => {
+            replace_value(table, key, value.into());
+        }
+        Some(_) => {}
+        None => {
+            table.remove(key);
+        }
+    }
+}
+
+fn set_str_array(table: &mut dyn TableLike, key: &str, values: Vec<String>) {
+    let current = table.get(key).and_then(Item::as_array).map(|array| {
+        array
+            .iter()
+            .map(|value| value.as_str().unwrap_or_default())
+            .collect::<Vec<_>>()
+    });
+
+    match current {
+        Some(current) if current == values => {}
+        _ if values.is_empty() => {
+            table.remove(key);
+        }
+        _ => {
+            replace_value(table, key, Array::from_iter(values).into());
+        }
+    }
+}
+
+/// Inserts the value, retaining the whitespace and comments surrounding the current value, if any.
+fn replace_value(table: &mut dyn TableLike, key: &str, mut value: Value) {
+    if let Some(Item::Value(current)) = table.get(key) {
+        *value.decor_mut() = current.decor().clone();
+    }
+
+    table.insert(key, Item::Value(value));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Channel, ChannelSpec, Date, RustVersion};
+    use yare::parameterized;
+
+    const TOML: &str = r#"# Pinned by the release team
+[toolchain]
+channel = "1.70.0" # bumped regularly
+components = ["rustfmt", "clippy"]
+targets = ["wasm32-unknown-unknown"]
+profile = "minimal"
+unknown = { key = true }
+
+[extra]
+kept = "as-is"
+"#;
+
+    #[test]
+    fn parse_toml() {
+        let file = ToolchainFile::parse_toml(TOML).unwrap();
+
+        assert_eq!(
+            file.channel().unwrap().channel(),
+            &ChannelSpec::Exact(Channel::stable(RustVersion::new(1, 70, 0)))
+        );
+        assert_eq!(
+            file.components(),
+            &[Component::new("rustfmt"), Component::new("clippy")]
+        );
+        assert_eq!(
+            file.targets(),
+            &[Target::try_from_target_triple("wasm32-unknown-unknown").unwrap()]
+        );
+        assert_eq!(file.profile(), Some(Profile::Minimal));
+        assert!(file.path().is_none());
+        assert!(!file.is_legacy());
+    }
+
+    #[test]
+    fn write_unchanged_toml() {
+        let file = ToolchainFile::parse_toml(TOML).unwrap();
+
+        assert_eq!(file.to_string(), TOML);
+    }
+
+    #[test]
+    fn write_bumped_toml_preserves_unknown_keys() {
+        let mut file = ToolchainFile::parse_toml(TOML).unwrap();
+        file.set_channel(Some("1.82.0".parse().unwrap()));
+
+        assert_eq!(file.to_string(), TOML.replace("1.70.0", "1.82.0"));
+    }
+
+    #[test]
+    fn write_removed_keys() {
+        let mut file = ToolchainFile::parse_toml(TOML).unwrap();
+        file.set_components(Vec::new());
+        file.set_targets(Vec::new());
+        file.set_profile(None);
+
+        let written = file.to_string();
+
+        assert!(!written.contains("components"));
+        assert!(!written.contains("targets"));
+        assert!(!written.contains("profile"));
+        assert!(written.contains("unknown = { key = true }"));
+        assert!(written.contains("kept = \"as-is\""));
+    }
+
+    #[test]
+    fn parse_path() {
+        let file = ToolchainFile::parse_toml("[toolchain]\npath = \"/opt/rust\"\n").unwrap();
+
+        assert!(file.channel().is_none());
+        assert_eq!(file.path(), Some(Path::new("/opt/rust")));
+        assert_eq!(
+            file.to_toolchain().unwrap().channel(),
+            &Channel::Custom(Custom::Path(PathBuf::from("/opt/rust")))
+        );
+    }
+
+    #[test]
+    fn parse_custom_channel() {
+        let file = ToolchainFile::parse_toml("[toolchain]\nchannel = \"stage2\"\n").unwrap();
+
+        assert_eq!(
+            file.to_toolchain().unwrap().channel(),
+            &Channel::Custom(Custom::Named("stage2".to_string()))
+        );
+        assert_eq!(file.to_string(), "[toolchain]\nchannel = \"stage2\"\n");
+    }
+
+    #[test]
+    fn missing_channel_and_path() {
+        let file = ToolchainFile::parse_toml("[toolchain]\nprofile = \"minimal\"\n").unwrap();
+
+        assert!(matches!(
+            file.to_toolchain(),
+            Err(ToolchainFileError::MissingChannel)
+        ));
+    }
+
+    #[parameterized(
+        no_table = { "channel = \"stable\"" },
+        not_a_table = { "toolchain = 1" },
+        channel_type = { "[toolchain]\nchannel = 1" },
+        components_type = { "[toolchain]\ncomponents = \"rustfmt\"" },
+        components_item_type = { "[toolchain]\ncomponents = [1]" },
+        invalid_channel = { "[toolchain]\nchannel = \"1.x.0\"" },
+        invalid_profile = { "[toolchain]\nprofile = \"everything\"" },
+        invalid_target = { "[toolchain]\ntargets = [\"not-a-target\"]" },
+        channel_and_path = { "[toolchain]\nchannel = \"stable\"\npath = \"/opt/rust\"" },
+        invalid_toml = { "[toolchain" },
+    )]
+    fn parse_toml_rejects(contents: &str) {
+        assert!(ToolchainFile::parse_toml(contents).is_err());
+    }
+
+    #[parameterized(
+        symbolic = { "stable\n", ChannelSpec::Stable },
+        dated = { "nightly-2024-02-01", ChannelSpec::Nightly },
+        version = { "  1.70.0  \n", ChannelSpec::Exact(Channel::stable(RustVersion::new(1, 70, 0))) },
+        custom = { "stage2\n", ChannelSpec::Custom(Custom::Named("stage2".to_string())) },
+    )]
+    fn parse_legacy(contents: &str, expected: ChannelSpec) {
+        let file = contents.parse::<ToolchainFile>().unwrap();
+
+        assert!(file.is_legacy());
+        assert_eq!(file.channel().unwrap().channel(), &expected);
+    }
+
+    #[test]
+    fn write_legacy() {
+        let mut file = ToolchainFile::parse_legacy("1.70.0").unwrap();
+        file.set_channel(Some("1.82.0".parse().unwrap()));
+
+        assert_eq!(file.to_string(), "1.82.0\n");
+    }
+
+    #[test]
+    fn write_legacy_with_components_as_toml() {
+        let mut file = ToolchainFile::parse_legacy("stable").unwrap();
+        file.set_components(vec![Component::new("rustfmt")]);
+
+        assert!(!file.is_legacy());
+        assert_eq!(
+            file.to_string(),
+            "[toolchain]\nchannel = \"stable\"\ncomponents = [\"rustfmt\"]\n"
+        );
+    }
+
+    #[test]
+    fn to_toolchain() {
+        let file = ToolchainFile::parse_toml(
+            "[toolchain]\nchannel = \"nightly-2024-02-01\"\ncomponents = [\"miri\"]\n",
+        )
+        .unwrap();
+
+        let toolchain = file.to_toolchain().unwrap();
+
+        assert_eq!(
+            toolchain.channel(),
+            &Channel::nightly(Date::new(2024, 2, 1))
+        );
+        assert!(toolchain.components().contains(&Component::new("miri")));
+    }
+
+    #[test]
+    fn to_toolchain_unresolved() {
+        let file = ToolchainFile::parse_legacy("stable").unwrap();
+
+        assert!(matches!(
+            file.to_toolchain(),
+            Err(ToolchainFileError::Channel(
+                ParseToolchainError::Unresolved(_)
+            ))
+        ));
+    }
+
+    #[test]
+    fn new_toml() {
+        let file = ToolchainFile::new("1.70".parse().unwrap());
+
+        assert_eq!(file.to_string(), "[toolchain]\nchannel = \"1.70\"\n");
+    }
+}
@@ -2,6 +2,10 @@ use std::fmt;
 use std::fmt::Formatter;
 use std::str::FromStr;
 
+mod req;
+
+pub use req::{Comparator, Op, ParseVersionReqError, RustVersionReq};
+
 /// A three component, `major.minor.patch` version number.
 ///
 /// This version number is a subset of [semver](https://semver.org/spec/v2.0.0.html), except that
@@ -56,23 +60,9 @@ impl FromStr for RustVersion {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        use version_number::parsers::error::ExpectedError;
-        use version_number::parsers::error::NumericError;
-        use version_number::ParserError;
-
         version_number::FullVersion::parse(s)
             .map(|version| Self { version })
-            .map_err(|e| match e {
-                ParserError::Expected(inner) => match inner {
-                    ExpectedError::Numeric { got, .. } => ParseError::Expected("0-9", got),
-                    ExpectedError::Separator { got, .. } => ParseError::Expected(".", got),
-                    ExpectedError::EndOfInput { got, .. } => ParseError::Expected("EOI", Some(got)),
-                },
-                ParserError::Numeric(inner) => match inner {
-                    NumericError::LeadingZero => ParseError::LeadingZero,
-                    NumericError::Overflow => ParseError::NumberOverflow,
-                },
-            })
+            .map_err(ParseError::from)
     }
 }
 
@@ -82,18 +72,135 @@ impl fmt::Display for RustVersion {
     }
 }
 
+/// A two component, `major.minor` version number.
+///
+/// A partial version leaves out the patch component, and is commonly used to refer to the latest
+/// patch release of a `major.minor` release, for example in a rustup toolchain specifier like `1.70`.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct PartialRustVersion {
+    version: version_number::BaseVersion,
+}
+
+impl PartialRustVersion {
+    /// Instantiate a two component version number.
+    pub fn new(major: u64, minor: u64) -> Self {
+        Self {
+            version: version_number::BaseVersion { major, minor },
+        }
+    }
+
+    /// The major version of a two component version number
+    pub fn major(&self) -> u64 {
+        self.version.major
+    }
+
+    /// The minor version of a two component version number
+    pub fn minor(&self) -> u64 {
+        self.version.minor
+    }
+
+    /// Whether the version is a `major.minor.x` patch release of this partial version
+    pub fn matches(&self, version: &RustVersion) -> bool {
+        version.major() == self.major() && version.minor() == self.minor()
+    }
+}
+
+impl From<(u64, u64)> for PartialRustVersion {
+    fn from((major, minor): (u64, u64)) -> Self {
+        Self::new(major, minor)
+    }
+}
+
+impl From<RustVersion> for PartialRustVersion {
+    fn from(version: RustVersion) -> Self {
+        Self::new(version.major(), version.minor())
+    }
+}
+
+impl FromStr for PartialRustVersion {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        version_number::BaseVersion::parse(s)
+            .map(|version| Self { version })
+            .map_err(ParseError::from)
+    }
+}
+
+impl fmt::Display for PartialRustVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.version)
+    }
+}
+
+/// Errors which may occur while parsing a [`RustVersion`] or [`PartialRustVersion`].
 #[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
 pub enum ParseError {
+    /// Returned when an unexpected token was found
     #[error("Expected '{0}' but got '{got}'", got = .1.map(|c| c.to_string()).unwrap_or_default())]
     Expected(&'static str, Option<char>),
 
+    /// Returned when a number has a leading zero
     #[error("expected token 1-9, but got '0' (leading zero is not permitted)")]
     LeadingZero,
 
+    /// Returned when a number does not fit in an `u64`
     #[error("unable to parse number (overflow occurred)")]
     NumberOverflow,
 }
 
+impl From<version_number::ParserError> for ParseError {
+    fn from(error: version_number::ParserError) -> Self {
+        use version_number::parsers::error::ExpectedError;
+        use version_number::parsers::error::NumericError;
+        use version_number::ParserError;
+
+        match error {
+            ParserError::Expected(inner) => match inner {
+                ExpectedError::Numeric { got, .. } => ParseError::Expected("0-9", got),
+                ExpectedError::Separator { got, .. } => ParseError::Expected(".", got),
+                ExpectedError::EndOfInput { got, .. } => ParseError::Expected("EOI", Some(got)),
+            },
+            ParserError::Numeric(inner) => match inner {
+                NumericError::LeadingZero => ParseError::LeadingZero,
+                NumericError::Overflow => ParseError::NumberOverflow,
+            },
+        }
+    }
+}
+
+/// Returned when a `semver` version, or version requirement, has a pre-release or build
+/// metadata label, which can not be represented by a [`RustVersion`] or [`RustVersionReq`].
+#[cfg(feature = "semver")]
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+#[error("Version `{0}` has a label, which is not supported")]
+pub struct SemverError(String);
+
+#[cfg(feature = "semver")]
+impl From<RustVersion> for semver::Version {
+    fn from(version: RustVersion) -> Self {
+        semver::Version::new(version.major(), version.minor(), version.patch())
+    }
+}
+
+#[cfg(feature = "semver")]
+impl TryFrom<&semver::Version> for RustVersion {
+    type Error = SemverError;
+
+    /// Converts a `semver` version, which must not have a pre-release or build metadata label.
+    fn try_from(version: &semver::Version) -> Result<Self, Self::Error> {
+        if version.pre.is_empty() && version.build.is_empty() {
+            Ok(RustVersion::new(
+                version.major,
+                version.minor,
+                version.patch,
+            ))
+        } else {
+            Err(SemverError(version.to_string()))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -142,6 +249,48 @@ mod tests {
         assert_eq!(left.cmp(&right), expected_ord);
     }
 
+    #[yare::parameterized(
+        one_seventy = { "1.70", PartialRustVersion::new(1, 70) },
+        zero = { "0.0", PartialRustVersion::new(0, 0) },
+    )]
+    fn parse_partial(input: &str, expected: PartialRustVersion) {
+        assert_eq!(input.parse::<PartialRustVersion>().unwrap(), expected);
+        assert_eq!(expected.to_string(), input);
+    }
+
+    #[yare::parameterized(
+        same = { RustVersion::new(1, 70, 0), true },
+        patch = { RustVersion::new(1, 70, 2), true },
+        other_minor = { RustVersion::new(1, 71, 0), false },
+        other_major = { RustVersion::new(2, 70, 0), false },
+    )]
+    fn partial_matches(version: RustVersion, expected: bool) {
+        assert_eq!(PartialRustVersion::new(1, 70).matches(&version), expected);
+    }
+
+    #[cfg(feature = "semver")]
+    #[test]
+    fn semver_conversions() {
+        let version = RustVersion::new(1, 70, 1);
+
+        let converted = semver::Version::from(version);
+
+        assert_eq!(converted, semver::Version::new(1, 70, 1));
+        assert_eq!(RustVersion::try_from(&converted), Ok(version));
+
+        let beta = semver::Version::parse("1.71.0-beta.1").unwrap();
+        assert!(RustVersion::try_from(&beta).is_err());
+    }
+
+    #[yare::parameterized(
+        full = { "1.70.0" },
+        major_only = { "1" },
+        leading_zero = { "1.07" },
+    )]
+    fn parse_partial_rejects(input: &str) {
+        assert!(input.parse::<PartialRustVersion>().is_err());
+    }
+
     mod partial_eq {
         use super::*;
 
@@ -0,0 +1,550 @@
+use crate::RustVersion;
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+/// A requirement on a [`RustVersion`], like `>=1.56`, `~1.70.1`, `1.70.*` or `>=1.60, <1.70`.
+///
+/// The syntax and semantics follow the version requirements of Cargo, except that pre-release
+/// labels are not supported. A requirement consists of zero or more comparators separated by
+/// commas, all of which must match. A requirement without comparators, `*`, matches any version.
+///
+/// A comparator consists of an operator, followed by a one, two or three component version.
+/// A version without an operator, like `1.70`, is a caret requirement, like it is in Cargo.
+///
+/// # Reading materials
+///
+/// - [`cargo: specifying dependencies`]
+///
+/// [`cargo: specifying dependencies`]: https://doc.rust-lang.org/cargo/reference/specifying-dependencies.html
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct RustVersionReq {
+    comparators: Vec<Comparator>,
+}
+
+impl RustVersionReq {
+    /// A requirement which matches any version, i.e. `*`
+    pub const STAR: RustVersionReq = RustVersionReq {
+        comparators: Vec::new(),
+    };
+
+    /// Create a new requirement, which matches when all comparators match
+    pub fn new(comparators: Vec<Comparator>) -> Self {
+        Self { comparators }
+    }
+
+    /// The comparators of the requirement
+    pub fn comparators(&self) -> &[Comparator] {
+        &self.comparators
+    }
+
+    /// Whether the version satisfies each comparator of the requirement
+    pub fn matches(&self, version: &RustVersion) -> bool {
+        self.comparators
+            .iter()
+            .all(|comparator| comparator.matches(version))
+    }
+}
+
+impl FromStr for RustVersionReq {
+    type Err = ParseVersionReqError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        if s.is_empty() {
+            return Err(ParseVersionReqError::Empty);
+        }
+
+        if s == "*" {
+            return Ok(Self::STAR);
+        }
+
+        s.split(',')
+            .map(|comparator| comparator.parse())
+            .collect::<Result<_, _>>()
+            .map(Self::new)
+    }
+}
+
+impl fmt::Display for RustVersionReq {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.comparators.is_empty() {
+            return f.write_str("*");
+        }
+
+        for (i, comparator) in self.comparators.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+
+            write!(f, "{}", comparator)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A single comparator of a [`RustVersionReq`], like `>=1.56` or `1.70.*`.
+///
+/// The minor and patch components may be left out, in which case they match any value,
+/// depending on the operator.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Comparator {
+    op: Op,
+    major: u64,
+    minor: Option<u64>,
+    patch: Option<u64>,
+}
+
+impl Comparator {
+    /// Create a new comparator.
+    ///
+    /// A patch component is ignored if the minor component is not given.
+    pub fn new(op: Op, major: u64, minor: Option<u64>, patch: Option<u64>) -> Self {
+        Self {
+            op,
+            major,
+            minor,
+            patch: minor.and(patch),
+        }
+    }
+
+    /// The operator of the comparator
+    pub fn op(&self) -> Op {
+        self.op
+    }
+
+    /// The major component of the version
+    pub fn major(&self) -> u64 {
+        self.major
+    }
+
+    /// The minor component of the version, if given
+    pub fn minor(&self) -> Option<u64> {
+        self.minor
+    }
+
+    /// The patch component of the version, if given
+    pub fn patch(&self) -> Option<u64> {
+        self.patch
+    }
+
+    /// Whether the version satisfies the comparator
+    pub fn matches(&self, version: &RustVersion) -> bool {
+        let ordering = self.cmp_given(version);
+
+        match self.op {
+            Op::Exact | Op::Wildcard => ordering == Ordering::Equal,
+            Op::Greater => ordering == Ordering::Greater,
+            Op::GreaterEq => ordering != Ordering::Less,
+            Op::Less => ordering == Ordering::Less,
+            Op::LessEq => ordering != Ordering::Greater,
+            Op::Tilde => {
+                self.is_at_least(version)
+                    && version.major() == self.major
+                    && self.minor.is_none_or(|minor| version.minor() == minor)
+            }
+            Op::Caret => self.is_at_least(version) && self.is_caret_compatible(version),
+        }
+    }
+
+    /// Compares the version to the comparator, using only the components which are given
+    fn cmp_given(&self, version: &RustVersion) -> Ordering {
+        let components = [
+            (version.major(), Some(self.major)),
+            (version.minor(), self.minor),
+            (version.patch(), self.patch),
+        ];
+
+        components
+            .iter()
+            .filter_map(|(actual, given)| given.map(|given| actual.cmp(&given)))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    }
+
+    /// Whether the version is at least the version of the comparator, where components which
+    /// are not given are zero
+    fn is_at_least(&self, version: &RustVersion) -> bool {
+        let lower = RustVersion::new(
+            self.major,
+            self.minor.unwrap_or_default(),
+            self.patch.unwrap_or_default(),
+        );
+
+        *version >= lower
+    }
+
+    /// Whether the version does not change the left-most non-zero given component
+    fn is_caret_compatible(&self, version: &RustVersion) -> bool {
+        match (self.major, self.minor, self.patch) {
+            (0, Some(0), Some(patch)) => {
+                version.major() == 0 && version.minor() == 0 && version.patch() == patch
+            }
+            (0, Some(minor), _) => version.major() == 0 && version.minor() == minor,
+            (major, _, _) => version.major() == major,
+        }
+    }
+}
+
+impl FromStr for Comparator {
+    type Err = ParseVersionReqError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        if s.is_empty() {
+            return Err(ParseVersionReqError::EmptyComparator);
+        }
+
+        let (op, rest) = Op::split(s);
+        let rest = rest.trim_start();
+
+        let invalid = || ParseVersionReqError::InvalidVersion(s.to_string());
+
+        let mut components = rest.split('.');
+        let major = components.next().ok_or_else(invalid)?;
+        let minor = components.next();
+        let patch = components.next();
+
+        if components.next().is_some() {
+            return Err(invalid());
+        }
+
+        let is_wildcard = |component: &str| matches!(component, "*" | "x" | "X");
+
+        // A wildcard may only replace the trailing components, e.g. `1.*` or `1.70.*`
+        let (minor, patch, wildcard) = match (minor, patch) {
+            (Some(minor), patch) if is_wildcard(minor) && patch.is_none_or(is_wildcard) => {
+                (None, None, true)
+            }
+            (Some(minor), Some(patch)) if is_wildcard(patch) => (Some(minor), None, true),
+            (minor, patch) => (minor, patch, false),
+        };
+
+        if wildcard && op.is_some() {
+            return Err(ParseVersionReqError::UnexpectedWildcard(s.to_string()));
+        }
+
+        let op = match (op, wildcard) {
+            (_, true) => Op::Wildcard,
+            (Some(op), false) => op,
+            (None, false) => Op::Caret,
+        };
+
+        let number = |component: &str| parse_number(component).ok_or_else(invalid);
+
+        Ok(Self::new(
+            op,
+            number(major)?,
+            minor.map(number).transpose()?,
+            patch.map(number).transpose()?,
+        ))
+    }
+}
+
+impl fmt::Display for Comparator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.op, self.major)?;
+
+        if let Some(minor) = self.minor {
+            write!(f, ".{}", minor)?;
+        }
+
+        if let Some(patch) = self.patch {
+            write!(f, ".{}", patch)?;
+        }
+
+        if self.op == Op::Wildcard {
+            f.write_str(".*")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The operator of a [`Comparator`]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Op {
+    /// `=1.70.0` matches exactly `1.70.0`, while `=1.70` matches any `1.70.x`
+    Exact,
+    /// `>1.70.0` matches versions newer than `1.70.0`, while `>1.70` matches `1.71.0` and newer
+    Greater,
+    /// `>=1.70` matches `1.70.0` and newer
+    GreaterEq,
+    /// `<1.70` matches versions older than `1.70.0`
+    Less,
+    /// `<=1.70` matches `1.70.x` and older
+    LessEq,
+    /// `~1.70.1` matches `1.70.1` and newer `1.70.x` patch releases
+    Tilde,
+    /// `^1.70.1`, or `1.70.1`, matches `1.70.1` and newer `1.x.y` releases
+    Caret,
+    /// `1.70.*` matches any `1.70.x`, while `1.*` matches any `1.x.y`
+    Wildcard,
+}
+
+impl Op {
+    /// Splits the operator, if any, from the start of a comparator
+    fn split(s: &str) -> (Option<Op>, &str) {
+        const OPS: &[(&str, Op)] = &[
+            (">=", Op::GreaterEq),
+            ("<=", Op::LessEq),
+            (">", Op::Greater),
+            ("<", Op::Less),
+            ("=", Op::Exact),
+            ("~", Op::Tilde),
+            ("^", Op::Caret),
+        ];
+
+        OPS.iter()
+            .find_map(|(token, op)| s.strip_prefix(token).map(|rest| (Some(*op), rest)))
+            .unwrap_or((None, s))
+    }
+}
+
+impl fmt::Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Op::Exact => "=",
+            Op::Greater => ">",
+            Op::GreaterEq => ">=",
+            Op::Less => "<",
+            Op::LessEq => "<=",
+            Op::Tilde => "~",
+            Op::Caret => "^",
+            Op::Wildcard => "",
+        })
+    }
+}
+
+// Numbers must not have a leading zero, like the components of a `RustVersion`
+fn parse_number(component: &str) -> Option<u64> {
+    let is_numeric = !component.is_empty() && component.bytes().all(|b| b.is_ascii_digit());
+    let has_leading_zero = component.len() > 1 && component.starts_with('0');
+
+    if is_numeric && !has_leading_zero {
+        component.parse().ok()
+    } else {
+        None
+    }
+}
+
+/// Errors which may occur while parsing a [`RustVersionReq`].
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+#[non_exhaustive]
+pub enum ParseVersionReqError {
+    /// Returned when the requirement is empty
+    #[error("Version requirement is empty")]
+    Empty,
+
+    /// Returned when the requirement contains an empty comparator, e.g. `>=1.56,`
+    #[error("Version requirement contains an empty comparator")]
+    EmptyComparator,
+
+    /// Returned when the version of a comparator can not be parsed
+    #[error("Unable to parse the version of comparator `{0}`")]
+    InvalidVersion(String),
+
+    /// Returned when a wildcard is combined with an operator, e.g. `>=1.*`
+    #[error("Comparator `{0}` must not combine a wildcard with an operator")]
+    UnexpectedWildcard(String),
+}
+
+#[cfg(feature = "semver")]
+mod semver_conversions {
+    use super::*;
+
+    impl From<&RustVersionReq> for semver::VersionReq {
+        fn from(req: &RustVersionReq) -> Self {
+            semver::VersionReq {
+                comparators: req
+                    .comparators
+                    .iter()
+                    .map(|comparator| semver::Comparator {
+                        op: match comparator.op {
+                            Op::Exact => semver::Op::Exact,
+                            Op::Greater => semver::Op::Greater,
+                            Op::GreaterEq => semver::Op::GreaterEq,
+                            Op::Less => semver::Op::Less,
+                            Op::LessEq => semver::Op::LessEq,
+                            Op::Tilde => semver::Op::Tilde,
+                            Op::Caret => semver::Op::Caret,
+                            Op::Wildcard => semver::Op::Wildcard,
+                        },
+                        major: comparator.major,
+                        minor: comparator.minor,
+                        patch: comparator.patch,
+                        pre: semver::Prerelease::EMPTY,
+                    })
+                    .collect(),
+            }
+        }
+    }
+
+    impl TryFrom<&semver::VersionReq> for RustVersionReq {
+        type Error = crate::version::SemverError;
+
+        /// Converts a `semver` requirement, which must not have pre-release labels.
+        fn try_from(req: &semver::VersionReq) -> Result<Self, Self::Error> {
+            let unsupported = || crate::version::SemverError(req.to_string());
+
+            req.comparators
+                .iter()
+                .map(|comparator| {
+                    if !comparator.pre.is_empty() {
+                        return Err(unsupported());
+                    }
+
+                    let op = match comparator.op {
+                        semver::Op::Exact => Op::Exact,
+                        semver::Op::Greater => Op::Greater,
+                        semver::Op::GreaterEq => Op::GreaterEq,
+                        semver::Op::Less => Op::Less,
+                        semver::Op::LessEq => Op::LessEq,
+                        semver::Op::Tilde => Op::Tilde,
+                        semver::Op::Caret => Op::Caret,
+                        semver::Op::Wildcard => Op::Wildcard,
+                        _ => return Err(unsupported()),
+                    };
+
+                    Ok(Comparator::new(
+                        op,
+                        comparator.major,
+                        comparator.minor,
+                        comparator.patch,
+                    ))
+                })
+                .collect::<Result<_, _>>()
+                .map(RustVersionReq::new)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use yare::parameterized;
+
+    fn v(major: u64, minor: u64, patch: u64) -> RustVersion {
+        RustVersion::new(major, minor, patch)
+    }
+
+    #[parameterized(
+        greater_eq_partial = { ">=1.56", v(1, 56, 0), true },
+        greater_eq_partial_below = { ">=1.56", v(1, 55, 9), false },
+        greater_eq_major = { ">=1", v(1, 0, 0), true },
+        greater_partial = { ">1.70", v(1, 70, 5), false },
+        greater_partial_next = { ">1.70", v(1, 71, 0), true },
+        greater_full = { ">1.70.0", v(1, 70, 1), true },
+        less_partial = { "<1.70", v(1, 70, 0), false },
+        less_partial_below = { "<1.70", v(1, 69, 9), true },
+        less_eq_partial = { "<=1.70", v(1, 70, 9), true },
+        less_eq_partial_next = { "<=1.70", v(1, 71, 0), false },
+        exact_full = { "=1.70.0", v(1, 70, 0), true },
+        exact_full_other = { "=1.70.0", v(1, 70, 1), false },
+        exact_partial = { "=1.70", v(1, 70, 1), true },
+        tilde_full = { "~1.70.1", v(1, 70, 3), true },
+        tilde_full_below = { "~1.70.1", v(1, 70, 0), false },
+        tilde_full_next_minor = { "~1.70.1", v(1, 71, 0), false },
+        tilde_partial = { "~1.70", v(1, 70, 0), true },
+        tilde_major = { "~1", v(1, 80, 0), true },
+        caret = { "^1.60", v(1, 80, 0), true },
+        caret_below = { "^1.60", v(1, 59, 0), false },
+        caret_next_major = { "^1.60", v(2, 0, 0), false },
+        caret_bare = { "1.60.1", v(1, 60, 1), true },
+        caret_bare_below = { "1.60.1", v(1, 60, 0), false },
+        caret_zero_minor = { "^0.12", v(0, 12, 1), true },
+        caret_zero_minor_next = { "^0.12", v(0, 13, 0), false },
+        caret_zero_zero = { "^0.0.3", v(0, 0, 4), false },
+        wildcard_minor = { "1.70.*", v(1, 70, 2), true },
+        wildcard_minor_other = { "1.70.*", v(1, 71, 0), false },
+        wildcard_major = { "1.*", v(1, 71, 0), true },
+        wildcard_x = { "1.70.x", v(1, 70, 2), true },
+        wildcard_both = { "1.*.*", v(1, 2, 3), true },
+        star = { "*", v(0, 1, 0), true },
+        conjunction = { ">=1.60, <1.70", v(1, 65, 0), true },
+        conjunction_upper = { ">=1.60, <1.70", v(1, 70, 0), false },
+        conjunction_lower = { ">=1.60,<1.70", v(1, 59, 0), false },
+    )]
+    fn matches(req: &str, version: RustVersion, expected: bool) {
+        let req = req.parse::<RustVersionReq>().unwrap();
+
+        assert_eq!(req.matches(&version), expected);
+    }
+
+    #[parameterized(
+        greater_eq = { ">=1.56", ">=1.56" },
+        spaced = { ">= 1.56 ,  < 1.70.1", ">=1.56, <1.70.1" },
+        bare = { "1.70", "^1.70" },
+        tilde = { "~1.70.1", "~1.70.1" },
+        wildcard = { "1.70.*", "1.70.*" },
+        star = { "*", "*" },
+    )]
+    fn display(input: &str, expected: &str) {
+        let req = input.parse::<RustVersionReq>().unwrap();
+
+        assert_eq!(req.to_string(), expected);
+        assert_eq!(expected.parse::<RustVersionReq>().unwrap(), req);
+    }
+
+    #[parameterized(
+        empty = { "", ParseVersionReqError::Empty },
+        empty_comparator = { ">=1.56,", ParseVersionReqError::EmptyComparator },
+        four_components = { "1.2.3.4", ParseVersionReqError::InvalidVersion("1.2.3.4".to_string()) },
+        leading_zero = { ">=1.056", ParseVersionReqError::InvalidVersion(">=1.056".to_string()) },
+        prerelease = { "1.70.0-beta", ParseVersionReqError::InvalidVersion("1.70.0-beta".to_string()) },
+        operator_only = { ">=", ParseVersionReqError::InvalidVersion(">=".to_string()) },
+        wildcard_before_number = { "1.*.0", ParseVersionReqError::InvalidVersion("1.*.0".to_string()) },
+        wildcard_with_operator = { ">=1.*", ParseVersionReqError::UnexpectedWildcard(">=1.*".to_string()) },
+    )]
+    fn parse_rejects(input: &str, expected: ParseVersionReqError) {
+        assert_eq!(input.parse::<RustVersionReq>(), Err(expected));
+    }
+
+    #[test]
+    fn comparators() {
+        let req = ">=1.56, <2".parse::<RustVersionReq>().unwrap();
+
+        assert_eq!(
+            req.comparators(),
+            &[
+                Comparator::new(Op::GreaterEq, 1, Some(56), None),
+                Comparator::new(Op::Less, 2, None, None),
+            ]
+        );
+    }
+
+    #[cfg(feature = "semver")]
+    #[parameterized(
+        greater_eq = { ">=1.56" },
+        conjunction = { ">=1.60, <1.70.1" },
+        tilde = { "~1.70" },
+        caret = { "^1.60.2" },
+        wildcard = { "1.70.*" },
+        star = { "*" },
+    )]
+    fn semver_round_trip(input: &str) {
+        let req = input.parse::<RustVersionReq>().unwrap();
+
+        let converted = semver::VersionReq::from(&req);
+
+        for version in ["1.55.0", "1.56.0", "1.60.2", "1.69.0", "1.70.0", "1.70.3"] {
+            let ours = version.parse::<RustVersion>().unwrap();
+            let theirs = semver::Version::parse(version).unwrap();
+
+            assert_eq!(req.matches(&ours), converted.matches(&theirs), "{version}");
+        }
+
+        assert_eq!(RustVersionReq::try_from(&converted), Ok(req));
+    }
+
+    #[cfg(feature = "semver")]
+    #[test]
+    fn semver_prerelease_is_rejected() {
+        let req = semver::VersionReq::parse(">=1.70.0-beta.1").unwrap();
+
+        assert!(RustVersionReq::try_from(&req).is_err());
+    }
+}
@@ -0,0 +1,171 @@
+#!/usr/bin/env python3
+"""Generates the platform support table of the `rust-toolchain` crate.
+
+The table is written to `crates/rust-toolchain/src/target/platform_support/table.rs`, and
+combines two sources:
+
+* The tier, and whether `std` is available, of each target, from the target specifications of
+  a nightly `rustc`, i.e. `rustc +nightly -Z unstable-options --print all-target-specs-json`.
+  These are the metadata against which the rustc platform support page
+  (https://doc.rust-lang.org/rustc/platform-support.html) is checked.
+* The first and last stable release which shipped the `rust-std` component of each target, from
+  the release manifests (`dist/channel-rust-1.x.0.toml`) of each `1.x.0` release from 1.8.0, the
+  first release with a manifest, up to the latest stable release. For the releases before 1.8.0,
+  only the hosts for which a `rustc` was shipped are known, from the dist listing in
+  `resources/rust_dist/dist_static-rust-lang-org.txt`.
+
+Targets which are no longer supported by `rustc`, but of which the standard library was shipped,
+are included without a tier; for these targets, `std` is assumed to be available.
+
+Regenerate the table with `just generate-platform-support`, or run this script from the root of
+the repository:
+
+    python3 resources/platform_support/generate.py [--rustc rustc] [--cache DIR]
+
+Requires Python 3.11 or newer, a nightly `rustc`, and access to https://static.rust-lang.org.
+"""
+
+import argparse
+import concurrent.futures
+import json
+import pathlib
+import re
+import subprocess
+import tomllib
+import urllib.request
+
+DIST = "https://static.rust-lang.org/dist"
+
+# The first release for which a release manifest is available
+FIRST_MANIFEST_MINOR = 8
+
+ROOT = pathlib.Path(__file__).resolve().parents[2]
+DIST_LISTING = ROOT / "resources" / "rust_dist" / "dist_static-rust-lang-org.txt"
+OUTPUT = ROOT / "crates" / "rust-toolchain" / "src" / "target" / "platform_support" / "table.rs"
+
+TIERS = {
+    (1, True): "Tier::One",
+    (1, False): "Tier::One",
+    (2, True): "Tier::TwoWithHostTools",
+    (2, False): "Tier::Two",
+    (2, None): "Tier::Two",
+    (3, True): "Tier::Three",
+    (3, False): "Tier::Three",
+    (3, None): "Tier::Three",
+}
+
+
+def target_specs(rustc):
+    """The metadata of the target specifications of the given nightly rustc."""
+    command = [rustc, "-Z", "unstable-options", "--print", "all-target-specs-json"]
+    specs = json.loads(subprocess.run(command, check=True, capture_output=True, text=True).stdout)
+    version = subprocess.run([rustc, "-V"], check=True, capture_output=True, text=True).stdout
+
+    return version.strip(), {triple: spec.get("metadata", {}) for triple, spec in specs.items()}
+
+
+def fetch(name, cache):
+    """Fetch a document from the dist server, or from the cache directory, if given."""
+    cached = cache / name if cache else None
+
+    if cached and cached.exists():
+        return cached.read_bytes()
+
+    with urllib.request.urlopen(f"{DIST}/{name}") as response:
+        document = response.read()
+
+    if cached:
+        cached.write_bytes(document)
+
+    return document
+
+
+def latest_stable_minor():
+    manifest = tomllib.loads(fetch("channel-rust-stable.toml", None).decode())
+    version = manifest["pkg"]["rust"]["version"].split()[0]
+
+    return int(version.split(".")[1])
+
+
+def shipped_from_manifest(minor, cache):
+    """The targets of which the rust-std component was shipped with release `1.<minor>.0`."""
+    manifest = tomllib.loads(fetch(f"channel-rust-1.{minor}.0.toml", cache).decode())
+    targets = manifest["pkg"].get("rust-std", {}).get("target", {})
+
+    return minor, {triple for triple, target in targets.items() if target.get("available")}
+
+
+def shipped_from_listing():
+    """The hosts for which a rustc was shipped with the releases before the first manifest."""
+    pattern = re.compile(r"^dist/rustc-1\.(\d+)\.0-(?!alpha|beta|src)([a-z0-9_.-]+)\.tar\.gz$")
+    shipped = {}
+
+    for line in DIST_LISTING.read_text().splitlines():
+        match = pattern.match(line.strip())
+        if match and int(match.group(1)) < FIRST_MANIFEST_MINOR:
+            shipped.setdefault(int(match.group(1)), set()).add(match.group(2))
+
+    return shipped
+
+
+def version(minor):
+    return f"Some((1, {minor}, 0))" if minor is not None else "None"
+
+
+def main():
+    parser = argparse.ArgumentParser(description=__doc__.splitlines()[0])
+    parser.add_argument("--rustc", default="rustc", help="a nightly rustc (default: rustc)")
+    parser.add_argument("--cache", type=pathlib.Path, help="a directory to cache manifests in")
+    args = parser.parse_args()
+
+    if args.cache:
+        args.cache.mkdir(parents=True, exist_ok=True)
+
+    rustc_version, specs = target_specs(args.rustc)
+    latest = latest_stable_minor()
+
+    shipped = shipped_from_listing()
+    with concurrent.futures.ThreadPoolExecutor(max_workers=8) as pool:
+        minors = range(FIRST_MANIFEST_MINOR, latest + 1)
+        shipped.update(pool.map(lambda minor: shipped_from_manifest(minor, args.cache), minors))
+
+    releases = {}
+    for minor, targets in shipped.items():
+        for triple in targets:
+            releases.setdefault(triple, []).append(minor)
+
+    lines = []
+    for triple in sorted(set(specs) | set(releases)):
+        metadata = specs.get(triple)
+        minors = sorted(releases.get(triple, []))
+        first = minors[0] if minors else None
+        last = minors[-1] if minors and minors[-1] < latest else None
+
+        if metadata is None:
+            tier = "None"
+            std = "true"
+        else:
+            tier = f"Some({TIERS[(metadata['tier'], metadata.get('host_tools'))]})"
+            std = "true" if metadata.get("std") else "false"
+
+        lines.append(
+            f'    entry("{triple}", {tier}, {std}, {version(first)}, {version(last)}),'
+        )
+
+    OUTPUT.write_text(
+        "// @generated by resources/platform_support/generate.py; do not edit.\n"
+        "//\n"
+        f"// Tiers from the target specifications of {rustc_version}.\n"
+        f"// Shipped releases from the release manifests up to Rust 1.{latest}.0.\n"
+        "\n"
+        "use super::{entry, PlatformSupport, Tier};\n"
+        "\n"
+        "#[rustfmt::skip]\n"
+        "pub(super) const PLATFORM_SUPPORT: &[PlatformSupport] = &[\n"
+        + "\n".join(lines)
+        + "\n];\n"
+    )
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,157 @@
+use clap::{Parser, Subcommand, ValueEnum};
+use rust_releases_core::channel::Channel;
+use rust_releases_core::rust_release::date::Date;
+use std::path::PathBuf;
+
+/// Query an index of Rust releases
+#[derive(Debug, Parser)]
+#[command(name = "rust-releases", version)]
+pub struct Cli {
+    /// The source from which the index is built
+    #[arg(long, global = true, value_enum, default_value_t = SourceKind::Changelog)]
+    pub source: SourceKind,
+
+    /// The format of the output
+    #[arg(long, global = true, value_enum, default_value_t = Format::Plain)]
+    pub format: Format,
+
+    /// Only use the cached copy of the source, even if it is stale, and never access the network
+    #[arg(long, global = true, conflicts_with = "source_file")]
+    pub offline: bool,
+
+    /// Build the index from a local copy of the source, i.e. the `RELEASES.md` of the Rust
+    /// changelog, or a listing of the Rust distribution bucket
+    #[arg(long, global = true, value_name = "PATH")]
+    pub source_file: Option<PathBuf>,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// List the releases of a channel, in ascending order
+    List {
+        /// The channel of the releases
+        #[arg(long, value_enum, default_value_t = ChannelArg::Stable)]
+        channel: ChannelArg,
+    },
+    /// Show the latest release of a channel
+    Latest {
+        /// The channel of the release
+        #[arg(long, value_enum, default_value_t = ChannelArg::Stable)]
+        channel: ChannelArg,
+    },
+    /// Resolve a toolchain specifier, like `stable`, `1.70` or `1.71.0-beta.2`, to a release
+    Resolve {
+        /// The toolchain specifier
+        spec: String,
+    },
+    /// Show the release date, toolchains and release notes of a release
+    ///
+    /// Release notes are only available from the changelog source.
+    Info {
+        /// The version, or another toolchain specifier, of the release
+        version: String,
+    },
+    /// Show the latest release of each channel, as of the given date
+    OnDate {
+        /// The date, as `YYYY-MM-DD`
+        date: Date,
+    },
+    /// Manage the cached copies of the sources
+    Cache {
+        #[command(subcommand)]
+        command: CacheCommand,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum CacheCommand {
+    /// Remove the cached copies of all sources
+    Clear,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub enum SourceKind {
+    /// The official Rust changelog; supports the stable and alpha channels
+    Changelog,
+    /// The Rust distribution bucket; supports the stable and alpha channels
+    Dist,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub enum Format {
+    /// One release per line
+    Plain,
+    /// An aligned table
+    Table,
+    /// A JSON document
+    Json,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub enum ChannelArg {
+    Stable,
+    Beta,
+    Alpha,
+}
+
+impl From<ChannelArg> for Channel {
+    fn from(channel: ChannelArg) -> Self {
+        match channel {
+            ChannelArg::Stable => Channel::Stable,
+            ChannelArg::Beta => Channel::Beta,
+            ChannelArg::Alpha => Channel::Alpha,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verify_cli() {
+        use clap::CommandFactory;
+
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn global_options_after_subcommand() {
+        let cli = Cli::try_parse_from([
+            "rust-releases",
+            "list",
+            "--channel",
+            "beta",
+            "--source",
+            "dist",
+            "--format",
+            "json",
+        ])
+        .unwrap();
+
+        assert_eq!(cli.source, SourceKind::Dist);
+        assert_eq!(cli.format, Format::Json);
+        assert!(matches!(
+            cli.command,
+            Command::List {
+                channel: ChannelArg::Beta
+            }
+        ));
+    }
+
+    #[test]
+    fn offline_conflicts_with_source_file() {
+        let cli = Cli::try_parse_from([
+            "rust-releases",
+            "latest",
+            "--offline",
+            "--source-file",
+            "RELEASES.md",
+        ]);
+
+        assert!(cli.is_err());
+    }
+}
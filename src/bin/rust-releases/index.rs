@@ -0,0 +1,147 @@
+use crate::cli::SourceKind;
+use rust_releases::{ReleaseNotes, RustChangelog, RustDist};
+use rust_releases_core::channel::Channel;
+use rust_releases_core::{BetaReleases, NightlyReleases, RustReleases};
+use rust_releases_io::Document;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Where the source document is obtained from
+#[derive(Clone, Debug)]
+pub enum Origin {
+    /// Fetch the source, or use its cached copy if it is not stale
+    Fetch,
+    /// Use the cached copy of the source, even if it is stale
+    Cache,
+    /// Use a local copy of the source
+    SourceFile(PathBuf),
+}
+
+/// An index built from a single source
+pub struct Loaded {
+    pub index: RustReleases,
+    /// The release notes, if the source provides them
+    pub notes: Option<ReleaseNotes>,
+}
+
+impl SourceKind {
+    /// The channels of which the source provides releases
+    pub fn channels(self) -> &'static [Channel] {
+        match self {
+            SourceKind::Changelog => &[Channel::Stable, Channel::Alpha],
+            SourceKind::Dist => &[Channel::Stable, Channel::Alpha],
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            SourceKind::Changelog => "changelog",
+            SourceKind::Dist => "dist",
+        }
+    }
+
+    /// Fails if the source does not provide releases of the given channel
+    pub fn require(self, channel: Channel) -> Result<(), Box<dyn Error>> {
+        if self.channels().contains(&channel) {
+            Ok(())
+        } else {
+            Err(format!(
+                "The {} channel is not available from the {} source",
+                channel,
+                self.name()
+            )
+            .into())
+        }
+    }
+}
+
+/// Build an index of all channels the source provides
+pub fn load(source: SourceKind, origin: &Origin) -> Result<Loaded, Box<dyn Error>> {
+    match source {
+        SourceKind::Changelog => {
+            let changelog = match origin {
+                Origin::Fetch => RustChangelog::fetch_channel(Channel::Stable)?,
+                Origin::Cache => RustChangelog::cached_channel(Channel::Stable)?,
+                Origin::SourceFile(path) => RustChangelog::from_document(read(path)?),
+            };
+
+            let index = RustReleases::new(
+                changelog.build_index()?,
+                BetaReleases::default(),
+                NightlyReleases::default(),
+                changelog.build_alpha_index()?,
+            );
+
+            Ok(Loaded {
+                index,
+                notes: Some(changelog.release_notes()?),
+            })
+        }
+        SourceKind::Dist => {
+            let dist = match origin {
+                Origin::Fetch => RustDist::fetch_channel(Channel::Stable)?,
+                Origin::Cache => RustDist::cached_channel(Channel::Stable)?,
+                Origin::SourceFile(path) => RustDist::from_document(read(path)?),
+            };
+
+            // The listing only contains the `1.0.0-beta` pre-releases; later beta releases are
+            // only published as channel manifests, which are not indexed by this tool yet
+            let index = RustReleases::new(
+                dist.build_index()?,
+                BetaReleases::default(),
+                NightlyReleases::default(),
+                dist.build_alpha_index()?,
+            );
+
+            Ok(Loaded { index, notes: None })
+        }
+    }
+}
+
+fn read(path: &Path) -> Result<Document, Box<dyn Error>> {
+    let buffer = fs::read(path)
+        .map_err(|err| format!("Unable to read source file '{}': {}", path.display(), err))?;
+
+    Ok(Document::new(buffer))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn source_file() -> Origin {
+        Origin::SourceFile(
+            [
+                env!("CARGO_MANIFEST_DIR"),
+                "/resources/rust_changelog/RELEASES.md",
+            ]
+            .join("")
+            .into(),
+        )
+    }
+
+    #[test]
+    fn load_changelog_source_file() {
+        let loaded = load(SourceKind::Changelog, &source_file()).unwrap();
+
+        assert_eq!(loaded.index.stable().into_iter().count(), 70);
+        assert_eq!(loaded.index.alpha().into_iter().count(), 14);
+        assert!(loaded.notes.is_some());
+    }
+
+    #[test]
+    fn missing_source_file() {
+        let origin = Origin::SourceFile("does-not-exist.md".into());
+
+        assert!(load(SourceKind::Changelog, &origin).is_err());
+    }
+
+    #[test]
+    fn require_channel() {
+        assert!(SourceKind::Dist.require(Channel::Alpha).is_ok());
+        assert!(SourceKind::Dist.require(Channel::Beta).is_err());
+        assert!(SourceKind::Changelog.require(Channel::Beta).is_err());
+        assert!(SourceKind::Dist.require(Channel::Nightly).is_err());
+    }
+}
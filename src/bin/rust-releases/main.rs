@@ -0,0 +1,133 @@
+#![deny(clippy::all)]
+#![deny(unsafe_code)]
+
+//! A command-line tool to query an index of Rust releases.
+//!
+//! Run `rust-releases --help` for its usage.
+
+mod cli;
+mod index;
+mod output;
+
+use crate::cli::{CacheCommand, Cli, Command};
+use crate::index::{Loaded, Origin};
+use clap::Parser;
+use rust_releases_core::channel::Channel;
+use rust_releases_core::rust_release::toolchain::ToolchainSpec;
+use rust_releases_core::{ReleaseVersion, RustRelease};
+use std::error::Error;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match run(cli) {
+        Ok(out) => {
+            print!("{}", out);
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(cli: Cli) -> Result<String, Box<dyn Error>> {
+    let origin = match (&cli.source_file, cli.offline) {
+        (Some(path), _) => Origin::SourceFile(path.clone()),
+        (None, true) => Origin::Cache,
+        (None, false) => Origin::Fetch,
+    };
+
+    match cli.command {
+        Command::List { channel } => {
+            let channel = Channel::from(channel);
+            cli.source.require(channel)?;
+
+            let loaded = index::load(cli.source, &origin)?;
+            let releases = of_channel(&loaded, channel).collect::<Vec<_>>();
+
+            Ok(output::releases(cli.format, &releases))
+        }
+        Command::Latest { channel } => {
+            let channel = Channel::from(channel);
+            cli.source.require(channel)?;
+
+            let loaded = index::load(cli.source, &origin)?;
+            let latest = of_channel(&loaded, channel)
+                .last()
+                .ok_or_else(|| format!("The index contains no {} releases", channel))?;
+
+            Ok(output::releases(cli.format, &[latest]))
+        }
+        Command::Resolve { spec } => {
+            let loaded = index::load(cli.source, &origin)?;
+            let release = resolve(&loaded, &spec)?;
+
+            Ok(output::releases(cli.format, &[release]))
+        }
+        Command::Info { version } => {
+            let loaded = index::load(cli.source, &origin)?;
+            let release = resolve(&loaded, &version)?;
+            let notes = loaded
+                .notes
+                .as_ref()
+                .and_then(|notes| notes.get(release.version()));
+
+            Ok(output::info(cli.format, &release, notes))
+        }
+        Command::OnDate { date } => {
+            let loaded = index::load(cli.source, &origin)?;
+
+            let releases = ["stable", "beta", "nightly"]
+                .iter()
+                .filter_map(|spec| {
+                    let spec = spec.parse::<ToolchainSpec>().ok()?;
+
+                    loaded.index.resolve_at(&spec, &date)
+                })
+                .map(|resolved| resolved.to_release())
+                .collect::<Vec<_>>();
+
+            Ok(output::releases(cli.format, &releases))
+        }
+        Command::Cache {
+            command: CacheCommand::Clear,
+        } => {
+            let cache = rust_releases_io::base_cache_dir()?;
+
+            if cache.exists() {
+                std::fs::remove_dir_all(&cache)?;
+                Ok(format!("Removed the cache at '{}'\n", cache.display()))
+            } else {
+                Ok(format!(
+                    "The cache at '{}' is already empty\n",
+                    cache.display()
+                ))
+            }
+        }
+    }
+}
+
+fn of_channel(
+    loaded: &Loaded,
+    channel: Channel,
+) -> impl Iterator<Item = RustRelease<ReleaseVersion>> {
+    loaded
+        .index
+        .all()
+        .filter(move |release| release.version().channel() == channel)
+}
+
+fn resolve(loaded: &Loaded, spec: &str) -> Result<RustRelease<ReleaseVersion>, Box<dyn Error>> {
+    let parsed = spec
+        .parse::<ToolchainSpec>()
+        .map_err(|err| format!("Invalid toolchain specifier '{}': {}", spec, err))?;
+
+    loaded
+        .index
+        .resolve(&parsed)
+        .map(|resolved| resolved.to_release())
+        .ok_or_else(|| format!("No release matches '{}'", spec).into())
+}
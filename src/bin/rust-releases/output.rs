@@ -0,0 +1,184 @@
+use crate::cli::Format;
+use rust_releases_core::json;
+use rust_releases_core::{ReleaseVersion, RustRelease};
+use std::fmt::Write;
+
+/// Render a list of releases
+pub fn releases(format: Format, releases: &[RustRelease<ReleaseVersion>]) -> String {
+    match format {
+        Format::Plain => releases
+            .iter()
+            .map(|release| format!("{}\n", release.version()))
+            .collect(),
+        Format::Table => {
+            let rows = releases
+                .iter()
+                .map(|release| {
+                    vec![
+                        release.version().to_string(),
+                        release.version().channel().to_string(),
+                        date(release),
+                        release.toolchains().len().to_string(),
+                    ]
+                })
+                .collect::<Vec<_>>();
+
+            table(&["VERSION", "CHANNEL", "DATE", "TOOLCHAINS"], &rows)
+        }
+        Format::Json => {
+            let items = releases
+                .iter()
+                .map(|release| format!("  {}", json_release(release, None)))
+                .collect::<Vec<_>>();
+
+            if items.is_empty() {
+                "[]\n".to_string()
+            } else {
+                format!("[\n{}\n]\n", items.join(",\n"))
+            }
+        }
+    }
+}
+
+/// Render the details of a single release
+pub fn info(format: Format, release: &RustRelease<ReleaseVersion>, notes: Option<&str>) -> String {
+    match format {
+        Format::Plain | Format::Table => {
+            let mut out = String::new();
+            let _ = writeln!(out, "version:    {}", release.version());
+            let _ = writeln!(out, "channel:    {}", release.version().channel());
+            let _ = writeln!(out, "date:       {}", date(release));
+            let _ = writeln!(out, "toolchains: {}", release.toolchains().len());
+
+            for toolchain in release.toolchains_iter() {
+                let _ = writeln!(out, "  {}", toolchain);
+            }
+
+            if let Some(notes) = notes {
+                let _ = writeln!(out, "\n{}", notes);
+            }
+
+            out
+        }
+        Format::Json => format!("{}\n", json_release(release, Some(notes))),
+    }
+}
+
+fn date(release: &RustRelease<ReleaseVersion>) -> String {
+    release
+        .release_date()
+        .map_or_else(|| "unknown".to_string(), ToString::to_string)
+}
+
+fn table(header: &[&str], rows: &[Vec<String>]) -> String {
+    let mut widths = header.iter().map(|cell| cell.len()).collect::<Vec<_>>();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    let line = |cells: Vec<&str>| {
+        let padded = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:width$}", cell, width = width))
+            .collect::<Vec<_>>();
+
+        format!("{}\n", padded.join("  ").trim_end())
+    };
+
+    let mut out = line(header.to_vec());
+    for row in rows {
+        out.push_str(&line(row.iter().map(String::as_str).collect()));
+    }
+
+    out
+}
+
+// The notes are only included for a single release, where `None` means they were not requested
+fn json_release(release: &RustRelease<ReleaseVersion>, notes: Option<Option<&str>>) -> String {
+    let toolchains = release
+        .toolchains_iter()
+        .map(|toolchain| json::string(&toolchain.to_string()))
+        .collect::<Vec<_>>();
+
+    let mut fields = vec![
+        format!(
+            r#""version": {}"#,
+            json::string(&release.version().to_string())
+        ),
+        format!(r#""channel": "{}""#, release.version().channel()),
+        format!(
+            r#""release_date": {}"#,
+            release
+                .release_date()
+                .map_or_else(|| "null".to_string(), |date| format!("\"{}\"", date))
+        ),
+        format!(r#""toolchains": [{}]"#, toolchains.join(", ")),
+    ];
+
+    if let Some(notes) = notes {
+        fields.push(format!(
+            r#""notes": {}"#,
+            notes.map_or_else(|| "null".to_string(), json::string)
+        ));
+    }
+
+    format!("{{ {} }}", fields.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_releases_core::rust_release::date::Date;
+    use rust_releases_core::Stable;
+    use yare::parameterized;
+
+    fn release(minor: u64, date: Option<Date>) -> RustRelease<ReleaseVersion> {
+        RustRelease::new(ReleaseVersion::Stable(Stable::new(1, minor, 0)), date, [])
+    }
+
+    fn sample() -> Vec<RustRelease<ReleaseVersion>> {
+        vec![
+            release(9, Some(Date::new(2016, 5, 26))),
+            release(70, Some(Date::new(2023, 6, 1))),
+            release(71, None),
+        ]
+    }
+
+    #[parameterized(
+        plain = { Format::Plain, "1.9.0\n1.70.0\n1.71.0\n" },
+        table = { Format::Table, "VERSION  CHANNEL  DATE        TOOLCHAINS\n1.9.0    stable   2016-05-26  0\n1.70.0   stable   2023-06-01  0\n1.71.0   stable   unknown     0\n" },
+        json = { Format::Json, "[\n  { \"version\": \"1.9.0\", \"channel\": \"stable\", \"release_date\": \"2016-05-26\", \"toolchains\": [] },\n  { \"version\": \"1.70.0\", \"channel\": \"stable\", \"release_date\": \"2023-06-01\", \"toolchains\": [] },\n  { \"version\": \"1.71.0\", \"channel\": \"stable\", \"release_date\": null, \"toolchains\": [] }\n]\n" },
+    )]
+    fn list(format: Format, expected: &str) {
+        assert_eq!(releases(format, &sample()), expected);
+    }
+
+    #[test]
+    fn empty_json_list() {
+        assert_eq!(releases(Format::Json, &[]), "[]\n");
+    }
+
+    #[test]
+    fn info_json_with_notes() {
+        let release = release(70, Some(Date::new(2023, 6, 1)));
+
+        assert_eq!(
+            info(Format::Json, &release, Some("- \"a\"\n- b")),
+            "{ \"version\": \"1.70.0\", \"channel\": \"stable\", \"release_date\": \"2023-06-01\", \"toolchains\": [], \"notes\": \"- \\\"a\\\"\\n- b\" }\n"
+        );
+        assert!(info(Format::Json, &release, None).contains("\"notes\": null"));
+    }
+
+    #[test]
+    fn info_plain() {
+        let release = release(70, Some(Date::new(2023, 6, 1)));
+
+        assert_eq!(
+            info(Format::Plain, &release, Some("notes")),
+            "version:    1.70.0\nchannel:    stable\ndate:       2023-06-01\ntoolchains: 0\n\nnotes\n"
+        );
+    }
+}
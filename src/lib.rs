@@ -180,7 +180,12 @@ pub use rust_releases_io::{
 };
 
 #[cfg(feature = "rust-releases-rust-changelog")]
-pub use rust_releases_rust_changelog::{RustChangelog, RustChangelogError, RustChangelogResult};
+pub use rust_releases_rust_changelog::{
+    Diagnostic, DiagnosticReason, LenientIndex, ReleaseNotes, RustChangelog, RustChangelogError,
+    RustChangelogResult,
+};
 
 #[cfg(feature = "rust-releases-rust-dist")]
-pub use rust_releases_rust_dist::{RustDist, RustDistError, RustDistResult};
+pub use rust_releases_rust_dist::{
+    parse_beta_manifest, BetaManifests, RustDist, RustDistError, RustDistResult,
+};